@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bilinear accumulator ([Nguyen05](https://eprint.iacr.org/2005/123)) over a set of field elements,
+//! as a constant-size alternative to a Merkle tree for set membership.
+//!
+//! The accumulator value is a KZG commitment to the characteristic polynomial `∏ (X + x)` of the set,
+//! computed from the public powers of `β` in the SRS, so no party needs to know the trapdoor.
+//! A membership witness for `x` is a commitment to `∏ (X + y)` over every other element `y`,
+//! and is checked with the pairing equation `e(witness, β·H + x·H) = e(value, H)`.
+
+use crate::{
+    fft::DensePolynomial,
+    polycommit::{
+        kzg10::{Powers, VerifierKey, KZG10},
+        PCError,
+    },
+};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, Zero};
+
+use anyhow::anyhow;
+use core::ops::Mul;
+
+/// A bilinear accumulator, whose capacity is bounded by the number of powers it is updated with.
+#[derive(Clone, Debug)]
+pub struct BilinearAccumulator<E: PairingEngine> {
+    /// The characteristic polynomial of the accumulated set.
+    polynomial: DensePolynomial<E::Fr>,
+    /// The accumulator value, which is the commitment to the characteristic polynomial.
+    value: E::G1Affine,
+}
+
+impl<E: PairingEngine> BilinearAccumulator<E> {
+    /// Initializes a new accumulator for the empty set.
+    pub fn new(powers: &Powers<E>) -> Result<Self, PCError> {
+        let polynomial = DensePolynomial::from_coefficients_vec(vec![E::Fr::one()]);
+        let value = Self::commit(powers, &polynomial)?;
+        Ok(Self { polynomial, value })
+    }
+
+    /// Returns the accumulator value.
+    pub const fn value(&self) -> E::G1Affine {
+        self.value
+    }
+
+    /// Returns the number of accumulated elements.
+    pub fn len(&self) -> usize {
+        self.polynomial.degree()
+    }
+
+    /// Returns `true` if no elements are accumulated.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the given element is accumulated.
+    pub fn contains(&self, element: &E::Fr) -> bool {
+        self.polynomial.evaluate(-*element).is_zero()
+    }
+
+    /// Adds the given element to the accumulator.
+    pub fn add(&mut self, powers: &Powers<E>, element: E::Fr) -> Result<(), PCError> {
+        // Ensure the element is not already accumulated.
+        if self.contains(&element) {
+            return Err(anyhow!("The element is already in the accumulator").into());
+        }
+        // Multiply the characteristic polynomial by `(X + element)`.
+        let coeffs = &self.polynomial.coeffs;
+        let mut product = vec![E::Fr::zero(); coeffs.len() + 1];
+        for (i, coeff) in coeffs.iter().enumerate() {
+            product[i] += element * coeff;
+            product[i + 1] += coeff;
+        }
+        let polynomial = DensePolynomial::from_coefficients_vec(product);
+        // Update the accumulator value, which fails if the set has outgrown the powers.
+        self.value = Self::commit(powers, &polynomial)?;
+        self.polynomial = polynomial;
+        Ok(())
+    }
+
+    /// Removes the given element from the accumulator.
+    pub fn remove(&mut self, powers: &Powers<E>, element: &E::Fr) -> Result<(), PCError> {
+        // Ensure the element is accumulated.
+        if !self.contains(element) {
+            return Err(anyhow!("The element is not in the accumulator").into());
+        }
+        // Note: `-element` is a root of the polynomial, so the division has no remainder.
+        let (polynomial, _) = self.polynomial.divide_by_linear(-*element);
+        self.value = Self::commit(powers, &polynomial)?;
+        self.polynomial = polynomial;
+        Ok(())
+    }
+
+    /// Returns the membership witness for the given element.
+    pub fn prove_membership(&self, powers: &Powers<E>, element: &E::Fr) -> Result<E::G1Affine, PCError> {
+        // Ensure the element is accumulated.
+        if !self.contains(element) {
+            return Err(anyhow!("Cannot prove membership of an element that is not in the accumulator").into());
+        }
+        let (witness_polynomial, _) = self.polynomial.divide_by_linear(-*element);
+        Self::commit(powers, &witness_polynomial)
+    }
+
+    /// Returns `true` if the given witness proves that the element is in the set accumulated in `value`.
+    pub fn verify_membership(vk: &VerifierKey<E>, value: &E::G1Affine, element: &E::Fr, witness: &E::G1Affine) -> bool {
+        // Compute `β·H + element·H`.
+        let shifted_h = (vk.beta_h.to_projective() + vk.h.mul(*element)).to_affine();
+        // Check that `e(witness, β·H + element·H) = e(value, H)`.
+        E::product_of_pairings(
+            [(&witness.prepare(), &shifted_h.prepare()), (&(-*value).prepare(), &vk.prepared_h)].iter().copied(),
+        )
+        .is_one()
+    }
+
+    /// Returns the commitment to the given polynomial.
+    fn commit(powers: &Powers<E>, polynomial: &DensePolynomial<E::Fr>) -> Result<E::G1Affine, PCError> {
+        let (commitment, _) = KZG10::commit(powers, &polynomial.into(), None, None)?;
+        Ok(commitment.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    type Accumulator = BilinearAccumulator<Bls12_377>;
+
+    const CAPACITY: usize = 16;
+
+    #[test]
+    fn test_add_and_remove() {
+        let rng = &mut TestRng::default();
+        let pp = KZG10::<Bls12_377>::load_srs(CAPACITY).unwrap();
+        let (powers, vk) = KZG10::trim(&pp, CAPACITY, None);
+
+        let mut accumulator = Accumulator::new(&powers).unwrap();
+        let empty_value = accumulator.value();
+        assert!(accumulator.is_empty());
+
+        // Add the elements, checking the witnesses of every element added so far.
+        let elements = (0..CAPACITY).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        for (i, element) in elements.iter().enumerate() {
+            accumulator.add(&powers, *element).unwrap();
+            assert_eq!(accumulator.len(), i + 1);
+            for member in &elements[..=i] {
+                let witness = accumulator.prove_membership(&powers, member).unwrap();
+                assert!(Accumulator::verify_membership(&vk, &accumulator.value(), member, &witness));
+            }
+        }
+
+        // A witness does not verify for another element, or against a stale value.
+        let witness = accumulator.prove_membership(&powers, &elements[0]).unwrap();
+        assert!(!Accumulator::verify_membership(&vk, &accumulator.value(), &elements[1], &witness));
+        assert!(!Accumulator::verify_membership(&vk, &empty_value, &elements[0], &witness));
+
+        // Remove the elements in reverse order.
+        for element in elements.iter().rev() {
+            accumulator.remove(&powers, element).unwrap();
+            assert!(!accumulator.contains(element));
+        }
+        assert!(accumulator.is_empty());
+        assert_eq!(accumulator.value(), empty_value);
+    }
+
+    #[test]
+    fn test_value_is_independent_of_order() {
+        let rng = &mut TestRng::default();
+        let pp = KZG10::<Bls12_377>::load_srs(CAPACITY).unwrap();
+        let (powers, _) = KZG10::trim(&pp, CAPACITY, None);
+
+        let elements = (0..4).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let mut a = Accumulator::new(&powers).unwrap();
+        let mut b = Accumulator::new(&powers).unwrap();
+        for (x, y) in elements.iter().zip(elements.iter().rev()) {
+            a.add(&powers, *x).unwrap();
+            b.add(&powers, *y).unwrap();
+        }
+        assert_eq!(a.value(), b.value());
+    }
+
+    #[test]
+    fn test_invalid_updates() {
+        let rng = &mut TestRng::default();
+        let pp = KZG10::<Bls12_377>::load_srs(CAPACITY).unwrap();
+        let (powers, _) = KZG10::trim(&pp, CAPACITY, None);
+
+        let mut accumulator = Accumulator::new(&powers).unwrap();
+        let element = Fr::rand(rng);
+        // Elements that are not accumulated cannot be removed or proven.
+        assert!(accumulator.remove(&powers, &element).is_err());
+        assert!(accumulator.prove_membership(&powers, &element).is_err());
+        // Elements cannot be added twice.
+        accumulator.add(&powers, element).unwrap();
+        assert!(accumulator.add(&powers, element).is_err());
+        assert_eq!(accumulator.len(), 1);
+    }
+
+    #[test]
+    fn test_capacity_is_bounded_by_powers() {
+        let rng = &mut TestRng::default();
+        let pp = KZG10::<Bls12_377>::load_srs(CAPACITY).unwrap();
+        let (powers, _) = KZG10::trim(&pp, CAPACITY, None);
+
+        let mut accumulator = Accumulator::new(&powers).unwrap();
+        while accumulator.len() < powers.size() - 1 {
+            accumulator.add(&powers, Fr::rand(rng)).unwrap();
+        }
+        // The set has outgrown the powers, and the accumulator is left unchanged.
+        let value = accumulator.value();
+        assert!(accumulator.add(&powers, Fr::rand(rng)).is_err());
+        assert_eq!(accumulator.value(), value);
+    }
+}
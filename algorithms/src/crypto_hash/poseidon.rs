@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{nonnative_params::*, AlgebraicSponge, DuplexSpongeMode};
+use crate::{nonnative_params::*, transcript_separator, AlgebraicSponge, DuplexSpongeMode, Transcript};
 use snarkvm_fields::{FieldParameters, PoseidonParameters, PrimeField, ToConstraintField};
 use snarkvm_utilities::{BigInteger, FromBits, ToBits};
 
@@ -206,6 +206,34 @@ impl<F: PrimeField, const RATE: usize> AlgebraicSponge<F, RATE> for PoseidonSpon
     }
 }
 
+impl<F: PrimeField, const RATE: usize> Transcript<F> for PoseidonSponge<F, RATE, 1> {
+    fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        let mut bytes = transcript_separator(label, message.len());
+        bytes.extend_from_slice(message);
+        self.absorb_bytes(&bytes);
+    }
+
+    fn append_elements<T: ToConstraintField<F>>(&mut self, label: &[u8], elements: &[T]) {
+        self.absorb_bytes(&transcript_separator(label, elements.len()));
+        self.absorb_native_field_elements(elements);
+    }
+
+    fn append_nonnative_elements<Target: PrimeField>(&mut self, label: &[u8], elements: &[Target]) {
+        self.absorb_bytes(&transcript_separator(label, elements.len()));
+        self.absorb_nonnative_field_elements(elements.iter().copied());
+    }
+
+    fn challenge_elements(&mut self, label: &[u8], num: usize) -> SmallVec<[F; 10]> {
+        self.absorb_bytes(&transcript_separator(label, num));
+        self.squeeze_native_field_elements(num)
+    }
+
+    fn challenge_nonnative_elements<Target: PrimeField>(&mut self, label: &[u8], num: usize) -> SmallVec<[Target; 10]> {
+        self.absorb_bytes(&transcript_separator(label, num));
+        self.squeeze_nonnative_field_elements(num)
+    }
+}
+
 impl<F: PrimeField, const RATE: usize> PoseidonSponge<F, RATE, 1> {
     #[inline]
     fn apply_ark(&mut self, round_number: usize) {
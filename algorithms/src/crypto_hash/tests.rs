@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode};
+use crate::{crypto_hash::PoseidonSponge, AlgebraicSponge, DuplexSpongeMode, Transcript};
 use snarkvm_curves::bls12_377::Fr;
 use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
 
@@ -86,3 +86,30 @@ fn bls12_377_fr_poseidon_default_parameters_test() {
     single_rate_test::<7>();
     single_rate_test::<8>();
 }
+
+#[test]
+fn test_transcript_domain_separation() {
+    type Sponge = PoseidonSponge<Fr, 2, 1>;
+
+    let challenge = |messages: &[(&str, &str)]| {
+        let mut transcript = Sponge::new();
+        for (label, message) in messages {
+            transcript.append_message(label.as_bytes(), message.as_bytes());
+        }
+        transcript.challenge_element(b"challenge")
+    };
+
+    // The transcript is deterministic.
+    assert_eq!(challenge(&[("a", "message")]), challenge(&[("a", "message")]));
+    // The challenge depends on the label.
+    assert_ne!(challenge(&[("a", "message")]), challenge(&[("b", "message")]));
+    // The challenge depends on the message boundaries.
+    assert_ne!(challenge(&[("a", "mess"), ("a", "age")]), challenge(&[("a", "message")]));
+    assert_ne!(challenge(&[("a", "")]), challenge(&[("", "a")]));
+
+    // The challenge depends on the challenge label.
+    let mut transcript = Sponge::new();
+    transcript.append_elements(b"elements", &[Fr::from(1u64), Fr::from(2u64)]);
+    let mut other = transcript.clone();
+    assert_ne!(transcript.challenge_element(b"alpha"), other.challenge_element(b"beta"));
+}
@@ -15,26 +15,27 @@
 use crate::{r1cs::SynthesisError, snark::varuna::ahp::AHPError};
 use snarkvm_fields::ConstraintFieldError;
 
+/// Describes the failure modes of a SNARK.
+///
+/// Each failure mode has a stable numeric code (see [`SNARKError::code`]), and wrapped errors
+/// are exposed through [`std::error::Error::source`] rather than being flattened into strings.
 #[derive(Debug, Error)]
 pub enum SNARKError {
-    #[error("{}", _0)]
+    #[error(transparent)]
     AnyhowError(#[from] anyhow::Error),
 
-    #[error("{}", _0)]
+    #[error("Failed to convert an object into constraint field elements")]
     ConstraintFieldError(#[from] ConstraintFieldError),
 
-    #[error("{}: {}", _0, _1)]
-    Crate(&'static str, String),
-
     #[error("Expected a circuit-specific SRS in SNARK")]
     ExpectedCircuitSpecificSRS,
 
-    #[error("{}", _0)]
-    Message(String),
-
-    #[error("{}", _0)]
+    #[error("Failed to synthesize the circuit")]
     SynthesisError(#[from] SynthesisError),
 
+    #[error("Failed to run the AHP")]
+    AHPError(#[from] AHPError),
+
     #[error("Batch size was zero; must be at least 1")]
     EmptyBatch,
 
@@ -45,8 +46,75 @@ pub enum SNARKError {
     CircuitNotFound,
 }
 
-impl From<AHPError> for SNARKError {
-    fn from(err: AHPError) -> Self {
-        SNARKError::Crate("AHPError", format!("{err:?}"))
+impl SNARKError {
+    /// Returns the stable numeric code of this error.
+    ///
+    /// Codes in `100..200` are synthesis errors, codes in `200..300` are AHP errors,
+    /// and codes in `300..400` are SNARK errors. Codes are never reassigned.
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::AnyhowError(..) => 300,
+            Self::ConstraintFieldError(..) => 301,
+            Self::ExpectedCircuitSpecificSRS => 302,
+            Self::SynthesisError(error) => error.code(),
+            Self::AHPError(error) => error.code(),
+            Self::EmptyBatch => 305,
+            Self::BatchSizeMismatch => 306,
+            Self::CircuitNotFound => 307,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_code_of_wrapped_error() {
+        let error = SNARKError::from(AHPError::from(SynthesisError::Unsatisfiable));
+        assert_eq!(error.code(), SynthesisError::Unsatisfiable.code());
+
+        let error = SNARKError::from(AHPError::PolyTooLarge);
+        assert_eq!(error.code(), AHPError::PolyTooLarge.code());
+    }
+
+    #[test]
+    fn test_source_chain() {
+        let error = SNARKError::from(AHPError::from(SynthesisError::Unsatisfiable));
+        // Walk the source chain to the root cause.
+        let ahp_error = error.source().unwrap();
+        assert_eq!(ahp_error.to_string(), AHPError::from(SynthesisError::Unsatisfiable).to_string());
+        let synthesis_error = ahp_error.source().unwrap();
+        assert_eq!(synthesis_error.to_string(), SynthesisError::Unsatisfiable.to_string());
+        assert!(synthesis_error.source().is_none());
+    }
+
+    #[test]
+    fn test_codes_are_in_range() {
+        for error in [
+            SNARKError::ExpectedCircuitSpecificSRS,
+            SNARKError::EmptyBatch,
+            SNARKError::BatchSizeMismatch,
+            SNARKError::CircuitNotFound,
+        ] {
+            assert!((300..400).contains(&error.code()));
+        }
+        for error in [
+            AHPError::BatchSizeIsZero,
+            AHPError::InstanceDoesNotMatchIndex,
+            AHPError::NonSquareMatrix,
+            AHPError::PolyTooLarge,
+        ] {
+            assert!((200..300).contains(&error.code()));
+        }
+        for error in [
+            SynthesisError::AssignmentMissing,
+            SynthesisError::DivisionByZero,
+            SynthesisError::PolyTooLarge,
+            SynthesisError::UnconstrainedVariable,
+        ] {
+            assert!((100..200).contains(&error.code()));
+        }
     }
 }
@@ -302,11 +302,52 @@ impl<F: FftField> EvaluationDomain<F> {
         tau.pow([self.size]) - F::one()
     }
 
+    /// Evaluates the vanishing polynomial for this domain over the coset `offset * D`,
+    /// where `D` is the given domain, which must be at least as large as this domain.
+    ///
+    /// Over `offset * D`, the vanishing polynomial takes only `k = |D| / self.size` distinct values,
+    /// so this returns those `k` values, where the `i`-th element of `offset * D` maps to the value at `i % k`.
+    pub fn evaluate_vanishing_polynomial_over_coset_domain(&self, domain: &Self, offset: F) -> Option<Vec<F>> {
+        if domain.size < self.size {
+            return None;
+        }
+        // Compute `offset^self.size * (g_D^self.size)^i - 1` for `i` in `0..k`.
+        let num_values = (domain.size / self.size) as usize;
+        let offset_pow = offset.pow([self.size]);
+        let root = domain.group_gen.pow([self.size]);
+        let mut values = compute_powers_and_mul_by_const_serial(num_values, root, offset_pow);
+        values.iter_mut().for_each(|value| *value -= F::one());
+        Some(values)
+    }
+
     /// Return an iterator over the elements of the domain.
     pub fn elements(&self) -> Elements<F> {
         Elements { cur_elem: F::one(), cur_pow: 0, domain: *self }
     }
 
+    /// Returns the `i`-th element of the domain, i.e. `g^i`, without computing the preceding elements.
+    pub fn element(&self, i: usize) -> F {
+        self.group_gen.pow([i as u64])
+    }
+
+    /// Return an indexed parallel iterator over the elements of the domain.
+    /// Each thread computes the first element of its range with one exponentiation,
+    /// and the following elements with one multiplication each.
+    #[cfg(not(feature = "serial"))]
+    pub fn par_elements(&self) -> impl '_ + IndexedParallelIterator<Item = F> {
+        (0..self.size()).into_par_iter().map_init(
+            || None,
+            move |previous: &mut Option<(usize, F)>, i| {
+                let element = match *previous {
+                    Some((j, element)) if j + 1 == i => element * self.group_gen,
+                    _ => self.element(i),
+                };
+                *previous = Some((i, element));
+                element
+            },
+        )
+    }
+
     /// The target polynomial is the zero polynomial in our
     /// evaluation domain, so we must perform division over
     /// a coset.
@@ -875,8 +916,15 @@ impl<F: FftField> Iterator for Elements<F> {
             Some(cur_elem)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.domain.size - self.cur_pow) as usize;
+        (remaining, Some(remaining))
+    }
 }
 
+impl<F: FftField> ExactSizeIterator for Elements<F> {}
+
 /// An iterator over the elements of the domain.
 #[derive(Clone, Eq, PartialEq, Debug, CanonicalDeserialize, CanonicalSerialize)]
 pub struct FFTPrecomputation<F: FftField> {
@@ -1034,6 +1082,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn vanishing_polynomial_over_coset() {
+        let rng = &mut TestRng::default();
+        for log_size in 0..6 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let offset = Fr::rand(rng);
+            // The vanishing polynomial is constant over `offset * H`, where it equals `offset^|H| - 1`.
+            let expected = domain.evaluate_vanishing_polynomial(offset);
+            for element in domain.elements() {
+                assert_eq!(domain.evaluate_vanishing_polynomial(offset * element), expected);
+            }
+            // Check the evaluations over a coset of a larger domain.
+            for log_larger_size in log_size..log_size + 3 {
+                let larger = EvaluationDomain::<Fr>::new(1 << log_larger_size).unwrap();
+                let values = domain.evaluate_vanishing_polynomial_over_coset_domain(&larger, offset).unwrap();
+                assert_eq!(values.len(), 1 << (log_larger_size - log_size));
+                for (i, element) in larger.elements().enumerate() {
+                    assert_eq!(domain.evaluate_vanishing_polynomial(offset * element), values[i % values.len()]);
+                }
+            }
+            // A smaller domain is rejected.
+            if log_size > 0 {
+                let smaller = EvaluationDomain::<Fr>::new(1 << (log_size - 1)).unwrap();
+                assert!(domain.evaluate_vanishing_polynomial_over_coset_domain(&smaller, offset).is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn test_elements() {
+        for log_size in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let expected = domain.elements().collect::<Vec<_>>();
+            assert_eq!(domain.elements().len(), domain.size());
+            for (i, element) in expected.iter().enumerate() {
+                assert_eq!(domain.element(i), *element);
+            }
+            #[cfg(not(feature = "serial"))]
+            {
+                use rayon::prelude::*;
+                assert_eq!(domain.par_elements().collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
     /// Tests that the roots of unity result is the same as domain.elements().
     #[test]
     fn test_roots_of_unity() {
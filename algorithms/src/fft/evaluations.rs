@@ -116,6 +116,24 @@ impl<'a, F: PrimeField> MulAssign<&'a Evaluations<F>> for Evaluations<F> {
     }
 }
 
+impl<'a, F: PrimeField> Mul<F> for &'a Evaluations<F> {
+    type Output = Evaluations<F>;
+
+    #[inline]
+    fn mul(self, other: F) -> Evaluations<F> {
+        let mut result = self.clone();
+        result *= other;
+        result
+    }
+}
+
+impl<F: PrimeField> MulAssign<F> for Evaluations<F> {
+    #[inline]
+    fn mul_assign(&mut self, other: F) {
+        cfg_iter_mut!(self.evaluations).for_each(|a| *a *= other);
+    }
+}
+
 impl<'a, 'b, F: PrimeField> Add<&'a Evaluations<F>> for &'b Evaluations<F> {
     type Output = Evaluations<F>;
 
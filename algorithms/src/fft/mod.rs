@@ -22,7 +22,7 @@ pub mod evaluations;
 pub use evaluations::Evaluations;
 
 pub mod polynomial;
-pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
+pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial, SubproductTree};
 
 #[cfg(test)]
 mod tests;
@@ -130,6 +130,24 @@ impl<F: Field> DensePolynomial<F> {
         &self.coeffs
     }
 
+    /// Divides `self` by `(x - point)` in linear time, as used for KZG opening witnesses.
+    /// Returns the quotient and the remainder, which is the evaluation of `self` at `point`.
+    pub fn divide_by_linear(&self, point: F) -> (DensePolynomial<F>, F) {
+        if self.coeffs.len() <= 1 {
+            return (DensePolynomial::zero(), self.coeffs.first().copied().unwrap_or_else(F::zero));
+        }
+        // Perform synthetic division, from the leading coefficient down.
+        let mut quotient = vec![F::zero(); self.coeffs.len() - 1];
+        let mut remainder = F::zero();
+        for (i, coeff) in self.coeffs.iter().enumerate().rev() {
+            remainder = remainder * point + coeff;
+            if i > 0 {
+                quotient[i - 1] = remainder;
+            }
+        }
+        (DensePolynomial::from_coefficients_vec(quotient), remainder)
+    }
+
     /// Perform a naive n^2 multiplication of `self` by `other`.
     #[cfg(test)]
     fn naive_mul(&self, other: &Self) -> Self {
@@ -178,6 +196,18 @@ impl<F: PrimeField> DensePolynomial<F> {
         let poly: Polynomial<'_, F> = self.into();
         Polynomial::<F>::evaluate_over_domain(poly, domain)
     }
+
+    /// Evaluates `self` at each of the given (arbitrary) points, using a subproduct tree.
+    /// To evaluate many polynomials at the same points, build the `SubproductTree` once and reuse it.
+    pub fn evaluate_at_points(&self, points: &[F]) -> Result<Vec<F>> {
+        Ok(super::SubproductTree::new(points)?.evaluate(self))
+    }
+
+    /// Returns the unique polynomial of degree less than `points.len()`, which evaluates to
+    /// `evaluations[i]` at `points[i]`, using a subproduct tree. The points must be distinct.
+    pub fn interpolate_at_points(points: &[F], evaluations: &[F]) -> Result<Self> {
+        super::SubproductTree::new(points)?.interpolate(evaluations)
+    }
 }
 
 impl<F: Field> From<super::SparsePolynomial<F>> for DensePolynomial<F> {
@@ -376,6 +406,25 @@ impl<'a, F: Field> AddAssign<&'a super::SparsePolynomial<F>> for DensePolynomial
     }
 }
 
+/// Performs O(s * n) multiplication of a sparse polynomial with `s` terms by a dense polynomial.
+impl<'a, 'b, F: Field> Mul<&'a DensePolynomial<F>> for &'b super::SparsePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    #[inline]
+    fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
+        if self.is_zero() || other.is_zero() {
+            DensePolynomial::zero()
+        } else {
+            let mut result = vec![F::zero(); self.degree() + other.degree() + 1];
+            for (i, self_coeff) in self.coeffs() {
+                // Add the dense polynomial, shifted by `i` and scaled by the sparse coefficient.
+                result[*i..].iter_mut().zip(&other.coeffs).for_each(|(r, c)| *r += *self_coeff * c);
+            }
+            DensePolynomial::from_coefficients_vec(result)
+        }
+    }
+}
+
 impl<'a, F: Field> Sub<&'a super::SparsePolynomial<F>> for DensePolynomial<F> {
     type Output = Self;
 
@@ -703,6 +752,40 @@ mod tests {
         // Note PolyMultiplier doesn't support a evaluations with no polynomials
     }
 
+    #[test]
+    fn divide_by_linear() {
+        let rng = &mut TestRng::default();
+
+        for degree in 0..20 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let point = Fr::rand(rng);
+            let (quotient, remainder) = p.divide_by_linear(point);
+            // The remainder is the evaluation at the point.
+            assert_eq!(remainder, p.evaluate(point));
+            // The quotient matches long division by `(x - point)`.
+            let divisor = DensePolynomial::from_coefficients_vec(vec![-point, Fr::one()]);
+            assert_eq!(quotient, &p / &divisor);
+        }
+        assert_eq!(DensePolynomial::<Fr>::zero().divide_by_linear(Fr::one()), (DensePolynomial::zero(), Fr::zero()));
+    }
+
+    #[test]
+    fn mul_sparse_by_dense() {
+        let rng = &mut TestRng::default();
+
+        for degree in 0..20 {
+            let dense = DensePolynomial::<Fr>::rand(degree, rng);
+            let sparse = SparsePolynomial::from_coefficients(vec![
+                (0, Fr::rand(rng)),
+                (degree, Fr::rand(rng)),
+                (2 * degree + 1, Fr::rand(rng)),
+            ]);
+            let expected = dense.naive_mul(&DensePolynomial::from(sparse.clone()));
+            assert_eq!(&sparse * &dense, expected);
+        }
+        assert!((&SparsePolynomial::zero() * &DensePolynomial::<Fr>::rand(4, rng)).is_zero());
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut TestRng::default();
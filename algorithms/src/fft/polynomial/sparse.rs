@@ -113,16 +113,27 @@ impl<F: Field> SparsePolynomial<F> {
 impl<F: PrimeField> SparsePolynomial<F> {
     /// Evaluate `self` over `domain`.
     pub fn evaluate_over_domain_by_ref(&self, domain: EvaluationDomain<F>) -> Evaluations<F> {
+        // For polynomials with few terms, evaluating each term directly is cheaper than an FFT.
+        if self.coeffs.len() <= domain.log_size_of_group as usize {
+            let mut evaluations = vec![F::zero(); domain.size()];
+            for (i, coeff) in &self.coeffs {
+                // The term `coeff * x^i` at the `j`-th element of the domain is `coeff * (g^i)^j`.
+                let step = domain.group_gen.pow([*i as u64]);
+                let mut term = *coeff;
+                for evaluation in evaluations.iter_mut() {
+                    *evaluation += term;
+                    term *= step;
+                }
+            }
+            return Evaluations::from_vec_and_domain(evaluations, domain);
+        }
         let poly: Polynomial<'_, F> = self.into();
         Polynomial::<F>::evaluate_over_domain(poly, domain)
-        // unimplemented!("current implementation does not produce evals in correct order")
     }
 
     /// Evaluate `self` over `domain`.
     pub fn evaluate_over_domain(self, domain: EvaluationDomain<F>) -> Evaluations<F> {
-        let poly: Polynomial<'_, F> = self.into();
-        Polynomial::<F>::evaluate_over_domain(poly, domain)
-        // unimplemented!("current implementation does not produce evals in correct order")
+        self.evaluate_over_domain_by_ref(domain)
     }
 }
 impl<F: PrimeField> core::ops::MulAssign<F> for SparsePolynomial<F> {
@@ -174,6 +185,7 @@ mod tests {
     use crate::fft::{DensePolynomial, EvaluationDomain, SparsePolynomial};
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::One;
+    use snarkvm_utilities::rand::{TestRng, Uniform};
 
     #[test]
     fn evaluate_over_domain() {
@@ -191,4 +203,23 @@ mod tests {
             assert_eq!(evals2.interpolate(), dense_poly);
         }
     }
+
+    #[test]
+    fn evaluate_over_domain_matches_fft() {
+        let rng = &mut TestRng::default();
+
+        for size in 2..10 {
+            let domain = EvaluationDomain::new(1 << size).unwrap();
+            // Include a term whose degree exceeds the domain size.
+            let sparse_poly = SparsePolynomial::from_coefficients(vec![
+                (0, Fr::rand(rng)),
+                (3, Fr::rand(rng)),
+                ((1 << size) + 5, Fr::rand(rng)),
+            ]);
+            let evals = sparse_poly.evaluate_over_domain_by_ref(domain);
+            for (element, evaluation) in domain.elements().zip(evals.evaluations) {
+                assert_eq!(sparse_poly.evaluate(element), evaluation);
+            }
+        }
+    }
 }
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{DensePolynomial, Polynomial};
+use snarkvm_fields::{batch_inversion, PrimeField};
+
+use anyhow::{bail, ensure, Result};
+
+/// Below this divisor degree, remainders are computed with schoolbook long division,
+/// which is faster than the Newton iteration for small inputs.
+const NAIVE_DIVISION_THRESHOLD: usize = 64;
+
+/// A subproduct tree over a set of points `x_0, ..., x_{n-1}`.
+///
+/// Each node stores the product of `(X - x_i)` over the points in its subtree, so the root stores
+/// the vanishing polynomial of the points. The tree supports evaluating a polynomial at all `n` points,
+/// and interpolating a polynomial from its evaluations at all `n` points, in `O(M(n) log n)` time,
+/// where `M(n)` is the cost of an FFT-based polynomial multiplication.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubproductTree<F: PrimeField> {
+    /// The product of `(X - x_i)` over the points in this subtree.
+    polynomial: DensePolynomial<F>,
+    /// The inverse of the reversal of `polynomial`, modulo `X^(degree + 1)`.
+    reversed_inverse: DensePolynomial<F>,
+    /// The left and right subtrees, or `None` if this node is a leaf.
+    children: Option<Box<(SubproductTree<F>, SubproductTree<F>)>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Initializes a new subproduct tree over the given points.
+    pub fn new(points: &[F]) -> Result<Self> {
+        ensure!(!points.is_empty(), "A subproduct tree requires at least one point");
+        Ok(Self::build(points))
+    }
+
+    /// Returns the number of points in the tree.
+    pub fn num_points(&self) -> usize {
+        self.polynomial.degree()
+    }
+
+    /// Returns the vanishing polynomial of the points, i.e. the product of `(X - x_i)`.
+    pub fn vanishing_polynomial(&self) -> &DensePolynomial<F> {
+        &self.polynomial
+    }
+
+    /// Evaluates the given polynomial at each of the points, in order.
+    pub fn evaluate(&self, polynomial: &DensePolynomial<F>) -> Vec<F> {
+        let mut evaluations = Vec::with_capacity(self.num_points());
+        self.evaluate_remainder(self.remainder(polynomial), &mut evaluations);
+        evaluations
+    }
+
+    /// Returns the unique polynomial of degree less than the number of points,
+    /// which evaluates to `evaluations[i]` at the `i`-th point.
+    pub fn interpolate(&self, evaluations: &[F]) -> Result<DensePolynomial<F>> {
+        ensure!(
+            evaluations.len() == self.num_points(),
+            "Expected {} evaluations for interpolation, found {}",
+            self.num_points(),
+            evaluations.len()
+        );
+        // Evaluate the derivative of the vanishing polynomial at each point.
+        // This is the denominator of each Lagrange coefficient, and is zero if and only if the point is repeated.
+        let mut weights = self.evaluate(&derivative(&self.polynomial));
+        if weights.iter().any(|weight| weight.is_zero()) {
+            bail!("Interpolation requires the points to be distinct")
+        }
+        batch_inversion(&mut weights);
+        weights.iter_mut().zip(evaluations).for_each(|(weight, evaluation)| *weight *= evaluation);
+        // Combine the weighted Lagrange basis polynomials, from the leaves up.
+        Ok(self.linear_combination(&weights))
+    }
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Builds the subtree over the given (non-empty) points.
+    fn build(points: &[F]) -> Self {
+        let (polynomial, children) = match points.len() {
+            1 => (DensePolynomial::from_coefficients_vec(vec![-points[0], F::one()]), None),
+            num_points => {
+                let (left, right) = points.split_at(num_points / 2);
+                let (left, right) = (Self::build(left), Self::build(right));
+                (&left.polynomial * &right.polynomial, Some(Box::new((left, right))))
+            }
+        };
+        let reversed = polynomial.coeffs.iter().rev().copied().collect::<Vec<_>>();
+        let reversed_inverse = inverse_mod_x_pow(&reversed, polynomial.degree() + 1);
+        Self { polynomial, reversed_inverse, children }
+    }
+
+    /// Returns the remainder of the given polynomial modulo the polynomial of this node.
+    fn remainder(&self, dividend: &DensePolynomial<F>) -> DensePolynomial<F> {
+        let divisor_degree = self.polynomial.degree();
+        if dividend.is_zero() || dividend.degree() < divisor_degree {
+            return dividend.clone();
+        }
+        if divisor_degree < NAIVE_DIVISION_THRESHOLD {
+            let (_, remainder) = Polynomial::from(dividend)
+                .divide_with_q_and_r(&Polynomial::from(&self.polynomial))
+                .expect("The divisor of a subproduct tree is never zero");
+            return remainder;
+        }
+
+        // The reversal of the quotient is the reversal of the dividend,
+        // multiplied by the inverse of the reversal of the divisor, modulo `X^(quotient_degree + 1)`.
+        let quotient_len = dividend.degree() - divisor_degree + 1;
+        let reversed_dividend =
+            DensePolynomial::from_coefficients_vec(dividend.coeffs.iter().rev().take(quotient_len).copied().collect());
+        let reversed_quotient = match quotient_len <= divisor_degree + 1 {
+            true => &reversed_dividend * &self.reversed_inverse,
+            // The root may be asked to reduce a dividend of arbitrary degree.
+            false => {
+                let reversed = self.polynomial.coeffs.iter().rev().copied().collect::<Vec<_>>();
+                &reversed_dividend * &inverse_mod_x_pow(&reversed, quotient_len)
+            }
+        };
+        let mut quotient = reversed_quotient.coeffs;
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+        let quotient = DensePolynomial::from_coefficients_vec(quotient);
+
+        // The remainder is `dividend - quotient * divisor`, which has degree less than the divisor.
+        let mut remainder = (dividend - &(&quotient * &self.polynomial)).coeffs;
+        remainder.truncate(divisor_degree);
+        DensePolynomial::from_coefficients_vec(remainder)
+    }
+
+    /// Pushes the evaluations at the points of this subtree, given the remainder modulo the polynomial of this node.
+    fn evaluate_remainder(&self, remainder: DensePolynomial<F>, evaluations: &mut Vec<F>) {
+        match &self.children {
+            // The remainder modulo `(X - x_i)` is the constant `p(x_i)`.
+            None => evaluations.push(remainder.coeffs.first().copied().unwrap_or_else(F::zero)),
+            Some(children) => {
+                let (left, right) = children.as_ref();
+                left.evaluate_remainder(left.remainder(&remainder), evaluations);
+                right.evaluate_remainder(right.remainder(&remainder), evaluations);
+            }
+        }
+    }
+
+    /// Returns the sum of `weights[i]` times the product of `(X - x_j)` over the points `j != i` in this subtree.
+    fn linear_combination(&self, weights: &[F]) -> DensePolynomial<F> {
+        match &self.children {
+            None => DensePolynomial::from_coefficients_slice(weights),
+            Some(children) => {
+                let (left, right) = children.as_ref();
+                let (left_weights, right_weights) = weights.split_at(left.num_points());
+                let left_combination = left.linear_combination(left_weights);
+                let right_combination = right.linear_combination(right_weights);
+                &(&left_combination * &right.polynomial) + &(&right_combination * &left.polynomial)
+            }
+        }
+    }
+}
+
+/// Returns the inverse of the given polynomial modulo `X^precision`, using Newton iteration.
+/// The constant coefficient of the polynomial must be nonzero.
+fn inverse_mod_x_pow<F: PrimeField>(coeffs: &[F], precision: usize) -> DensePolynomial<F> {
+    let mut inverse = DensePolynomial::from_coefficients_vec(vec![coeffs[0].inverse().unwrap()]);
+    let mut current_precision = 1;
+    while current_precision < precision {
+        current_precision = (2 * current_precision).min(precision);
+        // Lift the inverse from precision `k` to `2k`, as `g = g * (2 - f * g) mod X^2k`.
+        let truncated = DensePolynomial::from_coefficients_slice(&coeffs[..coeffs.len().min(current_precision)]);
+        let mut correction = vec![F::zero(); current_precision];
+        for (c, product) in correction.iter_mut().zip(&(&truncated * &inverse).coeffs) {
+            *c = -*product;
+        }
+        correction[0] += F::one().double();
+        let mut lifted = (&inverse * &DensePolynomial::from_coefficients_vec(correction)).coeffs;
+        lifted.truncate(current_precision);
+        inverse = DensePolynomial::from_coefficients_vec(lifted);
+    }
+    inverse
+}
+
+/// Returns the formal derivative of the given polynomial.
+fn derivative<F: PrimeField>(polynomial: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let coeffs = polynomial.coeffs.iter().enumerate().skip(1).map(|(i, c)| F::from(i as u64) * c).collect();
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    #[test]
+    fn test_evaluate() {
+        let rng = &mut TestRng::default();
+        for num_points in [1, 2, 3, 7, 64, 65, 200] {
+            let points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let tree = SubproductTree::new(&points).unwrap();
+            // Check polynomials of lower, equal, and (much) higher degree than the number of points.
+            for degree in [0, num_points - 1, num_points, 3 * num_points + 5] {
+                let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+                let expected = points.iter().map(|point| polynomial.evaluate(*point)).collect::<Vec<_>>();
+                assert_eq!(tree.evaluate(&polynomial), expected);
+            }
+            assert_eq!(tree.evaluate(&DensePolynomial::zero()), vec![Fr::zero(); num_points]);
+        }
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let rng = &mut TestRng::default();
+        for num_points in [1, 2, 3, 7, 64, 65, 200] {
+            let points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let tree = SubproductTree::new(&points).unwrap();
+            let polynomial = DensePolynomial::<Fr>::rand(num_points - 1, rng);
+            let evaluations = points.iter().map(|point| polynomial.evaluate(*point)).collect::<Vec<_>>();
+            assert_eq!(tree.interpolate(&evaluations).unwrap(), polynomial);
+        }
+    }
+
+    #[test]
+    fn test_vanishing_polynomial() {
+        let rng = &mut TestRng::default();
+        let points = (0..100).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let tree = SubproductTree::new(&points).unwrap();
+        assert_eq!(tree.num_points(), 100);
+        for point in &points {
+            assert!(tree.vanishing_polynomial().evaluate(*point).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let rng = &mut TestRng::default();
+        assert!(SubproductTree::<Fr>::new(&[]).is_err());
+
+        // Interpolation requires one evaluation per point.
+        let points = (0..10).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let tree = SubproductTree::new(&points).unwrap();
+        assert!(tree.interpolate(&[Fr::one(); 9]).is_err());
+
+        // Interpolation requires distinct points.
+        let mut points = points;
+        points[7] = points[2];
+        let tree = SubproductTree::new(&points).unwrap();
+        assert!(tree.interpolate(&[Fr::one(); 10]).is_err());
+    }
+}
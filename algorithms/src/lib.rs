@@ -29,6 +29,8 @@ extern crate thiserror;
 
 pub use snarkvm_utilities::{cfg_chunks, cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut, cfg_reduce};
 
+#[cfg(feature = "polycommit")]
+pub mod accumulator;
 #[cfg(feature = "crypto_hash")]
 pub mod crypto_hash;
 #[cfg(feature = "fft")]
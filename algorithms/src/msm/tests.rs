@@ -14,7 +14,7 @@
 
 use crate::msm::*;
 use snarkvm_curves::{
-    bls12_377::{Fr, G1Projective},
+    bls12_377::{Fr, G1Projective, G2Projective},
     traits::{AffineCurve, ProjectiveCurve},
 };
 use snarkvm_fields::{PrimeField, Zero};
@@ -50,6 +50,21 @@ fn variable_base_test_with_bls12() {
     assert_eq!(naive.to_affine(), fast.to_affine());
 }
 
+#[test]
+fn variable_base_test_with_bls12_g2() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = TestRng::default();
+
+    let v = (0..SAMPLES).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G2Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+
+    let naive = naive_variable_base_msm(g.as_slice(), v.as_slice());
+    let fast = VariableBase::msm(g.as_slice(), v.as_slice());
+
+    assert_eq!(naive.to_affine(), fast.to_affine());
+}
+
 #[test]
 fn variable_base_test_with_bls12_unequal_numbers() {
     const SAMPLES: usize = 1 << 10;
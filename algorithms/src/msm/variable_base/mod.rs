@@ -18,7 +18,10 @@ pub mod standard;
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
+use snarkvm_curves::{
+    bls12_377::{G1Affine, G2Affine},
+    traits::AffineCurve,
+};
 use snarkvm_fields::PrimeField;
 
 use core::any::TypeId;
@@ -27,6 +30,16 @@ pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        // Note: The timer records the elapsed time when it is dropped, after the MSM returns.
+        #[cfg(feature = "metrics")]
+        let _timer = {
+            snarkvm_metrics::increment_counter(snarkvm_metrics::algorithms::MSM_CALLS, 1);
+            snarkvm_metrics::HistogramTimer::start(snarkvm_metrics::algorithms::MSM_TIME)
+        };
+        Self::msm_inner(bases, scalars)
+    }
+
+    fn msm_inner<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
@@ -41,6 +54,11 @@ impl VariableBase {
             }
             batched::msm(bases, scalars)
         }
+        // For the BLS12-377 G2 group, batched addition also outperforms Pippenger's algorithm,
+        // as the inversions over the quadratic extension field are amortized across the batch.
+        else if TypeId::of::<G>() == TypeId::of::<G2Affine>() {
+            batched::msm(bases, scalars)
+        }
         // For all other curves, we perform variable base MSM using Pippenger's algorithm.
         else {
             standard::msm(bases, scalars)
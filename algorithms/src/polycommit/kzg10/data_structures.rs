@@ -390,6 +390,32 @@ impl<'a, E: PairingEngine> AddAssign<(E::Fr, &'a KZGRandomness<E>)> for KZGRando
     }
 }
 
+/// `KZGAccumulator` is a deferred evaluation check that is output by `KZG10::accumulate`.
+///
+/// An accumulator is valid if `e(c, h) = e(w, beta_h)`, which `KZG10::decide` checks with a single
+/// product of pairings. Any number of evaluation proofs can be folded into an accumulator,
+/// so that a long chain of proofs is decided once at the end.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KZGAccumulator<E: PairingEngine> {
+    /// The random linear combination of the witness commitments.
+    pub w: E::G1Affine,
+    /// The random linear combination of the (shifted) polynomial commitments.
+    pub c: E::G1Affine,
+}
+
+impl<E: PairingEngine> KZGAccumulator<E> {
+    /// Returns the empty accumulator, which is valid and folds no proofs.
+    #[inline]
+    pub fn empty() -> Self {
+        Self { w: E::G1Affine::zero(), c: E::G1Affine::zero() }
+    }
+
+    pub fn absorb_into_sponge(&self, sponge: &mut impl AlgebraicSponge<E::Fq, 2>) {
+        sponge.absorb_native_field_elements(&self.w.to_field_elements().unwrap());
+        sponge.absorb_native_field_elements(&self.c.to_field_elements().unwrap());
+    }
+}
+
 /// `KZGProof` is an evaluation proof that is output by `KZG10::open`.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
 pub struct KZGProof<E: PairingEngine> {
@@ -23,6 +23,7 @@ use crate::{
     fft::{DensePolynomial, Polynomial},
     msm::VariableBase,
     polycommit::PCError,
+    AlgebraicSponge,
 };
 use anyhow::anyhow;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -214,17 +215,15 @@ impl<E: PairingEngine> KZG10<E> {
         point: E::Fr,
         randomness: &KZGRandomness<E>,
     ) -> Result<(DensePolynomial<E::Fr>, Option<DensePolynomial<E::Fr>>), PCError> {
-        let divisor = DensePolynomial::from_coefficients_vec(vec![-point, E::Fr::one()]);
-
         let witness_time = start_timer!(|| "Computing witness polynomial");
-        let witness_polynomial = polynomial / &divisor;
+        let (witness_polynomial, _) = polynomial.divide_by_linear(point);
         end_timer!(witness_time);
 
         let random_witness_polynomial = if randomness.is_hiding() {
             let random_p = &randomness.blinding_polynomial;
 
             let witness_time = start_timer!(|| "Computing random witness polynomial");
-            let random_witness_polynomial = random_p / &divisor;
+            let (random_witness_polynomial, _) = random_p.divide_by_linear(point);
             end_timer!(witness_time);
             Some(random_witness_polynomial)
         } else {
@@ -399,6 +398,103 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(result)
     }
 
+    /// Folds each `proof_i` in `proofs`, for `commitment_i` at `point_i` with `value_i`,
+    /// into the given accumulator, and outputs the new accumulator.
+    ///
+    /// The randomizers are Fiat-Shamir challenges, squeezed from the given sponge after absorbing
+    /// the given accumulator, commitments, points, values, and proofs, so the accumulation is deterministic
+    /// and can be checked with `verify_accumulation`.
+    ///
+    /// The new accumulator is valid if and only if (except with negligible probability)
+    /// the given accumulator is valid and each proof is a valid proof of evaluation.
+    pub fn accumulate<S: AlgebraicSponge<E::Fq, 2>>(
+        vk: &VerifierKey<E>,
+        accumulator: &KZGAccumulator<E>,
+        commitments: &[KZGCommitment<E>],
+        points: &[E::Fr],
+        values: &[E::Fr],
+        proofs: &[KZGProof<E>],
+        sponge: &mut S,
+    ) -> Result<KZGAccumulator<E>, PCError> {
+        if commitments.len() != points.len() || points.len() != values.len() || values.len() != proofs.len() {
+            return Err(anyhow!("Mismatched number of commitments, points, values, and proofs").into());
+        }
+        let accumulate_time = start_timer!(|| format!("Accumulating {} evaluation proofs", commitments.len()));
+
+        // Absorb the accumulation transcript, and squeeze a randomizer for each proof.
+        accumulator.absorb_into_sponge(sponge);
+        for commitment in commitments {
+            sponge.absorb_native_field_elements(&[*commitment]);
+        }
+        sponge.absorb_nonnative_field_elements(points.iter().chain(values).copied());
+        for proof in proofs {
+            proof.absorb_into_sponge(sponge);
+        }
+        let randomizers = sponge.squeeze_short_nonnative_field_elements::<E::Fr>(commitments.len());
+
+        let mut total_c = accumulator.c.to_projective();
+        let mut total_w = accumulator.w.to_projective();
+
+        // As in `batch_check`, we accumulate the coefficients of g and gamma_g
+        // and perform a final multiplication at the end.
+        let mut g_multiplier = E::Fr::zero();
+        let mut gamma_g_multiplier = E::Fr::zero();
+        for ((((c, z), v), proof), randomizer) in
+            commitments.iter().zip(points).zip(values).zip(proofs).zip_eq(randomizers)
+        {
+            // The given accumulator has an implicit coefficient of one, so every proof
+            // must be scaled by a fresh randomizer.
+            let w = proof.w;
+            let mut temp = w.mul(*z);
+            temp.add_assign_mixed(&c.0);
+            g_multiplier += &(randomizer * v);
+            if let Some(random_v) = proof.random_v {
+                gamma_g_multiplier += &(randomizer * random_v);
+            }
+            total_c += &temp.mul(randomizer);
+            total_w += &w.mul(randomizer);
+        }
+        total_c -= &vk.g.to_projective().mul(g_multiplier);
+        total_c -= &vk.gamma_g.to_projective().mul(gamma_g_multiplier);
+
+        let affine_points = E::G1Projective::batch_normalization_into_affine(vec![total_w, total_c]);
+        end_timer!(accumulate_time);
+        Ok(KZGAccumulator { w: affine_points[0], c: affine_points[1] })
+    }
+
+    /// Checks that the new accumulator is the result of folding each `proof_i` in `proofs`,
+    /// for `commitment_i` at `point_i` with `value_i`, into the given accumulator,
+    /// where the given sponge is in the same state as the sponge given to `accumulate`.
+    ///
+    /// This check is native, as it recomputes the accumulation with group operations and no pairings.
+    pub fn verify_accumulation<S: AlgebraicSponge<E::Fq, 2>>(
+        vk: &VerifierKey<E>,
+        accumulator: &KZGAccumulator<E>,
+        new_accumulator: &KZGAccumulator<E>,
+        commitments: &[KZGCommitment<E>],
+        points: &[E::Fr],
+        values: &[E::Fr],
+        proofs: &[KZGProof<E>],
+        sponge: &mut S,
+    ) -> Result<bool, PCError> {
+        let expected = Self::accumulate(vk, accumulator, commitments, points, values, proofs, sponge)?;
+        Ok(&expected == new_accumulator)
+    }
+
+    /// Decides whether the given accumulator is valid, i.e. whether every proof folded into it
+    /// is a valid proof of evaluation.
+    pub fn decide(vk: &VerifierKey<E>, accumulator: &KZGAccumulator<E>) -> bool {
+        let decide_time = start_timer!(|| "Deciding accumulator");
+        let result = E::product_of_pairings(
+            [(&(-accumulator.w).prepare(), &vk.prepared_beta_h), (&accumulator.c.prepare(), &vk.prepared_h)]
+                .iter()
+                .copied(),
+        )
+        .is_one();
+        end_timer!(decide_time, || format!("Result: {result}"));
+        result
+    }
+
     pub(crate) fn check_degree_is_too_large(degree: usize, num_powers: usize) -> Result<(), PCError> {
         let num_coefficients = degree + 1;
         if num_coefficients > num_powers {
@@ -472,6 +568,7 @@ mod tests {
     #![allow(non_camel_case_types)]
     #![allow(clippy::needless_borrow)]
     use super::*;
+    use crate::crypto_hash::PoseidonSponge;
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
     use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
 
@@ -609,6 +706,78 @@ mod tests {
         Ok(())
     }
 
+    fn accumulate_test_template<E: PairingEngine>() -> Result<(), PCError> {
+        let rng = &mut TestRng::default();
+        let hiding_bound = Some(1);
+        let degree = 10;
+        let pp = KZG10::<E>::load_srs(degree)?;
+        let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+
+        // Initialize the prover and verifier sponges, which are in the same state.
+        let parameters = PoseidonSponge::<E::Fq, 2, 1>::sample_parameters();
+        let mut prover_sponge = PoseidonSponge::<E::Fq, 2, 1>::new_with_parameters(&parameters);
+        let mut verifier_sponge = prover_sponge.clone();
+
+        // The empty accumulator is valid.
+        let mut accumulator = KZGAccumulator::<E>::empty();
+        assert!(KZG10::<E>::decide(&vk, &accumulator));
+
+        // Fold a chain of proofs into the accumulator, one round at a time.
+        for _ in 0..5 {
+            let p = DensePolynomial::rand(degree, rng);
+            let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, Some(rng))?;
+            let point = E::Fr::rand(rng);
+            let value = p.evaluate(point);
+            let proof = KZG10::<E>::open(&ck, &p, point, &rand)?;
+
+            // Folding an incorrect evaluation yields an invalid accumulator.
+            let wrong_value = value + E::Fr::one();
+            let invalid = KZG10::<E>::accumulate(
+                &vk,
+                &accumulator,
+                &[comm],
+                &[point],
+                &[wrong_value],
+                &[proof],
+                &mut prover_sponge.clone(),
+            )?;
+            assert!(!KZG10::<E>::decide(&vk, &invalid));
+
+            let new_accumulator =
+                KZG10::<E>::accumulate(&vk, &accumulator, &[comm], &[point], &[value], &[proof], &mut prover_sponge)?;
+            assert!(KZG10::<E>::decide(&vk, &new_accumulator));
+
+            // The accumulation verifier rejects an accumulator for a different transcript.
+            assert!(!KZG10::<E>::verify_accumulation(
+                &vk,
+                &accumulator,
+                &new_accumulator,
+                &[comm],
+                &[point],
+                &[wrong_value],
+                &[proof],
+                &mut verifier_sponge.clone(),
+            )?);
+            // The accumulation verifier accepts the accumulator for the same transcript.
+            assert!(KZG10::<E>::verify_accumulation(
+                &vk,
+                &accumulator,
+                &new_accumulator,
+                &[comm],
+                &[point],
+                &[value],
+                &[proof],
+                &mut verifier_sponge,
+            )?);
+            accumulator = new_accumulator;
+        }
+
+        // Mismatched inputs are rejected.
+        let result = KZG10::<E>::accumulate(&vk, &accumulator, &[], &[E::Fr::one()], &[], &[], &mut prover_sponge);
+        assert!(result.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_end_to_end() {
         end_to_end_test_template::<Bls12_377>().expect("test failed for bls12-377");
@@ -624,6 +793,11 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_accumulate() {
+        accumulate_test_template::<Bls12_377>().expect("test failed for bls12-377");
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut TestRng::default();
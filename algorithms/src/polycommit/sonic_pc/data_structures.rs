@@ -16,7 +16,7 @@ use super::{LabeledPolynomial, PolynomialInfo};
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
-use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
+use snarkvm_utilities::{bounded_capacity, error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
 use std::{
@@ -65,7 +65,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         // Deserialize `powers`.
         let powers_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers_of_beta_g = Vec::with_capacity(powers_len as usize);
+        let mut powers_of_beta_g = Vec::with_capacity(bounded_capacity(powers_len as u64));
         for _ in 0..powers_len {
             let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
             powers_of_beta_g.push(power);
@@ -76,7 +76,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         let mut lagrange_bases_at_beta_g = BTreeMap::new();
         for _ in 0..lagrange_bases_at_beta_len {
             let size: u32 = FromBytes::read_le(&mut reader)?;
-            let mut basis = Vec::with_capacity(size as usize);
+            let mut basis = Vec::with_capacity(bounded_capacity(size as u64));
             for _ in 0..size {
                 let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
                 basis.push(power);
@@ -86,7 +86,8 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
 
         // Deserialize `powers_of_beta_times_gamma_g`.
         let powers_of_beta_times_gamma_g_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers_of_beta_times_gamma_g = Vec::with_capacity(powers_of_beta_times_gamma_g_len as usize);
+        let mut powers_of_beta_times_gamma_g =
+            Vec::with_capacity(bounded_capacity(powers_of_beta_times_gamma_g_len as u64));
         for _ in 0..powers_of_beta_times_gamma_g_len {
             let powers_of_g: E::G1Affine = FromBytes::read_le(&mut reader)?;
             powers_of_beta_times_gamma_g.push(powers_of_g);
@@ -97,7 +98,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         let shifted_powers_of_beta_g = match has_shifted_powers_of_beta_g {
             true => {
                 let shifted_powers_len: u32 = FromBytes::read_le(&mut reader)?;
-                let mut shifted_powers_of_beta_g = Vec::with_capacity(shifted_powers_len as usize);
+                let mut shifted_powers_of_beta_g = Vec::with_capacity(bounded_capacity(shifted_powers_len as u64));
                 for _ in 0..shifted_powers_len {
                     let shifted_power: E::G1Affine = FromBytes::read_le(&mut reader)?;
                     shifted_powers_of_beta_g.push(shifted_power);
@@ -118,7 +119,7 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
                     let key: u32 = FromBytes::read_le(&mut reader)?;
 
                     let value_len: u32 = FromBytes::read_le(&mut reader)?;
-                    let mut value = Vec::with_capacity(value_len as usize);
+                    let mut value = Vec::with_capacity(bounded_capacity(value_len as u64));
                     for _ in 0..value_len {
                         let val: E::G1Affine = FromBytes::read_le(&mut reader)?;
                         value.push(val);
@@ -137,7 +138,8 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         let enforced_degree_bounds = match has_enforced_degree_bounds {
             true => {
                 let enforced_degree_bounds_len: u32 = FromBytes::read_le(&mut reader)?;
-                let mut enforced_degree_bounds = Vec::with_capacity(enforced_degree_bounds_len as usize);
+                let mut enforced_degree_bounds =
+                    Vec::with_capacity(bounded_capacity(enforced_degree_bounds_len as u64));
                 for _ in 0..enforced_degree_bounds_len {
                     let enforced_degree_bound: u32 = FromBytes::read_le(&mut reader)?;
                     enforced_degree_bounds.push(enforced_degree_bound as usize);
@@ -15,14 +15,28 @@
 use crate::r1cs::{errors::SynthesisError, ConstraintSystem, Index, LinearCombination, Variable};
 use snarkvm_fields::Field;
 
-/// Constraint counter for testing purposes.
-#[derive(Default)]
+/// A constraint system that only counts the variables and constraints of a circuit.
+///
+/// The assignment closures are never called, so a circuit that computes its witness in those closures
+/// can be counted with placeholder (or missing) witness values, which is much faster than synthesizing it.
+/// See `ConstraintSynthesizer::constraint_estimate`.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConstraintCounter {
+    /// The number of public variables, including the "one" variable.
     pub num_public_variables: usize,
+    /// The number of private variables.
     pub num_private_variables: usize,
+    /// The number of constraints.
     pub num_constraints: usize,
 }
 
+impl Default for ConstraintCounter {
+    /// Initializes a counter with the "one" variable allocated, as in every other constraint system.
+    fn default() -> Self {
+        Self { num_public_variables: 1, num_private_variables: 0, num_constraints: 0 }
+    }
+}
+
 impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
     type Root = Self;
 
@@ -89,3 +103,49 @@ impl<ConstraintF: Field> ConstraintSystem<ConstraintF> for ConstraintCounter {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::r1cs::{ConstraintSynthesizer, Fr};
+
+    /// A circuit that enforces `a * b = c`, with `c` as a public input.
+    struct MulCircuit {
+        a: Option<Fr>,
+        b: Option<Fr>,
+    }
+
+    impl ConstraintSynthesizer<Fr> for MulCircuit {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+            let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+            let c = cs.alloc_input(
+                || "c",
+                || {
+                    let a = self.a.ok_or(SynthesisError::AssignmentMissing)?;
+                    let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+                    Ok(a * b)
+                },
+            )?;
+            cs.enforce(|| "a * b = c", |lc| lc + a, |lc| lc + b, |lc| lc + c);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_constraint_estimate() {
+        // Ensure the circuit is counted without a witness.
+        let estimate = MulCircuit { a: None, b: None }.constraint_estimate().unwrap();
+        assert_eq!(estimate.num_public_variables, 2);
+        assert_eq!(estimate.num_private_variables, 2);
+        assert_eq!(estimate.num_constraints, 1);
+
+        // Ensure the estimate matches a full synthesis.
+        let mut cs = crate::r1cs::TestConstraintSystem::<Fr>::new();
+        MulCircuit { a: Some(Fr::from(2u64)), b: Some(Fr::from(3u64)) }.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!(estimate.num_public_variables, cs.num_public_variables());
+        assert_eq!(estimate.num_private_variables, cs.num_private_variables());
+        assert_eq!(estimate.num_constraints, cs.num_constraints());
+    }
+}
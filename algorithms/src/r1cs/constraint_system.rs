@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::r1cs::{errors::SynthesisError, Index, LinearCombination, Namespace, Variable};
+use crate::r1cs::{errors::SynthesisError, ConstraintCounter, Index, LinearCombination, Namespace, Variable};
 use snarkvm_fields::Field;
 
 use std::marker::PhantomData;
@@ -23,6 +23,19 @@ use std::marker::PhantomData;
 pub trait ConstraintSynthesizer<F: Field>: Sync {
     /// Drives generation of new constraints inside `CS`.
     fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError>;
+
+    /// Returns the number of variables and constraints of the circuit, without computing its witness.
+    ///
+    /// Note: This only saves work for a circuit that computes its witness lazily, inside the assignment
+    /// closures of `generate_constraints` (e.g. a hand-written circuit with `Option` witness values).
+    /// A circuit that holds an already-computed witness, such as a synthesized program `Assignment`,
+    /// has paid for the witness before this method is called, so the estimate is no cheaper than
+    /// counting the constraints of the synthesized circuit.
+    fn constraint_estimate(&self) -> Result<ConstraintCounter, SynthesisError> {
+        let mut counter = ConstraintCounter::default();
+        self.generate_constraints(&mut counter)?;
+        Ok(counter)
+    }
 }
 
 /// Represents a constraint system which can have new variables
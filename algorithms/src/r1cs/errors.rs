@@ -18,7 +18,7 @@ pub type SynthesisResult<T> = Result<T, SynthesisError>;
 /// such as CRS generation, proving or verification.
 #[derive(Debug, Error)]
 pub enum SynthesisError {
-    #[error("{}", _0)]
+    #[error(transparent)]
     AnyhowError(#[from] anyhow::Error),
     /// During synthesis, we lacked knowledge of a variable assignment.
     #[error("An assignment for a variable could not be computed")]
@@ -40,7 +40,7 @@ pub enum SynthesisError {
     UnexpectedIdentity,
     /// During proof generation, we encountered an I/O error with the CRS
     #[error("Encountered an I/O error")]
-    IoError(std::io::Error),
+    IoError(#[from] std::io::Error),
     /// During verification, our verifying key was malformed.
     #[error("Malformed verifying key, public input count was {} but expected {}", _0, _1)]
     MalformedVerifyingKey(usize, usize),
@@ -49,8 +49,20 @@ pub enum SynthesisError {
     UnconstrainedVariable,
 }
 
-impl From<std::io::Error> for SynthesisError {
-    fn from(e: std::io::Error) -> SynthesisError {
-        SynthesisError::IoError(e)
+impl SynthesisError {
+    /// Returns the stable numeric code of this error, in the range `100..200`.
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::AnyhowError(..) => 100,
+            Self::AssignmentMissing => 101,
+            Self::ConstraintFieldError(..) => 102,
+            Self::DivisionByZero => 103,
+            Self::Unsatisfiable => 104,
+            Self::PolyTooLarge => 105,
+            Self::UnexpectedIdentity => 106,
+            Self::IoError(..) => 107,
+            Self::MalformedVerifyingKey(..) => 108,
+            Self::UnconstrainedVariable => 109,
+        }
     }
 }
@@ -15,14 +15,14 @@
 /// Describes the failure modes of the AHP scheme.
 #[derive(Debug, Error)]
 pub enum AHPError {
-    #[error("{}", _0)]
+    #[error(transparent)]
     AnyhowError(#[from] anyhow::Error),
 
     #[error("Batch size was zero; must be at least 1.")]
     BatchSizeIsZero,
 
     #[error("An error occurred during constraint generation.")]
-    ConstraintSystemError(crate::r1cs::errors::SynthesisError),
+    ConstraintSystemError(#[from] crate::r1cs::errors::SynthesisError),
 
     #[error("The instance generated during proving does not match that in the index.")]
     InstanceDoesNotMatchIndex,
@@ -40,8 +40,20 @@ pub enum AHPError {
     PolyTooLarge,
 }
 
-impl From<crate::r1cs::errors::SynthesisError> for AHPError {
-    fn from(other: crate::r1cs::errors::SynthesisError) -> Self {
-        AHPError::ConstraintSystemError(other)
+impl AHPError {
+    /// Returns the stable numeric code of this error, in the range `200..300`.
+    ///
+    /// Constraint system errors return the code of the underlying synthesis error.
+    pub const fn code(&self) -> u16 {
+        match self {
+            Self::AnyhowError(..) => 200,
+            Self::BatchSizeIsZero => 201,
+            Self::ConstraintSystemError(error) => error.code(),
+            Self::InstanceDoesNotMatchIndex => 203,
+            Self::InvalidPublicInputLength => 204,
+            Self::MissingEval(..) => 205,
+            Self::NonSquareMatrix => 206,
+            Self::PolyTooLarge => 207,
+        }
     }
 }
@@ -17,6 +17,7 @@ use crate::{
     snark::varuna::{ahp::indexer::*, CircuitVerifyingKey, SNARKMode},
 };
 use snarkvm_curves::PairingEngine;
+use snarkvm_parameters::version::CircuitFingerprint;
 use snarkvm_utilities::{
     io::{self, Read, Write},
     serialize::*,
@@ -58,6 +59,44 @@ impl<E: PairingEngine, SM: SNARKMode> FromBytes for CircuitProvingKey<E, SM> {
     }
 }
 
+impl<E: PairingEngine, SM: SNARKMode> CircuitProvingKey<E, SM> {
+    /// Writes the proving key in the uncompressed layout, which stores each group element
+    /// as its little-endian affine coordinates, so it can be read without point decompression.
+    pub fn write_uncompressed<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_uncompressed(&self.circuit_verifying_key, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&*self.circuit, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&*self.committer_key, &mut writer)?;
+        Ok(())
+    }
+
+    /// Reads a proving key in the uncompressed layout, without validating its group elements.
+    ///
+    /// The group elements are not checked to be on the curve and in the prime-order subgroup,
+    /// so the bytes must come from a trusted source (e.g. a checksummed parameter file),
+    /// or the proving key must be validated with `check` before it is used.
+    pub fn read_uncompressed_unchecked<R: Read>(mut reader: R) -> io::Result<Self> {
+        let circuit_verifying_key = CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?;
+        let circuit = Arc::new(CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?);
+        let committer_key = Arc::new(CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?);
+
+        Ok(Self { circuit_verifying_key, circuit, committer_key })
+    }
+
+    /// Validates the group elements of the proving key, which are not checked by `read_uncompressed_unchecked`.
+    pub fn check(&self) -> Result<(), SerializationError> {
+        self.circuit_verifying_key.check()?;
+        self.circuit.check()?;
+        self.committer_key.check()
+    }
+}
+
+impl<E: PairingEngine, SM: SNARKMode> CircuitFingerprint for CircuitProvingKey<E, SM> {
+    /// Returns the hex-encoded ID of the circuit.
+    fn circuit_id(&self) -> String {
+        self.circuit_verifying_key.circuit_id()
+    }
+}
+
 impl<E: PairingEngine, SM: SNARKMode> Ord for CircuitProvingKey<E, SM> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.circuit.id.cmp(&other.circuit.id)
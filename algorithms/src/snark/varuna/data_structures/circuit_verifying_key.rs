@@ -14,6 +14,7 @@
 
 use crate::{polycommit::sonic_pc, snark::varuna::ahp::indexer::*};
 use snarkvm_curves::PairingEngine;
+use snarkvm_parameters::version::CircuitFingerprint;
 use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
@@ -59,6 +60,13 @@ impl<E: PairingEngine> CircuitVerifyingKey<E> {
     }
 }
 
+impl<E: PairingEngine> CircuitFingerprint for CircuitVerifyingKey<E> {
+    /// Returns the hex-encoded ID of the circuit.
+    fn circuit_id(&self) -> String {
+        self.id.to_string()
+    }
+}
+
 impl<E: PairingEngine> FromStr for CircuitVerifyingKey<E> {
     type Err = anyhow::Error;
 
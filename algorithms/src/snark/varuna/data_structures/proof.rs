@@ -293,6 +293,8 @@ impl<E: PairingEngine> Proof<E> {
     }
 }
 
+/// Encodes the batch sizes, commitments, evaluations, prover sums, and opening proof, in that order.
+/// This encoding is part of the versioned wire layout of proofs, so it must not change without a new version.
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
         let batch_sizes: Vec<u64> = self.batch_sizes.iter().map(|x| u64::try_from(*x)).collect::<Result<_, _>>()?;
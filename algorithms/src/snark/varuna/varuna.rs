@@ -346,6 +346,8 @@ where
         zk_rng: &mut R,
     ) -> Result<Self::Proof> {
         let prover_time = start_timer!(|| "Varuna::Prover");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
         if keys_to_constraints.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -622,6 +624,8 @@ where
         ensure!(proof.pc_proof.is_hiding() == SM::ZK);
 
         end_timer!(prover_time);
+        #[cfg(feature = "metrics")]
+        snarkvm_metrics::histogram_elapsed(snarkvm_metrics::algorithms::VARUNA_PROVE_TIME, start);
         Ok(proof)
     }
 
@@ -634,6 +638,8 @@ where
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -916,6 +922,8 @@ where
             " SonicKZG10::Check for AHP Verifier linear equations: {}",
             evaluations_are_correct & proof_has_correct_zk_mode
         ));
+        #[cfg(feature = "metrics")]
+        snarkvm_metrics::histogram_elapsed(snarkvm_metrics::algorithms::VARUNA_VERIFY_TIME, start);
         Ok(evaluations_are_correct & proof_has_correct_zk_mode)
     }
 }
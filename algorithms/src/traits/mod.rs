@@ -17,3 +17,6 @@ pub use algebraic_sponge::*;
 
 pub mod snark;
 pub use snark::*;
+
+pub mod transcript;
+pub use transcript::*;
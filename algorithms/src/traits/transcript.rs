@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use smallvec::SmallVec;
+use snarkvm_fields::{PrimeField, ToConstraintField};
+
+/// The interface for a Fiat-Shamir transcript over a sponge.
+///
+/// Every absorption and every challenge is bound to a label, so that protocols (and the messages
+/// within a protocol) are domain-separated. To reproduce a transcript in another implementation,
+/// note that each operation first absorbs, as bytes, the little-endian `u64` length of the label,
+/// the label, and the little-endian `u64` length of the message (or the number of challenges),
+/// and then absorbs the message (or squeezes the challenges).
+pub trait Transcript<F: PrimeField> {
+    /// Absorbs the given bytes, under the given label.
+    fn append_message(&mut self, label: &[u8], message: &[u8]);
+
+    /// Absorbs the given elements (such as field elements or curve points), under the given label.
+    fn append_elements<T: ToConstraintField<F>>(&mut self, label: &[u8], elements: &[T]);
+
+    /// Absorbs the given non-native field elements, under the given label.
+    fn append_nonnative_elements<Target: PrimeField>(&mut self, label: &[u8], elements: &[Target]);
+
+    /// Squeezes `num` field elements, under the given label.
+    fn challenge_elements(&mut self, label: &[u8], num: usize) -> SmallVec<[F; 10]>;
+
+    /// Squeezes `num` non-native field elements, under the given label.
+    fn challenge_nonnative_elements<Target: PrimeField>(&mut self, label: &[u8], num: usize) -> SmallVec<[Target; 10]>;
+
+    /// Squeezes a field element, under the given label.
+    fn challenge_element(&mut self, label: &[u8]) -> F {
+        self.challenge_elements(label, 1)[0]
+    }
+}
+
+/// Returns the domain separator for a transcript operation, which is the little-endian `u64` length of the label,
+/// the label, and the little-endian `u64` length of the message.
+pub(crate) fn transcript_separator(label: &[u8], length: usize) -> Vec<u8> {
+    let mut separator = Vec::with_capacity(16 + label.len());
+    separator.extend_from_slice(&(label.len() as u64).to_le_bytes());
+    separator.extend_from_slice(label);
+    separator.extend_from_slice(&(length as u64).to_le_bytes());
+    separator
+}
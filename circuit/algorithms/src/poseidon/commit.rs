@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Commit for Poseidon<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the Poseidon commitment of the given field elements and randomizer.
+    /// Note: The preimage is prefixed with a commitment domain separator, so a commitment is
+    /// domain-separated from the hash and PRF outputs of the same instance.
+    #[inline]
+    fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Self::Output {
+        // Construct the preimage: commit_domain || randomizer || input.
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(self.commit_domain.clone());
+        preimage.push(randomizer.to_field());
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage to derive the commitment.
+        self.hash(&preimage)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const DOMAIN: &str = "PoseidonCircuit0";
+    const ITERATIONS: usize = 10;
+    const RATE: usize = 4;
+
+    fn check_commit(
+        mode: Mode,
+        num_inputs: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) -> Result<()> {
+        use console::Commit as C;
+
+        let native = console::Poseidon::<<Circuit as Environment>::Network, RATE>::setup(DOMAIN)?;
+        let poseidon = Poseidon::<Circuit, RATE>::constant(native.clone());
+
+        for i in 0..ITERATIONS {
+            // Prepare the randomizer.
+            let native_randomizer = Uniform::rand(rng);
+            let randomizer = Scalar::new(mode, native_randomizer);
+
+            // Prepare the input.
+            let native_input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native commitment.
+            let expected = native.commit(&native_input, &native_randomizer).expect("Failed to commit native input");
+
+            // Compute the circuit commitment.
+            Circuit::scope(format!("Poseidon Commit {mode} {i}"), || {
+                let candidate = poseidon.commit(&input, &randomizer);
+                assert_eq!(expected, candidate.eject_value());
+                let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
+                assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_constant() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_inputs in 0..=RATE {
+            check_commit(Mode::Constant, num_inputs, 1, 0, 0, 0, &mut rng)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_public() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_commit(Mode::Public, 0, 1, 0, 335, 335, &mut rng)?;
+        check_commit(Mode::Public, 1, 1, 0, 340, 340, &mut rng)?;
+        check_commit(Mode::Public, 2, 1, 0, 345, 345, &mut rng)?;
+        check_commit(Mode::Public, 3, 1, 0, 700, 700, &mut rng)?;
+        check_commit(Mode::Public, 4, 1, 0, 700, 700, &mut rng)?;
+        check_commit(Mode::Public, 8, 1, 0, 1055, 1055, &mut rng)
+    }
+
+    #[test]
+    fn test_commit_private() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        check_commit(Mode::Private, 0, 1, 0, 335, 335, &mut rng)?;
+        check_commit(Mode::Private, 1, 1, 0, 340, 340, &mut rng)?;
+        check_commit(Mode::Private, 2, 1, 0, 345, 345, &mut rng)?;
+        check_commit(Mode::Private, 3, 1, 0, 700, 700, &mut rng)?;
+        check_commit(Mode::Private, 4, 1, 0, 700, 700, &mut rng)?;
+        check_commit(Mode::Private, 8, 1, 0, 1055, 1055, &mut rng)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod commit;
 mod hash;
 mod hash_many;
 mod hash_to_group;
@@ -23,7 +24,7 @@ use snarkvm_circuit_types::environment::assert_scope;
 #[cfg(test)]
 use snarkvm_utilities::{TestRng, Uniform};
 
-use crate::{Elligator2, Hash, HashMany, HashToGroup, HashToScalar, PRF};
+use crate::{Commit, Elligator2, Hash, HashMany, HashToGroup, HashToScalar, PRF};
 use snarkvm_circuit_types::{environment::prelude::*, Field, Group, Scalar};
 
 /// Poseidon2 is a cryptographic hash function of input rate 2.
@@ -54,6 +55,8 @@ pub enum DuplexSpongeMode {
 pub struct Poseidon<E: Environment, const RATE: usize> {
     /// The domain separator for the Poseidon hash function.
     domain: Field<E>,
+    /// The domain separator for Poseidon commitments.
+    commit_domain: Field<E>,
     /// The number of rounds in a full-round operation.
     full_rounds: usize,
     /// The number of rounds in a partial-round operation.
@@ -74,6 +77,8 @@ impl<E: Environment, const RATE: usize> Inject for Poseidon<E, RATE> {
     fn new(_mode: Mode, poseidon: Self::Primitive) -> Self {
         // Initialize the domain separator.
         let domain = Field::constant(poseidon.domain());
+        // Initialize the commitment domain separator.
+        let commit_domain = Field::constant(poseidon.commit_domain());
 
         // Initialize the Poseidon parameters.
         let parameters = poseidon.parameters();
@@ -99,6 +104,6 @@ impl<E: Environment, const RATE: usize> Inject for Poseidon<E, RATE> {
             })
             .collect();
 
-        Self { domain, full_rounds, partial_rounds, alpha, ark, mds }
+        Self { domain, commit_domain, full_rounds, partial_rounds, alpha, ark, mds }
     }
 }
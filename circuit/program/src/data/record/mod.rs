@@ -29,6 +29,7 @@ mod num_randomizers;
 mod serial_number;
 mod tag;
 mod to_bits;
+mod to_checksum;
 mod to_commitment;
 mod to_fields;
 
@@ -37,6 +38,13 @@ use snarkvm_circuit_account::{PrivateKey, ViewKey};
 use snarkvm_circuit_network::Aleo;
 use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Group, Scalar, U32};
 
+/// A program record, as a circuit.
+///
+/// Program circuits can reuse the record gadgets directly, instead of re-deriving record semantics:
+/// - `to_commitment` computes the record commitment, as `BHP(program_id || record_name || record)`.
+/// - `serial_number` and `serial_number_from_gamma` compute the record serial number.
+/// - `tag` computes the record tag, from the `sk_tag` and the commitment.
+/// - `encrypt` and `decrypt` encrypt the record to its owner, and `to_checksum` hashes the encrypted record.
 #[derive(Clone)]
 pub struct Record<A: Aleo, Private: Visibility<A>> {
     /// The owner of the program record.
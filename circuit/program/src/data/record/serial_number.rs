@@ -18,13 +18,19 @@ impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
     /// A helper method to derive the serial number from the private key and commitment.
     pub fn serial_number(private_key: PrivateKey<A>, commitment: Field<A>) -> Field<A> {
         // Compute the generator `H` as `HashToGroup(commitment)`.
-        let h = A::hash_to_group_psd2(&[A::serial_number_domain(), commitment.clone()]);
+        let h = Self::serial_number_generator(commitment.clone());
         // Compute `gamma` as `sk_sig * H`.
         let gamma = h * private_key.sk_sig();
         // Compute the serial number from `gamma`.
         Self::serial_number_from_gamma(&gamma, commitment)
     }
 
+    /// A helper method to derive the generator `H` as `HashToGroup(commitment)`.
+    /// The signer proves knowledge of `sk_sig` such that `gamma = sk_sig * H`.
+    pub fn serial_number_generator(commitment: Field<A>) -> Group<A> {
+        A::hash_to_group_psd2(&[A::serial_number_domain(), commitment])
+    }
+
     /// A helper method to derive the serial number from the gamma and commitment.
     pub fn serial_number_from_gamma(gamma: &Group<A>, commitment: Field<A>) -> Field<A> {
         // Compute `sn_nonce` as `Hash(COFACTOR * gamma)`.
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> Record<A, Ciphertext<A>> {
+    /// Returns the record checksum, as the hash of the encrypted record.
+    /// The checksum binds a record output to its ciphertext, so the ciphertext cannot be replaced.
+    pub fn to_checksum(&self) -> Field<A> {
+        A::hash_bhp1024(&self.to_bits_le())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console::Network as _;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    #[test]
+    fn test_to_checksum() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a record, with the nonce for the randomizer.
+        let randomizer = console::Scalar::<CurrentNetwork>::rand(&mut rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = console::Record::<CurrentNetwork, console::Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, _nonce: {nonce}.public }}"
+        ))?;
+
+        // Compute the expected checksum.
+        let expected = CurrentNetwork::hash_bhp1024(&record.encrypt(randomizer)?.to_bits_le())?;
+
+        // Compute the checksum in the circuit.
+        let record = Record::<Circuit, Plaintext<Circuit>>::new(Mode::Private, record);
+        let randomizer = Scalar::<Circuit>::new(Mode::Private, randomizer);
+        let candidate = record.encrypt(&randomizer).to_checksum();
+        assert_eq!(expected, candidate.eject_value());
+        Ok(())
+    }
+}
@@ -232,7 +232,7 @@ impl<A: Aleo> Request<A> {
                             let response = signature.response();
 
                             // Compute the generator `H` as `HashToGroup(commitment)`.
-                            let h = A::hash_to_group_psd2(&[A::serial_number_domain(), candidate_commitment.clone()]);
+                            let h = Record::<A, Plaintext<A>>::serial_number_generator(candidate_commitment.clone());
                             // Compute `h_r` as `(challenge * gamma) + (response * H)`, equivalent to `r * H`.
                             let h_r = (gamma.deref() * challenge) + (&h * response);
 
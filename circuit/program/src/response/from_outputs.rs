@@ -122,7 +122,7 @@ impl<A: Aleo> Response<A> {
                         // Encrypt the record, using the randomizer.
                         let encrypted_record = record.encrypt(&randomizer);
                         // Compute the record checksum, as the hash of the encrypted record.
-                        let checksum = A::hash_bhp1024(&encrypted_record.to_bits_le());
+                        let checksum = encrypted_record.to_checksum();
 
                         // Return the output ID.
                         OutputID::record(commitment, checksum)
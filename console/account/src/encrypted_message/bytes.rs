@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for EncryptedMessage<N> {
+    /// Reads an encrypted message from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid encrypted message version"));
+        }
+        // Read the nonce.
+        let nonce = Group::read_le(&mut reader)?;
+        // Read the ciphertext.
+        let num_fields = u32::read_le(&mut reader)?;
+        let ciphertext = (0..num_fields).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the tag.
+        let tag = Field::read_le(&mut reader)?;
+        Ok(Self { nonce, ciphertext, tag })
+    }
+}
+
+impl<N: Network> ToBytes for EncryptedMessage<N> {
+    /// Writes an encrypted message to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the nonce.
+        self.nonce.write_le(&mut writer)?;
+        // Write the ciphertext.
+        u32::try_from(self.ciphertext.len()).map_err(|e| error(format!("{e}")))?.write_le(&mut writer)?;
+        self.ciphertext.iter().try_for_each(|field| field.write_le(&mut writer))?;
+        // Write the tag.
+        self.tag.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an encrypted message.
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let expected = EncryptedMessage::encrypt(&address, b"hello, aleo", rng)?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, EncryptedMessage::read_le(&expected_bytes[..])?);
+        assert!(EncryptedMessage::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use crate::address::Address;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+use zeroize::Zeroize;
+
+/// The domain separator for the randomizers that mask a message.
+static MESSAGE_ENCRYPTION_DOMAIN: &str = "AleoMessageEncryption0";
+/// The domain separator for the key of the message authentication tag.
+static MESSAGE_TAG_KEY_DOMAIN: &str = "AleoMessageTagKey0";
+
+/// A message encrypted to an account address, which can be decrypted with the account view key.
+///
+/// The sender samples a randomizer `r`, and publishes the nonce `G^r`. The message key is the
+/// x-coordinate of `address^r`, which the recipient recomputes as `nonce^view_key`. The message key
+/// seeds the Poseidon randomizers that mask the message, and separately, the key for the authentication tag.
+/// Both use domains specific to messages, so they are distinct from the randomizers of record encryption.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedMessage<N: Network> {
+    /// The nonce of the message, `G^r`.
+    nonce: Group<N>,
+    /// The masked field elements of the message.
+    ciphertext: Vec<Field<N>>,
+    /// The authentication tag of the ciphertext.
+    tag: Field<N>,
+}
+
+impl<N: Network> EncryptedMessage<N> {
+    /// Encrypts the given message to the given address.
+    pub fn encrypt<R: Rng + CryptoRng>(address: &Address<N>, message: &[u8], rng: &mut R) -> Result<Self> {
+        // Sample a randomizer, and compute the nonce.
+        let randomizer = Scalar::rand(rng);
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Compute the message key.
+        let mut message_key = (**address * randomizer).to_x_coordinate();
+        // Encrypt the message.
+        let encrypted = Self::encrypt_symmetric(nonce, &message_key, message);
+        // Erase the message key.
+        message_key.zeroize();
+        encrypted
+    }
+
+    /// Returns the nonce of the message.
+    pub const fn nonce(&self) -> &Group<N> {
+        &self.nonce
+    }
+
+    /// Returns the masked field elements of the message.
+    pub fn ciphertext(&self) -> &[Field<N>] {
+        &self.ciphertext
+    }
+
+    /// Returns the authentication tag of the ciphertext.
+    pub const fn tag(&self) -> &Field<N> {
+        &self.tag
+    }
+
+    /// Decrypts the message with the given message key, and returns the message bytes.
+    /// This method returns an error if the message key is incorrect, or if the ciphertext was modified.
+    pub(crate) fn decrypt_symmetric(&self, message_key: &Field<N>) -> Result<Vec<u8>> {
+        // Prepare the randomizers and the tag key.
+        let (randomizers, tag_key) = Self::randomizers(message_key, self.ciphertext.len())?;
+        // Ensure the tag is valid.
+        if Self::compute_tag(&tag_key, &self.ciphertext)? != self.tag {
+            bail!("Failed to decrypt the message: the tag is invalid")
+        }
+        // Unmask the field elements, and decode the message.
+        let fields = self.ciphertext.iter().zip_eq(&randomizers).map(|(c, r)| *c - r).collect::<Vec<_>>();
+        decode_message(&fields)
+    }
+}
+
+impl<N: Network> EncryptedMessage<N> {
+    /// Encrypts the given message under the given message key.
+    fn encrypt_symmetric(nonce: Group<N>, message_key: &Field<N>, message: &[u8]) -> Result<Self> {
+        // Encode the message as field elements.
+        let fields = encode_message::<N>(message)?;
+        // Prepare the randomizers and the tag key.
+        let (randomizers, tag_key) = Self::randomizers(message_key, fields.len())?;
+        // Mask the field elements, and compute the tag.
+        let ciphertext = fields.iter().zip_eq(&randomizers).map(|(m, r)| *m + r).collect::<Vec<_>>();
+        let tag = Self::compute_tag(&tag_key, &ciphertext)?;
+        Ok(Self { nonce, ciphertext, tag })
+    }
+
+    /// Returns the given number of randomizers, and the tag key, derived from the message key.
+    fn randomizers(message_key: &Field<N>, num_randomizers: usize) -> Result<(Vec<Field<N>>, Field<N>)> {
+        // Ensure the number of outputs fits the hash function.
+        let num_outputs = match u16::try_from(num_randomizers) {
+            Ok(num_outputs) => num_outputs,
+            Err(_) => bail!("The message is too large to encrypt ({num_randomizers} field elements)"),
+        };
+        // Compute the randomizers.
+        let encryption_domain = Field::new_domain_separator(MESSAGE_ENCRYPTION_DOMAIN);
+        let randomizers = N::hash_many_psd8(&[encryption_domain, *message_key], num_outputs);
+        // Compute the tag key with a separate hash, as the randomizers of a shorter message
+        // are a prefix of the randomizers of a longer message.
+        let tag_key = N::hash_psd2(&[Field::new_domain_separator(MESSAGE_TAG_KEY_DOMAIN), *message_key])?;
+        Ok((randomizers, tag_key))
+    }
+
+    /// Returns the authentication tag of the given ciphertext, under the given tag key.
+    fn compute_tag(tag_key: &Field<N>, ciphertext: &[Field<N>]) -> Result<Field<N>> {
+        let mut preimage = Vec::with_capacity(1 + ciphertext.len());
+        preimage.push(*tag_key);
+        preimage.extend_from_slice(ciphertext);
+        N::hash_psd4(&preimage)
+    }
+}
+
+/// Returns the number of message bytes packed into each field element.
+fn bytes_per_field<N: Network>() -> usize {
+    Field::<N>::SIZE_IN_DATA_BITS / 8
+}
+
+/// Returns the number of field elements needed to pack a message of the given length.
+fn num_chunks<N: Network>(length: usize) -> usize {
+    (length + bytes_per_field::<N>() - 1) / bytes_per_field::<N>()
+}
+
+/// Encodes the given message as field elements, starting with the message length.
+fn encode_message<N: Network>(message: &[u8]) -> Result<Vec<Field<N>>> {
+    let mut fields = Vec::with_capacity(1 + num_chunks::<N>(message.len()));
+    fields.push(Field::from_u64(u64::try_from(message.len())?));
+    for chunk in message.chunks(bytes_per_field::<N>()) {
+        fields.push(Field::from_bits_le(&chunk.to_bits_le())?);
+    }
+    Ok(fields)
+}
+
+/// Decodes the message from the given field elements, ensuring the encoding is canonical.
+fn decode_message<N: Network>(fields: &[Field<N>]) -> Result<Vec<u8>> {
+    let Some((length_field, chunks)) = fields.split_first() else { bail!("The message is missing its length") };
+    // Retrieve the message length.
+    let length = u64::from_bits_le(&length_field.to_bits_le()[..64])?;
+    ensure!(Field::<N>::from_u64(length) == *length_field, "The message length is invalid");
+    let length = usize::try_from(length)?;
+    ensure!(num_chunks::<N>(length) == chunks.len(), "The message length does not match its chunks");
+    // Retrieve the message bytes.
+    let num_chunk_bits = bytes_per_field::<N>() * 8;
+    let mut message = Vec::with_capacity(chunks.len() * bytes_per_field::<N>());
+    for chunk in chunks {
+        let bits = chunk.to_bits_le();
+        ensure!(bits[num_chunk_bits..].iter().all(|bit| !bit), "The message chunk is out of range");
+        message.extend(Vec::<u8>::from_bits_le(&bits[..num_chunk_bits])?);
+    }
+    // Ensure the padding is zero, and remove it.
+    ensure!(message[length..].iter().all(|byte| *byte == 0), "The message padding is invalid");
+    message.truncate(length);
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PrivateKey, ViewKey};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an account.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let address = Address::try_from(private_key)?;
+
+            // Sample a message, with lengths around the chunk boundaries.
+            let message = (0..i * 7).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let encrypted = EncryptedMessage::encrypt(&address, &message, rng)?;
+            assert_eq!(view_key.decrypt(&encrypted)?, message);
+
+            // Ensure a different view key fails to decrypt the message.
+            let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(other_view_key.decrypt(&encrypted).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampered_ciphertext() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(private_key)?;
+        let address = Address::try_from(private_key)?;
+        let encrypted = EncryptedMessage::encrypt(&address, b"hello, aleo", rng)?;
+
+        // Ensure a modified ciphertext is rejected.
+        let mut tampered = encrypted.clone();
+        tampered.ciphertext[1] += Field::one();
+        assert!(view_key.decrypt(&tampered).is_err());
+
+        // Ensure a modified tag is rejected.
+        let mut tampered = encrypted;
+        tampered.tag += Field::one();
+        assert!(view_key.decrypt(&tampered).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tag_key_is_not_a_randomizer() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let message_key = Field::<CurrentNetwork>::rand(rng);
+        for num_randomizers in 1..8 {
+            // Ensure the tag key is not a randomizer of a longer message under the same message key.
+            let (_, tag_key) = EncryptedMessage::randomizers(&message_key, num_randomizers)?;
+            let (randomizers, _) = EncryptedMessage::randomizers(&message_key, num_randomizers + 8)?;
+            assert!(!randomizers.contains(&tag_key));
+        }
+
+        // Ensure the randomizers are distinct from the randomizers of record encryption.
+        let (randomizers, _) = EncryptedMessage::randomizers(&message_key, 4)?;
+        assert_ne!(randomizers, CurrentNetwork::hash_many_psd8(&[CurrentNetwork::encryption_domain(), message_key], 4));
+        Ok(())
+    }
+}
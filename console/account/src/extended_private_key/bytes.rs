@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ExtendedPrivateKey<N> {
+    /// Reads an extended private key from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid extended private key version"));
+        }
+        // Read the depth and the child index.
+        let depth = u8::read_le(&mut reader)?;
+        let child_index = u32::read_le(&mut reader)?;
+        // Ensure the master key has no child index.
+        if depth == 0 && child_index != 0 {
+            return Err(error("Invalid extended private key: the master key must have a child index of 0"));
+        }
+        // Read the private key.
+        let private_key = PrivateKey::read_le(&mut reader)?;
+        Ok(Self { depth, child_index, private_key })
+    }
+}
+
+impl<N: Network> ToBytes for ExtendedPrivateKey<N> {
+    /// Writes an extended private key to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the depth and the child index.
+        self.depth.write_le(&mut writer)?;
+        self.child_index.write_le(&mut writer)?;
+        // Write the private key.
+        self.private_key.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 1000;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new extended private key.
+            let master = ExtendedPrivateKey::new(PrivateKey::<CurrentNetwork>::new(&mut rng)?);
+            let expected = master.derive_child(i)?;
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected_bytes.len(), 38);
+            assert_eq!(expected, ExtendedPrivateKey::read_le(&expected_bytes[..])?);
+            assert!(ExtendedPrivateKey::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_fails() -> Result<()> {
+        let master = ExtendedPrivateKey::new(PrivateKey::<CurrentNetwork>::new(&mut TestRng::default())?);
+
+        // Ensure a master key with a child index is rejected.
+        let mut bytes = master.to_bytes_le()?;
+        bytes[2] = 1;
+        assert!(ExtendedPrivateKey::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        // Ensure an unknown version is rejected.
+        let mut bytes = master.to_bytes_le()?;
+        bytes[0] = 2;
+        assert!(ExtendedPrivateKey::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
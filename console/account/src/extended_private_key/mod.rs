@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::PrivateKey;
+use snarkvm_console_network::prelude::*;
+
+use zeroize::Zeroize;
+
+/// An account private key, extended with its position in the derivation tree of its master key,
+/// so that any account in the tree can be exported and restored on its own.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
+pub struct ExtendedPrivateKey<N: Network> {
+    /// The depth of the private key in the derivation tree, where the master key is at depth 0.
+    depth: u8,
+    /// The index of the private key among its siblings, which is 0 for the master key.
+    child_index: u32,
+    /// The private key.
+    private_key: PrivateKey<N>,
+}
+
+impl<N: Network> ExtendedPrivateKey<N> {
+    /// Initializes the master extended private key, at the root of the derivation tree.
+    pub const fn new(private_key: PrivateKey<N>) -> Self {
+        Self { depth: 0, child_index: 0, private_key }
+    }
+
+    /// Returns the extended private key of the child at the given index.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Ensure the child is within the maximum depth.
+        let depth = match self.depth.checked_add(1) {
+            Some(depth) => depth,
+            None => bail!("Cannot derive a child beyond the maximum depth of {}", u8::MAX),
+        };
+        Ok(Self { depth, child_index: index, private_key: self.private_key.derive_child(index)? })
+    }
+
+    /// Returns the extended private key for the given derivation path (e.g. `m/0/1/2`),
+    /// which must be derived from the master key.
+    ///
+    /// The path must begin with `m`, and each subsequent component is a child index.
+    pub fn derive_path(&self, path: &str) -> Result<Self> {
+        // Ensure the path is derived from the master key.
+        ensure!(self.depth == 0, "Invalid derivation path '{path}': must be derived from the master key");
+        // Split the path into its components.
+        let mut components = path.split('/');
+        // Ensure the path starts from the master key.
+        ensure!(components.next() == Some("m"), "Invalid derivation path '{path}': must begin with 'm'");
+        // Derive each child extended private key along the path.
+        components.try_fold(*self, |extended_key, component| match component.parse::<u32>() {
+            Ok(index) => extended_key.derive_child(index),
+            Err(_) => bail!("Invalid derivation path '{path}': found an invalid index '{component}'"),
+        })
+    }
+
+    /// Returns the depth of the private key in the derivation tree.
+    pub const fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the index of the private key among its siblings.
+    pub const fn child_index(&self) -> u32 {
+        self.child_index
+    }
+
+    /// Returns the private key.
+    pub const fn private_key(&self) -> PrivateKey<N> {
+        self.private_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new master key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let master = ExtendedPrivateKey::new(private_key);
+            assert_eq!(master.depth(), 0);
+            assert_eq!(master.child_index(), 0);
+
+            // Ensure the child matches the private key derivation.
+            let child = master.derive_child(7)?.derive_child(3)?;
+            assert_eq!(child.depth(), 2);
+            assert_eq!(child.child_index(), 3);
+            assert_eq!(child.private_key(), private_key.derive_child(7)?.derive_child(3)?);
+            assert_eq!(child, master.derive_path("m/7/3")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_path_fails() -> Result<()> {
+        let master = ExtendedPrivateKey::new(PrivateKey::<CurrentNetwork>::new(&mut TestRng::default())?);
+
+        // Ensure paths are only derived from the master key.
+        assert!(master.derive_child(0)?.derive_path("m/1").is_err());
+        // Ensure the depth is bounded.
+        let deepest = (0..u8::MAX).try_fold(master, |key, _| key.derive_child(0))?;
+        assert_eq!(deepest.depth(), u8::MAX);
+        assert!(deepest.derive_child(0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static EXTENDED_PRIVATE_KEY_PREFIX: [u8; 11] = [57, 18, 226, 27, 64, 11, 113, 95, 126, 243, 235]; // AExtPrivateKey1
+
+impl<N: Network> FromStr for ExtendedPrivateKey<N> {
+    type Err = Error;
+
+    /// Reads in an extended private key from a base58 string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Encode the string into base58.
+        let data = bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?;
+        if data.len() != 49 {
+            bail!("Invalid extended private key length: found {}, expected 49", data.len())
+        } else if data[0..11] != EXTENDED_PRIVATE_KEY_PREFIX {
+            bail!(
+                "Invalid extended private key prefix: found {:?}, expected {:?}",
+                &data[0..11],
+                EXTENDED_PRIVATE_KEY_PREFIX
+            )
+        }
+        // Output the extended private key.
+        Ok(Self::read_le(&data[11..49])?)
+    }
+}
+
+impl<N: Network> fmt::Display for ExtendedPrivateKey<N> {
+    /// Writes the extended private key as a base58 string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Write the extended private key bytes.
+        let mut extended_private_key = [0u8; 49];
+        extended_private_key[0..11].copy_from_slice(&EXTENDED_PRIVATE_KEY_PREFIX);
+        self.write_le(&mut extended_private_key[11..49]).map_err(|_| fmt::Error)?;
+        // Encode the extended private key into base58.
+        write!(f, "{}", bs58::encode(extended_private_key).into_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 1000;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new extended private key.
+            let master = ExtendedPrivateKey::new(PrivateKey::<CurrentNetwork>::new(&mut rng)?);
+            let expected = master.derive_child(i)?;
+
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert_eq!(expected, ExtendedPrivateKey::from_str(&candidate)?);
+            assert_eq!(67, candidate.len());
+            assert!(candidate.starts_with("AExtPrivateKey1"));
+        }
+        Ok(())
+    }
+}
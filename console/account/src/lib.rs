@@ -26,6 +26,16 @@ pub mod compute_key;
 #[cfg(feature = "compute_key")]
 pub use compute_key::*;
 
+#[cfg(feature = "encrypted_message")]
+pub mod encrypted_message;
+#[cfg(feature = "encrypted_message")]
+pub use encrypted_message::*;
+
+#[cfg(feature = "private_key")]
+pub mod extended_private_key;
+#[cfg(feature = "private_key")]
+pub use extended_private_key::*;
+
 #[cfg(feature = "graph_key")]
 pub mod graph_key;
 #[cfg(feature = "graph_key")]
@@ -41,6 +51,9 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "test_vectors")]
+pub mod test_vectors;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
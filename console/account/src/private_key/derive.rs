@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::ExtendedPrivateKey;
+
+static ACCOUNT_CHILD_SEED_DOMAIN: &str = "AleoAccountChildSeed0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the child private key at the given index, derived from the account seed.
+    ///
+    /// The child account seed is computed as `HashPSD2(domain, seed, index)`, so a single
+    /// account seed can be used to recover every child account (and its view key and address).
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Construct the child seed domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_SEED_DOMAIN);
+        // Compute the child account seed.
+        let child_seed = N::hash_psd2(&[domain, self.seed, Field::from_u32(index)])?;
+        // Return the child private key.
+        Self::try_from(child_seed)
+    }
+
+    /// Returns the descendant private key for the given derivation path (e.g. `m/0/1/2`).
+    ///
+    /// The path must begin with `m`, and each subsequent component is a child index.
+    pub fn derive_path(&self, path: &str) -> Result<Self> {
+        Ok(ExtendedPrivateKey::new(*self).derive_path(path)?.private_key())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the derivation is deterministic.
+            let child = private_key.derive_child(0)?;
+            assert_eq!(child, private_key.derive_child(0)?);
+            // Ensure the child differs from the parent, and from its siblings.
+            assert_ne!(child, private_key);
+            assert_ne!(child, private_key.derive_child(1)?);
+            // Ensure the child is recoverable from its seed.
+            assert_eq!(child, PrivateKey::try_from(child.seed())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_path() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the master path returns the private key.
+            assert_eq!(private_key, private_key.derive_path("m")?);
+            // Ensure the path matches the chained child derivations.
+            let expected = private_key.derive_child(0)?.derive_child(1)?.derive_child(2)?;
+            assert_eq!(expected, private_key.derive_path("m/0/1/2")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_path_fails() -> Result<()> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut TestRng::default())?;
+
+        // Ensure invalid paths are rejected.
+        assert!(private_key.derive_path("").is_err());
+        assert!(private_key.derive_path("0/1").is_err());
+        assert!(private_key.derive_path("m/").is_err());
+        assert!(private_key.derive_path("m/a").is_err());
+        assert!(private_key.derive_path("m/0/-1").is_err());
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bytes;
+mod derive;
 mod serialize;
 mod string;
 mod try_from;
@@ -23,9 +24,11 @@ mod sign;
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Field, Scalar};
 
+use std::hash::{Hash, Hasher};
+use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
+#[derive(Copy, Clone, Debug, Zeroize)]
 pub struct PrivateKey<N: Network> {
     /// The account seed that derives the full private key.
     seed: Field<N>,
@@ -58,3 +61,19 @@ impl<N: Network> PrivateKey<N> {
         self.r_sig
     }
 }
+
+impl<N: Network> PartialEq for PrivateKey<N> {
+    /// Returns `true` if the private keys are equal, comparing the account seeds in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        self.seed.to_bigint().as_ref().ct_eq(other.seed.to_bigint().as_ref()).into()
+    }
+}
+
+impl<N: Network> Eq for PrivateKey<N> {}
+
+impl<N: Network> Hash for PrivateKey<N> {
+    /// Hashes the account seed, which derives the full private key.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.seed.hash(state)
+    }
+}
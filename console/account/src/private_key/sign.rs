@@ -13,7 +13,8 @@
 // limitations under the License.
 
 use super::*;
-use crate::Signature;
+use crate::{AdaptorSignature, Signature};
+use snarkvm_console_types::Group;
 
 impl<N: Network> PrivateKey<N> {
     /// Returns a signature for the given message (as field elements) using the private key.
@@ -30,6 +31,16 @@ impl<N: Network> PrivateKey<N> {
     pub fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
         Signature::sign_bits(self, message, rng)
     }
+
+    /// Returns an adaptor signature for the given message (as field elements) and adaptor point using the private key.
+    pub fn pre_sign<R: Rng + CryptoRng>(
+        &self,
+        message: &[Field<N>],
+        adaptor_point: Group<N>,
+        rng: &mut R,
+    ) -> Result<AdaptorSignature<N>> {
+        Signature::pre_sign(self, message, adaptor_point, rng)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An adaptor signature (pre-signature) `(challenge, pre_response, compute_key)` for an adaptor point `T = t * G`.
+///
+/// The pre-signature is not a valid signature, but anyone who learns the adaptor secret `t` can complete it
+/// into one. Conversely, anyone holding the pre-signature who sees the completed signature can extract `t`.
+/// This enables protocols such as scriptless atomic swaps, where revealing a signature reveals a secret.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AdaptorSignature<N: Network> {
+    /// The verifier challenge, which commits to `nonce * G + T`.
+    challenge: Scalar<N>,
+    /// The prover response, before it is adapted by the adaptor secret.
+    pre_response: Scalar<N>,
+    /// The compute key of the prover.
+    compute_key: ComputeKey<N>,
+}
+
+impl<N: Network> AdaptorSignature<N> {
+    /// Returns the verifier challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the prover response, before it is adapted by the adaptor secret.
+    pub const fn pre_response(&self) -> Scalar<N> {
+        self.pre_response
+    }
+
+    /// Returns the signer compute key.
+    pub const fn compute_key(&self) -> ComputeKey<N> {
+        self.compute_key
+    }
+}
+
+impl<N: Network> Signature<N> {
+    /// Returns an adaptor signature `(challenge, pre_response, compute_key)` for the given message and adaptor point, where:
+    ///     challenge := HashToScalar(nonce * G + adaptor_point, pk_sig, pr_sig, address, message)
+    ///     pre_response := nonce - challenge * private_key.sk_sig()
+    pub fn pre_sign<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        message: &[Field<N>],
+        adaptor_point: Group<N>,
+        rng: &mut R,
+    ) -> Result<AdaptorSignature<N>> {
+        // Sample a random nonce from the scalar field.
+        // Note: The nonce reveals the private key given the pre-signature, so it is erased when it goes out of scope.
+        let nonce = Zeroizing::new(Scalar::rand(rng));
+        // Compute `g_r` as `nonce * G + adaptor_point`.
+        let g_r = N::g_scalar_multiply(&nonce) + adaptor_point;
+
+        // Derive the compute key from the private key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Derive the address from the compute key.
+        let address = Address::try_from(compute_key)?;
+
+        // Compute the verifier challenge.
+        let challenge = Signature::compute_challenge(g_r, &compute_key, &address, message)?;
+        // Compute the prover response, before it is adapted.
+        let pre_response = *nonce - (challenge * private_key.sk_sig());
+
+        // Output the adaptor signature.
+        Ok(AdaptorSignature { challenge, pre_response, compute_key })
+    }
+}
+
+impl<N: Network> AdaptorSignature<N> {
+    /// Verifies the adaptor signature is a valid pre-signature for the given address, message, and adaptor point,
+    /// i.e. that completing it with the adaptor secret yields a valid signature, where:
+    ///     challenge' := HashToScalar(G^pre_response pk_sig^challenge adaptor_point, pk_sig, pr_sig, address, message)
+    pub fn verify(&self, address: &Address<N>, message: &[Field<N>], adaptor_point: Group<N>) -> bool {
+        // Compute `g_r` := (pre_response * G) + (challenge * pk_sig) + adaptor_point.
+        let g_r =
+            N::g_scalar_multiply(&self.pre_response) + (self.compute_key.pk_sig() * self.challenge) + adaptor_point;
+
+        // Compute the candidate challenge, and return `false` if this operation fails.
+        let candidate_challenge = match Signature::compute_challenge(g_r, &self.compute_key, address, message) {
+            Ok(candidate_challenge) => candidate_challenge,
+            Err(_) => return false,
+        };
+        // Derive the address from the compute key, and return `false` if this operation fails.
+        let candidate_address = match Address::try_from(self.compute_key) {
+            Ok(candidate_address) => candidate_address,
+            Err(_) => return false,
+        };
+
+        // Return `true` if the candidate challenge and address are correct.
+        self.challenge == candidate_challenge && *address == candidate_address
+    }
+
+    /// Returns the signature completed with the given adaptor secret, where:
+    ///     response := pre_response + adaptor_secret
+    pub fn complete(&self, adaptor_secret: &Scalar<N>) -> Signature<N> {
+        Signature::from((self.challenge, self.pre_response + *adaptor_secret, self.compute_key))
+    }
+
+    /// Returns the adaptor secret `t` for the given adaptor point `T = t * G`,
+    /// from the signature that completed this adaptor signature.
+    pub fn extract(&self, signature: &Signature<N>, adaptor_point: Group<N>) -> Result<Scalar<N>> {
+        // Ensure the signature completes this adaptor signature.
+        ensure!(
+            signature.challenge() == self.challenge && signature.compute_key() == self.compute_key,
+            "The signature does not complete the adaptor signature"
+        );
+        // Compute the adaptor secret.
+        let adaptor_secret = signature.response() - self.pre_response;
+        // Ensure the adaptor secret corresponds to the adaptor point.
+        ensure!(
+            N::g_scalar_multiply(&adaptor_secret) == adaptor_point,
+            "The extracted adaptor secret does not match the adaptor point"
+        );
+        Ok(adaptor_secret)
+    }
+}
+
+impl<N: Network> FromBytes for AdaptorSignature<N> {
+    /// Reads an adaptor signature from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let challenge = Scalar::read_le(&mut reader)?;
+        let pre_response = Scalar::read_le(&mut reader)?;
+        let compute_key = ComputeKey::read_le(&mut reader)?;
+        Ok(Self { challenge, pre_response, compute_key })
+    }
+}
+
+impl<N: Network> ToBytes for AdaptorSignature<N> {
+    /// Writes an adaptor signature to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.challenge.write_le(&mut writer)?;
+        self.pre_response.write_le(&mut writer)?;
+        self.compute_key.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_pre_sign_and_complete() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Sample an adaptor secret and point.
+            let adaptor_secret = Scalar::rand(rng);
+            let adaptor_point = CurrentNetwork::g_scalar_multiply(&adaptor_secret);
+
+            // Check that the adaptor signature is valid for the message and adaptor point.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let adaptor_signature = Signature::pre_sign(&private_key, &message, adaptor_point, rng)?;
+            assert!(adaptor_signature.verify(&address, &message, adaptor_point));
+
+            // Check that the adaptor signature is invalid for an incorrect adaptor point.
+            assert!(!adaptor_signature.verify(&address, &message, Group::rand(rng)));
+            // Check that the adaptor signature is not a valid signature by itself.
+            let pre_signature = Signature::from((
+                adaptor_signature.challenge(),
+                adaptor_signature.pre_response(),
+                adaptor_signature.compute_key(),
+            ));
+            assert!(!pre_signature.verify(&address, &message));
+
+            // Check that the completed signature is valid.
+            let signature = adaptor_signature.complete(&adaptor_secret);
+            assert!(signature.verify(&address, &message));
+            // Check that the adaptor secret is extracted from the completed signature.
+            assert_eq!(adaptor_signature.extract(&signature, adaptor_point)?, adaptor_secret);
+            // Check that the adaptor secret cannot be extracted from an unrelated signature.
+            let other_signature = Signature::sign(&private_key, &message, rng)?;
+            assert!(adaptor_signature.extract(&other_signature, adaptor_point).is_err());
+
+            // Check the byte representation.
+            let adaptor_signature_bytes = adaptor_signature.to_bytes_le()?;
+            assert_eq!(adaptor_signature, AdaptorSignature::read_le(&adaptor_signature_bytes[..])?);
+        }
+        Ok(())
+    }
+}
@@ -25,6 +25,11 @@ mod verify;
 #[cfg(feature = "private_key")]
 mod sign;
 
+#[cfg(feature = "private_key")]
+mod adaptor;
+#[cfg(feature = "private_key")]
+pub use adaptor::AdaptorSignature;
+
 #[cfg(feature = "compute_key")]
 use crate::ComputeKey;
 #[cfg(feature = "private_key")]
@@ -32,7 +37,10 @@ use crate::PrivateKey;
 
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
-use snarkvm_console_types::{Boolean, Field, Scalar};
+use snarkvm_console_types::{Boolean, Field, Group, Scalar};
+
+#[cfg(feature = "private_key")]
+use zeroize::Zeroizing;
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
@@ -78,6 +86,27 @@ impl<N: Network> Signature<N> {
     pub fn to_address(&self) -> Address<N> {
         self.compute_key.to_address()
     }
+
+    /// Returns the verifier challenge for the given `g_r`, compute key, address, and message, as:
+    ///     challenge := HashToScalar(g_r, pk_sig, pr_sig, address, message)
+    pub(crate) fn compute_challenge(
+        g_r: Group<N>,
+        compute_key: &ComputeKey<N>,
+        address: &Address<N>,
+        message: &[Field<N>],
+    ) -> Result<Scalar<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("The message exceeds the maximum allowed size for a signature")
+        }
+        // Construct the hash input as (g_r, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage
+            .extend([g_r, compute_key.pk_sig(), compute_key.pr_sig(), **address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        // Compute the verifier challenge.
+        N::hash_to_scalar_psd8(&preimage)
+    }
 }
 
 impl<N: Network> TypeName for Signature<N> {
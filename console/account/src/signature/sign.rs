@@ -19,35 +19,21 @@ impl<N: Network> Signature<N> {
     ///     challenge := HashToScalar(nonce * G, pk_sig, pr_sig, address, message)
     ///     response := nonce - challenge * private_key.sk_sig()
     pub fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
-        // Ensure the number of field elements does not exceed the maximum allowed size.
-        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
-            bail!("Cannot sign the message: the message exceeds maximum allowed size")
-        }
-
         // Sample a random nonce from the scalar field.
-        let nonce = Scalar::rand(rng);
+        // Note: The nonce reveals the private key given the signature, so it is erased when it goes out of scope.
+        let nonce = Zeroizing::new(Scalar::rand(rng));
         // Compute `g_r` as `nonce * G`.
         let g_r = N::g_scalar_multiply(&nonce);
 
         // Derive the compute key from the private key.
         let compute_key = ComputeKey::try_from(private_key)?;
-        // Retrieve pk_sig.
-        let pk_sig = compute_key.pk_sig();
-        // Retrieve pr_sig.
-        let pr_sig = compute_key.pr_sig();
-
         // Derive the address from the compute key.
         let address = Address::try_from(compute_key)?;
 
-        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
-        let mut preimage = Vec::with_capacity(4 + message.len());
-        preimage.extend([g_r, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
-        preimage.extend(message);
-
         // Compute the verifier challenge.
-        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        let challenge = Self::compute_challenge(g_r, &compute_key, &address, message)?;
         // Compute the prover response.
-        let response = nonce - (challenge * private_key.sk_sig());
+        let response = *nonce - (challenge * private_key.sk_sig());
 
         // Output the signature.
         Ok(Self { challenge, response, compute_key })
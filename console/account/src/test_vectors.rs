@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Known-answer test vectors for account signatures.
+//!
+//! The vectors are sampled deterministically from a seed, and are returned as plain data
+//! (which is also `Serialize`), so alternative implementations can check their outputs against them.
+//! See also `snarkvm_console_network::test_vectors`.
+
+use crate::{Address, Field, PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+
+/// A known-answer vector for a signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SignatureVector {
+    /// The private key of the signer.
+    pub private_key: String,
+    /// The address of the signer.
+    pub address: String,
+    /// The message, as field elements.
+    pub message: Vec<String>,
+    /// The signature.
+    pub signature: String,
+}
+
+/// Returns `num_vectors` signature vectors, sampled from the given seed.
+pub fn signature_vectors<N: Network>(seed: u64, num_vectors: usize) -> Result<Vec<SignatureVector>> {
+    let rng = &mut TestRng::from_seed(seed);
+
+    let mut vectors = Vec::with_capacity(num_vectors);
+    for _ in 0..num_vectors {
+        // Sample a private key and a message.
+        let private_key = PrivateKey::<N>::new(rng)?;
+        let message = (0..rng.gen_range(1..=8)).map(|_| Field::<N>::rand(rng)).collect::<Vec<_>>();
+        // Sign the message.
+        let signature = Signature::sign(&private_key, &message, rng)?;
+        vectors.push(SignatureVector {
+            private_key: private_key.to_string(),
+            address: Address::try_from(&private_key)?.to_string(),
+            message: message.iter().map(|field| field.to_string()).collect(),
+            signature: signature.to_string(),
+        });
+    }
+    Ok(vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_signature_vectors() -> Result<()> {
+        let vectors = signature_vectors::<CurrentNetwork>(1234, 3)?;
+        assert_eq!(vectors, signature_vectors::<CurrentNetwork>(1234, 3)?);
+
+        // Ensure each signature verifies for its address and message.
+        for vector in vectors {
+            let address = Address::<CurrentNetwork>::from_str(&vector.address)?;
+            let message = vector.message.iter().map(|field| Field::from_str(field)).collect::<Result<Vec<_>>>()?;
+            let signature = Signature::<CurrentNetwork>::from_str(&vector.signature)?;
+            assert!(signature.verify(&address, &message));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> ViewKey<N> {
+    /// Decrypts the given encrypted message, and returns the message bytes.
+    /// This method returns an error if the message was not encrypted to this account, or was modified.
+    pub fn decrypt(&self, message: &EncryptedMessage<N>) -> Result<Vec<u8>> {
+        // Compute the message key.
+        let mut message_key = (*message.nonce() * **self).to_x_coordinate();
+        // Decrypt the message.
+        let decrypted = message.decrypt_symmetric(&message_key);
+        // Erase the message key.
+        message_key.zeroize();
+        decrypted
+    }
+}
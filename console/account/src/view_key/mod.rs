@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod bytes;
+#[cfg(feature = "encrypted_message")]
+mod decrypt;
 mod serialize;
 mod string;
 mod to_address;
@@ -20,16 +22,20 @@ mod try_from;
 
 #[cfg(feature = "compute_key")]
 use crate::ComputeKey;
+#[cfg(feature = "encrypted_message")]
+use crate::EncryptedMessage;
 #[cfg(feature = "private_key")]
 use crate::PrivateKey;
 
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Address, Scalar};
 
+use std::hash::{Hash, Hasher};
+use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
 
 /// The account view key used to decrypt records and ciphertext.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Zeroize)]
+#[derive(Copy, Clone, Debug, Zeroize)]
 pub struct ViewKey<N: Network>(Scalar<N>);
 
 impl<N: Network> ViewKey<N> {
@@ -39,6 +45,21 @@ impl<N: Network> ViewKey<N> {
     }
 }
 
+impl<N: Network> PartialEq for ViewKey<N> {
+    /// Returns `true` if the view keys are equal, in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bigint().as_ref().ct_eq(other.0.to_bigint().as_ref()).into()
+    }
+}
+
+impl<N: Network> Eq for ViewKey<N> {}
+
+impl<N: Network> Hash for ViewKey<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
 impl<N: Network> Deref for ViewKey<N> {
     type Target = Scalar<N>;
 
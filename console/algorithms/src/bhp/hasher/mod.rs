@@ -44,6 +44,18 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHPHasher<E,
 
     /// Initializes a new instance of BHP with the given domain.
     pub fn setup(domain: &str) -> Result<Self> {
+        Self::setup_with_label(domain)
+    }
+
+    /// Initializes a new instance of BHP from the given seed.
+    /// The bases are sampled exactly as in `setup`, with the label `Seed.{hex(seed)}` in place of the domain.
+    pub fn setup_from_seed(seed: &[u8; 32]) -> Result<Self> {
+        let seed_hex = seed.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        Self::setup_with_label(&format!("Seed.{seed_hex}"))
+    }
+
+    /// Initializes a new instance of BHP, sampling the bases with the given label.
+    fn setup_with_label(domain: &str) -> Result<Self> {
         // Calculate the maximum window size.
         let mut maximum_window_size = 0;
         let mut range = E::BigInteger::from(2_u64);
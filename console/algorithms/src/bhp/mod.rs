@@ -20,6 +20,7 @@ mod commit_uncompressed;
 mod hash;
 mod hash_uncompressed;
 
+use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
 
 use std::sync::Arc;
@@ -79,6 +80,44 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WI
         Ok(Self { domain, hasher })
     }
 
+    /// Initializes a new instance of BHP from the given 32-byte seed, so that independent
+    /// implementations can derive (and check) identical parameters from a published seed.
+    ///
+    /// The procedure is identical to `setup`, except for the label used to sample the bases:
+    /// ```text
+    /// BASE_i        = HashToCurve("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.Seed.{hex(seed)}.{i}")
+    /// RANDOM_BASE   = HashToCurve("Aleo.BHP.{NUM_WINDOWS}.{WINDOW_SIZE}.Seed.{hex(seed)}.Randomizer")
+    /// ```
+    /// where `hex(seed)` is the lowercase hexadecimal encoding of the seed, and `HashToCurve` is the
+    /// BLAKE2Xs hash-to-curve used by `setup`. The seed already separates the instance, so the domain
+    /// separator is empty (all zeros). As a domain is at most 23 bytes, a seed label never equals a domain.
+    pub fn setup_from_seed(seed: [u8; 32]) -> Result<Self> {
+        // Initialize the BHP hasher.
+        let hasher = BHPHasher::<E, NUM_WINDOWS, WINDOW_SIZE>::setup_from_seed(&seed)?;
+        // Initialize an empty domain separator.
+        let domain = vec![false; Field::<E>::size_in_data_bits() - 64];
+        Ok(Self { domain, hasher })
+    }
+
+    /// Returns a 32-byte checksum of the parameters, to check that two instances are identical.
+    ///
+    /// The checksum is the 32-byte BLAKE2Xs digest (with personalization `AleoBHPc`) of
+    /// `[ DOMAIN_BITS || BASES || RANDOM_BASE ]`, where each bit is one byte, and each base is
+    /// its little-endian byte encoding, in order.
+    pub fn checksum(&self) -> Result<[u8; 32]> {
+        let mut preimage = self.domain.iter().map(|bit| *bit as u8).collect::<Vec<_>>();
+        for base in self.bases().iter().flatten().chain(self.random_base().iter()) {
+            base.write_le(&mut preimage)?;
+        }
+        let digest = Blake2Xs::evaluate(&preimage, 32, "AleoBHPc".as_bytes());
+        digest.try_into().map_err(|_| anyhow!("Invalid BHP checksum length"))
+    }
+
+    /// Returns `true` if `self` and `other` have identical parameters.
+    pub fn is_identical_to(&self, other: &Self) -> bool {
+        self.domain == other.domain && self.bases() == other.bases() && self.random_base() == other.random_base()
+    }
+
     /// Returns the domain separator for the BHP hash function.
     pub fn domain(&self) -> &[bool] {
         &self.domain
@@ -104,3 +143,40 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WI
         WINDOW_SIZE
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_setup_from_seed() -> Result<()> {
+        // Ensure the same seed derives identical parameters.
+        let bhp = BHP256::<CurrentEnvironment>::setup_from_seed([7u8; 32])?;
+        let candidate = BHP256::<CurrentEnvironment>::setup_from_seed([7u8; 32])?;
+        assert!(bhp.is_identical_to(&candidate));
+        assert_eq!(bhp.checksum()?, candidate.checksum()?);
+
+        // Ensure a different seed derives different parameters.
+        let other = BHP256::<CurrentEnvironment>::setup_from_seed([8u8; 32])?;
+        assert!(!bhp.is_identical_to(&other));
+        assert_ne!(bhp.checksum()?, other.checksum()?);
+
+        // Ensure a domain-based setup derives different parameters.
+        let other = BHP256::<CurrentEnvironment>::setup("BHPTest")?;
+        assert!(!bhp.is_identical_to(&other));
+        assert_ne!(bhp.checksum()?, other.checksum()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_checksum() -> Result<()> {
+        // Ensure the checksum is deterministic for a domain-based setup.
+        let bhp = BHP512::<CurrentEnvironment>::setup("BHPTest")?;
+        assert_eq!(bhp.checksum()?, BHP512::<CurrentEnvironment>::setup("BHPTest")?.checksum()?);
+        assert_ne!(bhp.checksum()?, BHP512::<CurrentEnvironment>::setup("BHPTest2")?.checksum()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The `L` and `R` commitments and the final scalars `a` and `b` of an inner product argument.
+type InnerProductProof<E> = (Vec<Group<E>>, Vec<Group<E>>, Scalar<E>, Scalar<E>);
+
+impl<E: Environment> Bulletproofs<E> {
+    /// Returns the `L` and `R` commitments and the final scalars `a` and `b` of an inner product argument,
+    /// which proves knowledge of the vectors `a` and `b` such that `P = <a, G> + <b, H> + <a, b> * Q`.
+    pub(super) fn prove_inner_product(
+        transcript: &mut Transcript<E>,
+        q: Group<E>,
+        mut g: Vec<Group<E>>,
+        mut h: Vec<Group<E>>,
+        mut a: Vec<Scalar<E>>,
+        mut b: Vec<Scalar<E>>,
+    ) -> Result<InnerProductProof<E>> {
+        // Ensure the vectors have the same power-of-two length.
+        ensure!(a.len().is_power_of_two(), "The inner product vectors must have a power-of-two length");
+        ensure!(a.len() == b.len() && a.len() == g.len() && a.len() == h.len(), "Mismatching inner product lengths");
+
+        let num_rounds = a.len().trailing_zeros() as usize;
+        let mut l_vec = Vec::with_capacity(num_rounds);
+        let mut r_vec = Vec::with_capacity(num_rounds);
+
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+            let (h_lo, h_hi) = h.split_at(half);
+
+            // Compute L := <a_lo, G_hi> + <b_hi, H_lo> + <a_lo, b_hi> * Q.
+            let l =
+                multi_scalar_multiply(a_lo, g_hi) + multi_scalar_multiply(b_hi, h_lo) + q * inner_product(a_lo, b_hi);
+            // Compute R := <a_hi, G_lo> + <b_lo, H_hi> + <a_hi, b_lo> * Q.
+            let r =
+                multi_scalar_multiply(a_hi, g_lo) + multi_scalar_multiply(b_lo, h_hi) + q * inner_product(a_hi, b_lo);
+
+            // Derive the challenge `u`.
+            transcript.absorb_points(&[l, r])?;
+            let u = transcript.challenge()?;
+            let u_inv = u.inverse()?;
+
+            // Fold the vectors and the bases in half.
+            let a_next = a_lo.iter().zip_eq(a_hi).map(|(lo, hi)| *lo * u + *hi * u_inv).collect();
+            let b_next = b_lo.iter().zip_eq(b_hi).map(|(lo, hi)| *lo * u_inv + *hi * u).collect();
+            let g_next = g_lo.iter().zip_eq(g_hi).map(|(lo, hi)| *lo * u_inv + *hi * u).collect();
+            let h_next = h_lo.iter().zip_eq(h_hi).map(|(lo, hi)| *lo * u + *hi * u_inv).collect();
+            (a, b, g, h) = (a_next, b_next, g_next, h_next);
+
+            l_vec.push(l);
+            r_vec.push(r);
+        }
+
+        Ok((l_vec, r_vec, a[0], b[0]))
+    }
+
+    /// Returns `true` if the inner product argument is valid for `P = <a, G> + <b, H> + <a, b> * Q`.
+    pub(super) fn verify_inner_product(
+        transcript: &mut Transcript<E>,
+        q: Group<E>,
+        mut g: Vec<Group<E>>,
+        mut h: Vec<Group<E>>,
+        mut p: Group<E>,
+        proof: &RangeProof<E>,
+    ) -> Result<bool> {
+        // Ensure the number of rounds matches the number of bases.
+        let num_rounds = proof.l_vec().len();
+        ensure!(proof.r_vec().len() == num_rounds, "Mismatching number of inner product rounds");
+        ensure!(g.len() == h.len(), "Mismatching number of inner product bases");
+        ensure!(
+            g.len().is_power_of_two() && g.len().trailing_zeros() as usize == num_rounds,
+            "Incorrect number of inner product rounds"
+        );
+
+        for (l, r) in proof.l_vec().iter().zip_eq(proof.r_vec()) {
+            // Derive the challenge `u`.
+            transcript.absorb_points(&[*l, *r])?;
+            let u = transcript.challenge()?;
+            let u_inv = u.inverse()?;
+
+            // Compute P' := u^2 * L + P + u^-2 * R.
+            p = *l * u.square() + p + *r * u_inv.square();
+
+            // Fold the bases in half.
+            let half = g.len() / 2;
+            let (g_lo, g_hi) = g.split_at(half);
+            let (h_lo, h_hi) = h.split_at(half);
+            let g_next = g_lo.iter().zip_eq(g_hi).map(|(lo, hi)| *lo * u_inv + *hi * u).collect();
+            let h_next = h_lo.iter().zip_eq(h_hi).map(|(lo, hi)| *lo * u + *hi * u_inv).collect();
+            (g, h) = (g_next, h_next);
+        }
+
+        // Check that P = a * G + b * H + (a * b) * Q.
+        let (a, b) = (proof.ipa_a(), proof.ipa_b());
+        Ok(p == g[0] * a + h[0] * b + q * (a * b))
+    }
+}
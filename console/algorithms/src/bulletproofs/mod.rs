@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod inner_product;
+mod prove;
+mod verify;
+
+mod range_proof;
+pub use range_proof::RangeProof;
+
+use crate::{Blake2Xs, Pedersen64, Poseidon4};
+use snarkvm_console_types::prelude::*;
+
+use std::sync::Arc;
+
+/// The number of bits in a range proof, i.e. each committed value is proven to lie in `[0, 2^64)`.
+pub const RANGE_PROOF_NUM_BITS: usize = 64;
+
+/// Bulletproofs is a range proof system for Pedersen commitments of the form `value * G + blinding * H`,
+/// which proves, without a trusted setup, that each committed value lies in `[0, 2^64)`.
+///
+/// The bases `G` and `H` are those of `Pedersen64` for the same setup message, so the commitments
+/// match `Pedersen64::commit_uncompressed` on the bits of the value. A single proof may cover up to
+/// `max_parties` commitments (a power of two), with a size logarithmic in the number of commitments.
+#[derive(Clone)]
+pub struct Bulletproofs<E: Environment> {
+    /// The base `G` for the committed value.
+    value_base: Group<E>,
+    /// The base `H` for the blinding factor.
+    blinding_base: Group<E>,
+    /// The bases for the bits of the committed values.
+    g_vec: Arc<Vec<Group<E>>>,
+    /// The bases for the bits of the committed values, minus one.
+    h_vec: Arc<Vec<Group<E>>>,
+    /// The Poseidon hash function, used to derive the Fiat-Shamir challenges.
+    poseidon: Poseidon4<E>,
+}
+
+impl<E: Environment> Bulletproofs<E> {
+    /// Initializes a new instance of Bulletproofs with the given setup message,
+    /// for proofs over at most `max_parties` commitments.
+    pub fn setup(message: &str, max_parties: usize) -> Result<Self> {
+        // Ensure the maximum number of parties is a power of two.
+        ensure!(max_parties.is_power_of_two(), "The maximum number of parties must be a power of two");
+
+        // Retrieve the bases of the Pedersen commitment.
+        let pedersen = Pedersen64::<E>::setup(message);
+        let value_base = pedersen.base_window()[0];
+        let blinding_base = pedersen.random_base_window()[0];
+
+        // Sample the bases for the bits of the committed values.
+        let sample_bases = |label: &str| {
+            (0..RANGE_PROOF_NUM_BITS * max_parties)
+                .map(|index| {
+                    let input = format!("Aleo.Bulletproofs.{label}.{message}.{index}");
+                    Group::<E>::new(Blake2Xs::hash_to_curve::<E::Affine>(&input).0)
+                })
+                .collect::<Vec<_>>()
+        };
+        let g_vec = sample_bases("G");
+        let h_vec = sample_bases("H");
+
+        Ok(Self {
+            value_base,
+            blinding_base,
+            g_vec: Arc::new(g_vec),
+            h_vec: Arc::new(h_vec),
+            poseidon: Poseidon4::setup("AleoBulletproofs0")?,
+        })
+    }
+
+    /// Returns the base `G` for the committed value.
+    pub const fn value_base(&self) -> Group<E> {
+        self.value_base
+    }
+
+    /// Returns the base `H` for the blinding factor.
+    pub const fn blinding_base(&self) -> Group<E> {
+        self.blinding_base
+    }
+
+    /// Returns the maximum number of commitments in a single proof.
+    pub fn max_parties(&self) -> usize {
+        self.g_vec.len() / RANGE_PROOF_NUM_BITS
+    }
+
+    /// Returns the Pedersen commitment `value * G + blinding * H`.
+    pub fn commit(&self, value: u64, blinding: &Scalar<E>) -> Group<E> {
+        self.value_base * scalar_from_u64::<E>(value) + self.blinding_base * blinding
+    }
+
+    /// Ensures the given number of commitments can be proven with these parameters.
+    fn check_num_parties(&self, num_parties: usize) -> Result<()> {
+        // Ensure the number of commitments is a power of two.
+        ensure!(num_parties.is_power_of_two(), "The number of commitments must be a power of two, found {num_parties}");
+        // Ensure the number of commitments does not exceed the maximum.
+        let max_parties = self.max_parties();
+        ensure!(
+            num_parties <= max_parties,
+            "The number of commitments cannot exceed {max_parties}, found {num_parties}"
+        );
+        Ok(())
+    }
+}
+
+/// A Fiat-Shamir transcript, which absorbs the prover messages and squeezes the verifier challenges.
+struct Transcript<'a, E: Environment> {
+    /// The Poseidon hash function.
+    poseidon: &'a Poseidon4<E>,
+    /// The current state of the transcript.
+    state: Field<E>,
+}
+
+impl<'a, E: Environment> Transcript<'a, E> {
+    /// Initializes a new transcript for a proof over the given number of commitments.
+    fn new(poseidon: &'a Poseidon4<E>, num_parties: usize) -> Result<Self> {
+        let num_bits = Field::from_u64(RANGE_PROOF_NUM_BITS as u64);
+        let num_parties = Field::from_u64(u64::try_from(num_parties)?);
+        Ok(Self { poseidon, state: poseidon.hash(&[num_bits, num_parties])? })
+    }
+
+    /// Absorbs the given group elements into the transcript.
+    fn absorb_points(&mut self, points: &[Group<E>]) -> Result<()> {
+        let mut preimage = Vec::with_capacity(1 + points.len());
+        preimage.push(self.state);
+        preimage.extend(points.iter().map(|point| point.to_x_coordinate()));
+        self.state = self.poseidon.hash(&preimage)?;
+        Ok(())
+    }
+
+    /// Absorbs the given scalars into the transcript.
+    fn absorb_scalars(&mut self, scalars: &[Scalar<E>]) -> Result<()> {
+        let mut preimage = Vec::with_capacity(1 + scalars.len());
+        preimage.push(self.state);
+        for scalar in scalars {
+            preimage.push(scalar.to_field()?);
+        }
+        self.state = self.poseidon.hash(&preimage)?;
+        Ok(())
+    }
+
+    /// Returns the next (non-zero) challenge.
+    fn challenge(&mut self) -> Result<Scalar<E>> {
+        self.state = self.poseidon.hash(&[self.state])?;
+        let challenge = Scalar::from_field_lossy(&self.state);
+        ensure!(!challenge.is_zero(), "The Bulletproofs challenge cannot be zero");
+        Ok(challenge)
+    }
+}
+
+/// Returns the given integer as a scalar.
+fn scalar_from_u64<E: Environment>(value: u64) -> Scalar<E> {
+    Scalar::new(E::Scalar::from(value))
+}
+
+/// Returns `[1, base, base^2, ..., base^(num_powers - 1)]`.
+fn powers<E: Environment>(base: Scalar<E>, num_powers: usize) -> Vec<Scalar<E>> {
+    let mut powers = Vec::with_capacity(num_powers);
+    let mut power = Scalar::one();
+    for _ in 0..num_powers {
+        powers.push(power);
+        power *= base;
+    }
+    powers
+}
+
+/// Returns the vector `z^(2 + j) * 2^i` at index `j * 64 + i`, for each of the `num_parties` commitments.
+fn z_two_powers<E: Environment>(z: Scalar<E>, num_parties: usize) -> Vec<Scalar<E>> {
+    let z_powers = powers(z, num_parties + 2);
+    let two_powers = powers(scalar_from_u64::<E>(2), RANGE_PROOF_NUM_BITS);
+    z_powers[2..].iter().flat_map(|z_power| two_powers.iter().map(move |two_power| *z_power * two_power)).collect()
+}
+
+/// Returns the inner product `<a, b>`.
+fn inner_product<E: Environment>(a: &[Scalar<E>], b: &[Scalar<E>]) -> Scalar<E> {
+    a.iter().zip_eq(b).map(|(a, b)| *a * b).sum()
+}
+
+/// Returns the multi-scalar multiplication `<scalars, bases>`.
+fn multi_scalar_multiply<E: Environment>(scalars: &[Scalar<E>], bases: &[Group<E>]) -> Group<E> {
+    scalars.iter().zip_eq(bases).map(|(scalar, base)| *base * scalar).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 5;
+
+    fn sample_blindings(num_parties: usize, rng: &mut TestRng) -> Vec<Scalar<CurrentEnvironment>> {
+        (0..num_parties).map(|_| Uniform::rand(rng)).collect()
+    }
+
+    #[test]
+    fn test_prove_and_verify() -> Result<()> {
+        let bulletproofs = Bulletproofs::<CurrentEnvironment>::setup("BulletproofsTest", 4)?;
+        let mut rng = TestRng::default();
+
+        for num_parties in [1, 2, 4] {
+            for _ in 0..ITERATIONS {
+                // Sample the values, including the boundaries of the range.
+                let mut values = (0..num_parties).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+                values[0] = 0;
+                values[num_parties - 1] = u64::MAX;
+                let blindings = sample_blindings(num_parties, &mut rng);
+                let commitments =
+                    values.iter().zip_eq(&blindings).map(|(v, r)| bulletproofs.commit(*v, r)).collect::<Vec<_>>();
+
+                // Prove and verify the range proof.
+                let proof = bulletproofs.prove(&values, &blindings, &mut rng)?;
+                assert!(bulletproofs.verify(&commitments, &proof));
+
+                // Ensure the proof does not verify for a different commitment.
+                let mut wrong_commitments = commitments.clone();
+                wrong_commitments[0] = bulletproofs.commit(values[0].wrapping_add(1), &blindings[0]);
+                assert!(!bulletproofs.verify(&wrong_commitments, &proof));
+
+                // Ensure the proof does not verify for a different number of commitments.
+                assert!(!bulletproofs.verify(&commitments[..num_parties / 2], &proof));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_num_parties() -> Result<()> {
+        let bulletproofs = Bulletproofs::<CurrentEnvironment>::setup("BulletproofsTest", 2)?;
+        let mut rng = TestRng::default();
+
+        // Ensure the number of commitments must be a non-zero power of two, up to the maximum.
+        for num_parties in [0, 3, 4] {
+            let values = vec![1u64; num_parties];
+            let blindings = sample_blindings(num_parties, &mut rng);
+            assert!(bulletproofs.prove(&values, &blindings, &mut rng).is_err());
+        }
+        // Ensure the number of values and blindings must match.
+        assert!(bulletproofs.prove(&[1, 2], &sample_blindings(1, &mut rng), &mut rng).is_err());
+        // Ensure the maximum number of parties must be a power of two.
+        assert!(Bulletproofs::<CurrentEnvironment>::setup("BulletproofsTest", 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_pedersen64() -> Result<()> {
+        let bulletproofs = Bulletproofs::<CurrentEnvironment>::setup("BulletproofsTest", 1)?;
+        let pedersen = Pedersen64::<CurrentEnvironment>::setup("BulletproofsTest");
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let value = rng.gen::<u64>();
+            let blinding = Uniform::rand(&mut rng);
+            // Ensure the Bulletproofs commitment matches the Pedersen64 commitment.
+            let expected = pedersen.commit_uncompressed(&value.to_bits_le(), &blinding)?;
+            assert_eq!(expected, bulletproofs.commit(value, &blinding));
+
+            // Ensure a range proof verifies against the Pedersen64 commitment.
+            let proof = bulletproofs.prove(&[value], &[blinding], &mut rng)?;
+            assert!(bulletproofs.verify(&[expected], &proof));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Bulletproofs<E> {
+    /// Returns an aggregated range proof that each of the given values lies in `[0, 2^64)`,
+    /// for the commitments `value * G + blinding * H`.
+    /// The number of values must be a power of two, and at most `max_parties`.
+    pub fn prove<R: Rng + CryptoRng>(
+        &self,
+        values: &[u64],
+        blindings: &[Scalar<E>],
+        rng: &mut R,
+    ) -> Result<RangeProof<E>> {
+        // Ensure the number of values and blindings match.
+        ensure!(values.len() == blindings.len(), "The number of values and blindings must match");
+        // Ensure the number of commitments is supported.
+        self.check_num_parties(values.len())?;
+
+        let num_parties = values.len();
+        let size = RANGE_PROOF_NUM_BITS * num_parties;
+        let (g_vec, h_vec) = (&self.g_vec[..size], &self.h_vec[..size]);
+
+        // Initialize the transcript with the commitments.
+        let commitments =
+            values.iter().zip_eq(blindings).map(|(value, blinding)| self.commit(*value, blinding)).collect::<Vec<_>>();
+        let mut transcript = Transcript::new(&self.poseidon, num_parties)?;
+        transcript.absorb_points(&commitments)?;
+
+        // Decompose the values into bits `a_L`, and set `a_R := a_L - 1`.
+        let a_l = values
+            .iter()
+            .flat_map(|value| (0..RANGE_PROOF_NUM_BITS).map(move |i| scalar_from_u64::<E>((value >> i) & 1)))
+            .collect::<Vec<_>>();
+        let a_r = a_l.iter().map(|bit| *bit - Scalar::one()).collect::<Vec<_>>();
+
+        // Commit to the bits as A := alpha * H + <a_L, G> + <a_R, H>.
+        let alpha = Scalar::rand(rng);
+        let a = self.blinding_base * alpha + multi_scalar_multiply(&a_l, g_vec) + multi_scalar_multiply(&a_r, h_vec);
+
+        // Commit to the blinding vectors as S := rho * H + <s_L, G> + <s_R, H>.
+        let s_l = (0..size).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+        let s_r = (0..size).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+        let rho = Scalar::rand(rng);
+        let s = self.blinding_base * rho + multi_scalar_multiply(&s_l, g_vec) + multi_scalar_multiply(&s_r, h_vec);
+
+        // Derive the challenges `y` and `z`.
+        transcript.absorb_points(&[a, s])?;
+        let y = transcript.challenge()?;
+        let z = transcript.challenge()?;
+
+        // Compute the coefficients of l(X) := (a_L - z) + s_L * X,
+        // and r(X) := y^n o (a_R + z + s_R * X) + z^(2 + j) * 2^i.
+        let y_powers = powers(y, size);
+        let z_two = z_two_powers(z, num_parties);
+        let l_0 = a_l.iter().map(|bit| *bit - z).collect::<Vec<_>>();
+        let l_1 = s_l;
+        let r_0 = (0..size).map(|i| y_powers[i] * (a_r[i] + z) + z_two[i]).collect::<Vec<_>>();
+        let r_1 = (0..size).map(|i| y_powers[i] * s_r[i]).collect::<Vec<_>>();
+
+        // Compute the coefficients t_1 and t_2 of t(X) := <l(X), r(X)>, and commit to them.
+        let t_1 = inner_product(&l_0, &r_1) + inner_product(&l_1, &r_0);
+        let t_2 = inner_product(&l_1, &r_1);
+        let tau_1 = Scalar::rand(rng);
+        let tau_2 = Scalar::rand(rng);
+        let t_1_commitment = self.value_base * t_1 + self.blinding_base * tau_1;
+        let t_2_commitment = self.value_base * t_2 + self.blinding_base * tau_2;
+
+        // Derive the challenge `x`.
+        transcript.absorb_points(&[t_1_commitment, t_2_commitment])?;
+        let x = transcript.challenge()?;
+
+        // Evaluate l(x), r(x), and t(x).
+        let l = l_0.iter().zip_eq(&l_1).map(|(l_0, l_1)| *l_0 + *l_1 * x).collect::<Vec<_>>();
+        let r = r_0.iter().zip_eq(&r_1).map(|(r_0, r_1)| *r_0 + *r_1 * x).collect::<Vec<_>>();
+        let t_hat = inner_product(&l, &r);
+
+        // Compute the blinding factors tau_x and mu.
+        let z_powers = powers(z, num_parties + 2);
+        let tau_x = tau_2 * x.square()
+            + tau_1 * x
+            + z_powers[2..].iter().zip_eq(blindings).map(|(z_power, blinding)| *z_power * blinding).sum::<Scalar<E>>();
+        let mu = alpha + rho * x;
+
+        // Derive the challenge `w`, which binds t(x) to the inner product argument.
+        transcript.absorb_scalars(&[tau_x, mu, t_hat])?;
+        let q = self.value_base * transcript.challenge()?;
+
+        // Prove the inner product <l, r> over the bases G and H' := y^-i * H.
+        let y_inv_powers = powers(y.inverse()?, size);
+        let h_prime = h_vec.iter().zip_eq(&y_inv_powers).map(|(h, y_inv)| *h * y_inv).collect();
+        let (l_vec, r_vec, ipa_a, ipa_b) =
+            Self::prove_inner_product(&mut transcript, q, g_vec.to_vec(), h_prime, l, r)?;
+
+        Ok(RangeProof::new(a, s, t_1_commitment, t_2_commitment, tau_x, mu, t_hat, l_vec, r_vec, ipa_a, ipa_b))
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> FromBytes for RangeProof<E> {
+    /// Reads the range proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid range proof version"));
+        }
+
+        // Read the commitments.
+        let a = Group::read_le(&mut reader)?;
+        let s = Group::read_le(&mut reader)?;
+        let t_1_commitment = Group::read_le(&mut reader)?;
+        let t_2_commitment = Group::read_le(&mut reader)?;
+        // Read the blinding factors and the evaluation.
+        let tau_x = Scalar::read_le(&mut reader)?;
+        let mu = Scalar::read_le(&mut reader)?;
+        let t_hat = Scalar::read_le(&mut reader)?;
+        // Read the number of inner product rounds.
+        let num_rounds = u8::read_le(&mut reader)?;
+        // Read the inner product commitments.
+        let l_vec = (0..num_rounds).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let r_vec = (0..num_rounds).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the final inner product scalars.
+        let ipa_a = Scalar::read_le(&mut reader)?;
+        let ipa_b = Scalar::read_le(&mut reader)?;
+
+        Ok(Self::new(a, s, t_1_commitment, t_2_commitment, tau_x, mu, t_hat, l_vec, r_vec, ipa_a, ipa_b))
+    }
+}
+
+impl<E: Environment> ToBytes for RangeProof<E> {
+    /// Writes the range proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of inner product rounds is consistent.
+        if self.l_vec.len() != self.r_vec.len() {
+            return Err(error("Mismatching number of inner product rounds in the range proof"));
+        }
+
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the commitments.
+        self.a.write_le(&mut writer)?;
+        self.s.write_le(&mut writer)?;
+        self.t_1_commitment.write_le(&mut writer)?;
+        self.t_2_commitment.write_le(&mut writer)?;
+        // Write the blinding factors and the evaluation.
+        self.tau_x.write_le(&mut writer)?;
+        self.mu.write_le(&mut writer)?;
+        self.t_hat.write_le(&mut writer)?;
+        // Write the number of inner product rounds.
+        u8::try_from(self.l_vec.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the inner product commitments.
+        self.l_vec.write_le(&mut writer)?;
+        self.r_vec.write_le(&mut writer)?;
+        // Write the final inner product scalars.
+        self.ipa_a.write_le(&mut writer)?;
+        self.ipa_b.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bulletproofs;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let bulletproofs = Bulletproofs::<CurrentEnvironment>::setup("BulletproofsTest", 2)?;
+        let mut rng = TestRng::default();
+
+        for num_parties in [1, 2] {
+            let values = (0..num_parties).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+            let blindings = (0..num_parties).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+            let commitments =
+                values.iter().zip_eq(&blindings).map(|(v, r)| bulletproofs.commit(*v, r)).collect::<Vec<_>>();
+
+            // Check the byte representation.
+            let expected = bulletproofs.prove(&values, &blindings, &mut rng)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = RangeProof::read_le(&expected_bytes[..])?;
+            assert_eq!(expected, candidate);
+            assert!(bulletproofs.verify(&commitments, &candidate));
+
+            // Ensure an invalid version fails.
+            let mut invalid_bytes = expected_bytes.clone();
+            invalid_bytes[0] = 0;
+            assert!(RangeProof::<CurrentEnvironment>::read_le(&invalid_bytes[..]).is_err());
+        }
+        Ok(())
+    }
+}
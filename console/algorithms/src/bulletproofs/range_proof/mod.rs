@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use super::*;
+
+/// An aggregated Bulletproofs range proof, showing that each committed value lies in `[0, 2^64)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeProof<E: Environment> {
+    /// The commitment `A` to the bits of the values.
+    a: Group<E>,
+    /// The commitment `S` to the blinding vectors.
+    s: Group<E>,
+    /// The commitment `T_1` to the linear coefficient of t(X).
+    t_1_commitment: Group<E>,
+    /// The commitment `T_2` to the quadratic coefficient of t(X).
+    t_2_commitment: Group<E>,
+    /// The blinding factor `tau_x` of t(x).
+    tau_x: Scalar<E>,
+    /// The blinding factor `mu` of `A` and `S`.
+    mu: Scalar<E>,
+    /// The evaluation `t(x)`.
+    t_hat: Scalar<E>,
+    /// The `L` commitments of the inner product argument.
+    l_vec: Vec<Group<E>>,
+    /// The `R` commitments of the inner product argument.
+    r_vec: Vec<Group<E>>,
+    /// The final scalar `a` of the inner product argument.
+    ipa_a: Scalar<E>,
+    /// The final scalar `b` of the inner product argument.
+    ipa_b: Scalar<E>,
+}
+
+impl<E: Environment> RangeProof<E> {
+    /// Initializes a new range proof.
+    pub fn new(
+        a: Group<E>,
+        s: Group<E>,
+        t_1_commitment: Group<E>,
+        t_2_commitment: Group<E>,
+        tau_x: Scalar<E>,
+        mu: Scalar<E>,
+        t_hat: Scalar<E>,
+        l_vec: Vec<Group<E>>,
+        r_vec: Vec<Group<E>>,
+        ipa_a: Scalar<E>,
+        ipa_b: Scalar<E>,
+    ) -> Self {
+        Self { a, s, t_1_commitment, t_2_commitment, tau_x, mu, t_hat, l_vec, r_vec, ipa_a, ipa_b }
+    }
+
+    /// Returns the commitment `A` to the bits of the values.
+    pub const fn a(&self) -> Group<E> {
+        self.a
+    }
+
+    /// Returns the commitment `S` to the blinding vectors.
+    pub const fn s(&self) -> Group<E> {
+        self.s
+    }
+
+    /// Returns the commitment `T_1` to the linear coefficient of t(X).
+    pub const fn t_1_commitment(&self) -> Group<E> {
+        self.t_1_commitment
+    }
+
+    /// Returns the commitment `T_2` to the quadratic coefficient of t(X).
+    pub const fn t_2_commitment(&self) -> Group<E> {
+        self.t_2_commitment
+    }
+
+    /// Returns the blinding factor `tau_x` of t(x).
+    pub const fn tau_x(&self) -> Scalar<E> {
+        self.tau_x
+    }
+
+    /// Returns the blinding factor `mu` of `A` and `S`.
+    pub const fn mu(&self) -> Scalar<E> {
+        self.mu
+    }
+
+    /// Returns the evaluation `t(x)`.
+    pub const fn t_hat(&self) -> Scalar<E> {
+        self.t_hat
+    }
+
+    /// Returns the `L` commitments of the inner product argument.
+    pub fn l_vec(&self) -> &[Group<E>] {
+        &self.l_vec
+    }
+
+    /// Returns the `R` commitments of the inner product argument.
+    pub fn r_vec(&self) -> &[Group<E>] {
+        &self.r_vec
+    }
+
+    /// Returns the final scalar `a` of the inner product argument.
+    pub const fn ipa_a(&self) -> Scalar<E> {
+        self.ipa_a
+    }
+
+    /// Returns the final scalar `b` of the inner product argument.
+    pub const fn ipa_b(&self) -> Scalar<E> {
+        self.ipa_b
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Bulletproofs<E> {
+    /// Returns `true` if the range proof shows that each of the given commitments is to a value in `[0, 2^64)`.
+    pub fn verify(&self, commitments: &[Group<E>], proof: &RangeProof<E>) -> bool {
+        match self.check_range_proof(commitments, proof) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify the range proof: {error}");
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if the range proof is valid for the given commitments.
+    fn check_range_proof(&self, commitments: &[Group<E>], proof: &RangeProof<E>) -> Result<bool> {
+        // Ensure the number of commitments is supported.
+        self.check_num_parties(commitments.len())?;
+
+        let num_parties = commitments.len();
+        let size = RANGE_PROOF_NUM_BITS * num_parties;
+        let (g_vec, h_vec) = (&self.g_vec[..size], &self.h_vec[..size]);
+
+        // Recompute the challenges.
+        let mut transcript = Transcript::new(&self.poseidon, num_parties)?;
+        transcript.absorb_points(commitments)?;
+        transcript.absorb_points(&[proof.a(), proof.s()])?;
+        let y = transcript.challenge()?;
+        let z = transcript.challenge()?;
+        transcript.absorb_points(&[proof.t_1_commitment(), proof.t_2_commitment()])?;
+        let x = transcript.challenge()?;
+        transcript.absorb_scalars(&[proof.tau_x(), proof.mu(), proof.t_hat()])?;
+        let q = self.value_base * transcript.challenge()?;
+
+        // Compute delta(y, z) := (z - z^2) * <1, y^n> - sum_j z^(3 + j) * <1, 2^64>.
+        let y_powers = powers(y, size);
+        let z_powers = powers(z, num_parties + 3);
+        let sum_of_two_powers = powers(scalar_from_u64::<E>(2), RANGE_PROOF_NUM_BITS).into_iter().sum::<Scalar<E>>();
+        let delta = (z - z.square()) * y_powers.iter().sum::<Scalar<E>>()
+            - z_powers[3..].iter().sum::<Scalar<E>>() * sum_of_two_powers;
+
+        // Check that t(x) * G + tau_x * H == sum_j z^(2 + j) * V_j + delta(y, z) * G + x * T_1 + x^2 * T_2.
+        let lhs = self.value_base * proof.t_hat() + self.blinding_base * proof.tau_x();
+        let rhs = commitments
+            .iter()
+            .zip_eq(&z_powers[2..])
+            .map(|(commitment, z_power)| *commitment * z_power)
+            .sum::<Group<E>>()
+            + self.value_base * delta
+            + proof.t_1_commitment() * x
+            + proof.t_2_commitment() * x.square();
+        if lhs != rhs {
+            return Ok(false);
+        }
+
+        // Compute the bases H' := y^-i * H.
+        let y_inv_powers = powers(y.inverse()?, size);
+        let h_prime = h_vec.iter().zip_eq(&y_inv_powers).map(|(h, y_inv)| *h * y_inv).collect::<Vec<_>>();
+
+        // Compute P := A + x * S - mu * H - z * <1, G> + <z * y^n + z^(2 + j) * 2^i, H'> + t(x) * Q.
+        let z_two = z_two_powers(z, num_parties);
+        let h_prime_scalars = (0..size).map(|i| z * y_powers[i] + z_two[i]).collect::<Vec<_>>();
+        let p = proof.a() + proof.s() * x - self.blinding_base * proof.mu() - g_vec.iter().sum::<Group<E>>() * z
+            + multi_scalar_multiply(&h_prime_scalars, &h_prime)
+            + q * proof.t_hat();
+
+        // Verify the inner product argument for <l, r> = t(x).
+        Self::verify_inner_product(&mut transcript, q, g_vec.to_vec(), h_prime, p, proof)
+    }
+}
@@ -22,6 +22,9 @@ pub use snarkvm_console_types::prelude::*;
 pub mod bhp;
 pub use bhp::{BHP, BHP1024, BHP256, BHP512, BHP768};
 
+mod bulletproofs;
+pub use bulletproofs::{Bulletproofs, RangeProof, RANGE_PROOF_NUM_BITS};
+
 mod blake2xs;
 pub use blake2xs::Blake2Xs;
 
@@ -36,3 +39,6 @@ pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
 mod poseidon;
 pub use poseidon::{Poseidon, Poseidon2, Poseidon4, Poseidon8};
+
+#[cfg(feature = "test_vectors")]
+pub mod test_vectors;
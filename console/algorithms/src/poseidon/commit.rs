@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, const RATE: usize> Commit for Poseidon<E, RATE> {
+    type Input = Field<E>;
+    type Output = Field<E>;
+    type Randomizer = Scalar<E>;
+
+    /// Returns the Poseidon commitment of the given field elements and randomizer.
+    /// Note: The preimage is prefixed with a commitment domain separator, so a commitment is
+    /// domain-separated from the hash and PRF outputs of the same instance.
+    #[inline]
+    fn commit(&self, input: &[Self::Input], randomizer: &Self::Randomizer) -> Result<Self::Output> {
+        // Construct the preimage: commit_domain || randomizer || input.
+        let mut preimage = Vec::with_capacity(2 + input.len());
+        preimage.push(self.commit_domain);
+        preimage.push(randomizer.to_field()?);
+        preimage.extend_from_slice(input);
+
+        // Hash the preimage to derive the commitment.
+        self.hash(&preimage)
+    }
+}
@@ -14,6 +14,7 @@
 
 mod helpers;
 
+mod commit;
 mod hash;
 mod hash_many;
 mod hash_to_group;
@@ -27,6 +28,8 @@ use snarkvm_fields::{PoseidonDefaultField, PoseidonParameters};
 use std::sync::Arc;
 
 const CAPACITY: usize = 1;
+/// The domain separator for Poseidon commitments, which is prepended to the commitment preimage.
+const COMMIT_DOMAIN: &str = "AleoPoseidonCommit0";
 
 /// Poseidon2 is a cryptographic hash function of input rate 2.
 pub type Poseidon2<E> = Poseidon<E, 2>;
@@ -39,6 +42,8 @@ pub type Poseidon8<E> = Poseidon<E, 8>;
 pub struct Poseidon<E: Environment, const RATE: usize> {
     /// The domain separator for the Poseidon hash function.
     domain: Field<E>,
+    /// The domain separator for Poseidon commitments.
+    commit_domain: Field<E>,
     /// The Poseidon parameters for hashing.
     parameters: Arc<PoseidonParameters<E::Field, RATE, CAPACITY>>,
 }
@@ -53,6 +58,7 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
 
         Ok(Self {
             domain: Field::<E>::new_domain_separator(domain),
+            commit_domain: Field::<E>::new_domain_separator(COMMIT_DOMAIN),
             parameters: Arc::new(E::Field::default_poseidon_parameters::<RATE>()?),
         })
     }
@@ -62,6 +68,11 @@ impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
         self.domain
     }
 
+    /// Returns the domain separator for commitments.
+    pub fn commit_domain(&self) -> Field<E> {
+        self.commit_domain
+    }
+
     /// Returns the Poseidon parameters for hashing.
     pub fn parameters(&self) -> &Arc<PoseidonParameters<E::Field, RATE, CAPACITY>> {
         &self.parameters
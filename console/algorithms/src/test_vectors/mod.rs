@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Known-answer test vectors for the hash functions and commitments of the console algorithms.
+//!
+//! The algorithms are set up with the same domains as the network instances (e.g. `AleoBHP256`),
+//! and the vectors are sampled deterministically from a seed, so alternative implementations can
+//! check their outputs against them. Bit-oriented inputs are encoded as hex bytes, and are hashed
+//! as their little-endian bits. Field elements and scalars are encoded in their string form (e.g. `1field`).
+
+#![allow(clippy::type_complexity)]
+
+use crate::*;
+
+/// A known-answer vector for a hash function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct HashVector {
+    /// The name of the hash function, e.g. `bhp256` or `psd2`.
+    pub function: String,
+    /// The input, as a single hex string for bit-oriented hashes, or as field elements for Poseidon.
+    pub input: Vec<String>,
+    /// The output, as a field element, or as a hex string for Keccak and SHA-3.
+    pub output: String,
+}
+
+/// A known-answer vector for a commitment scheme.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CommitVector {
+    /// The name of the commitment scheme, e.g. `bhp256` or `ped64`.
+    pub function: String,
+    /// The input, as a hex string.
+    pub input: String,
+    /// The randomizer, as a scalar.
+    pub randomizer: String,
+    /// The commitment, as a field element.
+    pub output: String,
+}
+
+/// Returns `num_vectors` hash vectors for each hash function, sampled from the given seed.
+pub fn hash_vectors<E: Environment>(seed: u64, num_vectors: usize) -> Result<Vec<HashVector>> {
+    let rng = &mut TestRng::from_seed(seed);
+
+    let (bhp256, bhp512, bhp768, bhp1024) = setup_bhp::<E>()?;
+    let (ped64, ped128) = setup_pedersen::<E>();
+    let psd2 = Poseidon2::<E>::setup("AleoPoseidon2")?;
+    let psd4 = Poseidon4::<E>::setup("AleoPoseidon4")?;
+    let psd8 = Poseidon8::<E>::setup("AleoPoseidon8")?;
+
+    // The bit-oriented hash functions that output a field element.
+    let to_field: [(&str, usize, &dyn Hash<Input = bool, Output = Field<E>>); 6] = [
+        ("bhp256", 64, &bhp256),
+        ("bhp512", 64, &bhp512),
+        ("bhp768", 64, &bhp768),
+        ("bhp1024", 64, &bhp1024),
+        ("ped64", 8, &ped64),
+        ("ped128", 16, &ped128),
+    ];
+    // The bit-oriented hash functions that output bits.
+    let to_bits: [(&str, usize, &dyn Hash<Input = bool, Output = Vec<bool>>); 6] = [
+        ("keccak256", 64, &Keccak256::default()),
+        ("keccak384", 64, &Keccak384::default()),
+        ("keccak512", 64, &Keccak512::default()),
+        ("sha3_256", 64, &Sha3_256::default()),
+        ("sha3_384", 64, &Sha3_384::default()),
+        ("sha3_512", 64, &Sha3_512::default()),
+    ];
+    // The Poseidon hash functions.
+    let poseidon: [(&str, &dyn Hash<Input = Field<E>, Output = Field<E>>); 3] =
+        [("psd2", &psd2), ("psd4", &psd4), ("psd8", &psd8)];
+
+    let mut vectors = Vec::new();
+    for (function, max_bytes, hasher) in to_field {
+        for _ in 0..num_vectors {
+            let input = sample_bytes(max_bytes, rng);
+            let output = hasher.hash(&input.to_bits_le())?;
+            vectors.push(HashVector {
+                function: function.into(),
+                input: vec![to_hex(&input)],
+                output: output.to_string(),
+            });
+        }
+    }
+    for (function, max_bytes, hasher) in to_bits {
+        for _ in 0..num_vectors {
+            let input = sample_bytes(max_bytes, rng);
+            let output = bits_to_bytes(&hasher.hash(&input.to_bits_le())?);
+            vectors.push(HashVector {
+                function: function.into(),
+                input: vec![to_hex(&input)],
+                output: to_hex(&output),
+            });
+        }
+    }
+    for (function, hasher) in poseidon {
+        for _ in 0..num_vectors {
+            let input = (0..rng.gen_range(1..=8)).map(|_| Field::<E>::rand(rng)).collect::<Vec<_>>();
+            let output = hasher.hash(&input)?;
+            vectors.push(HashVector {
+                function: function.into(),
+                input: input.iter().map(|field| field.to_string()).collect(),
+                output: output.to_string(),
+            });
+        }
+    }
+    Ok(vectors)
+}
+
+/// Returns `num_vectors` commitment vectors for each commitment scheme, sampled from the given seed.
+pub fn commit_vectors<E: Environment>(seed: u64, num_vectors: usize) -> Result<Vec<CommitVector>> {
+    let rng = &mut TestRng::from_seed(seed);
+
+    let (bhp256, bhp512, bhp768, bhp1024) = setup_bhp::<E>()?;
+    let (ped64, ped128) = setup_pedersen::<E>();
+
+    // The commitment schemes.
+    let schemes: [(&str, usize, &dyn Commit<Input = bool, Output = Field<E>, Randomizer = Scalar<E>>); 6] = [
+        ("bhp256", 64, &bhp256),
+        ("bhp512", 64, &bhp512),
+        ("bhp768", 64, &bhp768),
+        ("bhp1024", 64, &bhp1024),
+        ("ped64", 8, &ped64),
+        ("ped128", 16, &ped128),
+    ];
+
+    let mut vectors = Vec::new();
+    for (function, max_bytes, scheme) in schemes {
+        for _ in 0..num_vectors {
+            let input = sample_bytes(max_bytes, rng);
+            let randomizer = Scalar::<E>::rand(rng);
+            let output = scheme.commit(&input.to_bits_le(), &randomizer)?;
+            vectors.push(CommitVector {
+                function: function.into(),
+                input: to_hex(&input),
+                randomizer: randomizer.to_string(),
+                output: output.to_string(),
+            });
+        }
+    }
+    Ok(vectors)
+}
+
+/// Returns the BHP instances, set up with the network domains.
+fn setup_bhp<E: Environment>() -> Result<(BHP256<E>, BHP512<E>, BHP768<E>, BHP1024<E>)> {
+    Ok((
+        BHP256::setup("AleoBHP256")?,
+        BHP512::setup("AleoBHP512")?,
+        BHP768::setup("AleoBHP768")?,
+        BHP1024::setup("AleoBHP1024")?,
+    ))
+}
+
+/// Returns the Pedersen instances, set up with the network domains.
+fn setup_pedersen<E: Environment>() -> (Pedersen64<E>, Pedersen128<E>) {
+    (Pedersen64::setup("AleoPedersen64"), Pedersen128::setup("AleoPedersen128"))
+}
+
+/// Samples between 1 and `max_bytes` random bytes.
+fn sample_bytes<R: Rng>(max_bytes: usize, rng: &mut R) -> Vec<u8> {
+    (0..rng.gen_range(1..=max_bytes)).map(|_| rng.gen()).collect()
+}
+
+/// Returns the given little-endian bits as bytes.
+pub fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8).map(|byte| byte.iter().rev().fold(0u8, |acc, bit| (acc << 1) | *bit as u8)).collect()
+}
+
+/// Returns the given bytes as a lowercase hex string.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const SEED: u64 = 1234;
+
+    /// Asserts the given vectors match the JSON file of the given name in the `resources` folder.
+    /// Run with `UPDATE_EXPECT=1` to regenerate the file.
+    #[track_caller]
+    fn assert_vectors<T: Serialize>(name: &str, vectors: &[T]) {
+        let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src/test_vectors/resources");
+        path.push(name);
+        path.set_extension("json");
+        expect_test::expect_file![path].assert_eq(&serde_json::to_string_pretty(vectors).unwrap());
+    }
+
+    #[test]
+    fn test_vectors_are_deterministic() -> Result<()> {
+        assert_eq!(hash_vectors::<CurrentEnvironment>(SEED, 2)?, hash_vectors::<CurrentEnvironment>(SEED, 2)?);
+        assert_eq!(commit_vectors::<CurrentEnvironment>(SEED, 2)?, commit_vectors::<CurrentEnvironment>(SEED, 2)?);
+        // Ensure a different seed produces different vectors.
+        assert_ne!(hash_vectors::<CurrentEnvironment>(SEED, 2)?, hash_vectors::<CurrentEnvironment>(SEED + 1, 2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_vectors() -> Result<()> {
+        let vectors = hash_vectors::<CurrentEnvironment>(SEED, 2)?;
+        assert_eq!(vectors.len(), 15 * 2);
+        assert_vectors("hash_vectors", &vectors);
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_vectors() -> Result<()> {
+        let vectors = commit_vectors::<CurrentEnvironment>(SEED, 2)?;
+        assert_eq!(vectors.len(), 6 * 2);
+        assert_vectors("commit_vectors", &vectors);
+        Ok(())
+    }
+
+    #[test]
+    fn test_keccak256_known_answer() -> Result<()> {
+        // The Keccak-256 digest of the empty string.
+        let output = bits_to_bytes(&Keccak256::default().hash(&[])?);
+        assert_eq!(to_hex(&output), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        Ok(())
+    }
+
+    #[test]
+    fn test_bits_to_bytes() {
+        let bytes = [0x00, 0x01, 0x80, 0xff, 0x5a];
+        assert_eq!(bits_to_bytes(&bytes.to_bits_le()), bytes);
+        assert_eq!(to_hex(&bytes), "000180ff5a");
+    }
+}
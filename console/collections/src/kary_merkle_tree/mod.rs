@@ -221,16 +221,19 @@ impl<LH: LeafHash<Hash = PH::Hash>, PH: PathHash, const DEPTH: u8, const ARITY:
 
 /// Returns the depth of the tree, given the size of the tree.
 #[inline]
-#[allow(clippy::cast_possible_truncation)]
 fn tree_depth<const DEPTH: u8, const ARITY: u8>(tree_size: usize) -> Result<u8> {
     let tree_size = u64::try_from(tree_size)?;
 
-    ensure!(tree_size < 4503599627370496_u64, "Tree size must be less than 2^52");
+    ensure!(ARITY > 1, "Merkle tree arity must be greater than 1");
 
-    // Calculate the tree depth based on the tree size and arity.
-    // log_arity(tree_size) =  ln(tree_size) / ln(arity).
-    let tree_depth_float = (tree_size as f64).ln() / (ARITY as f64).ln();
-    let tree_depth = u8::try_from(tree_depth_float.floor() as u64)?;
+    // Calculate the tree depth as floor(log_arity(tree_size)), using integer arithmetic
+    // so that the depth is identical on every platform.
+    let mut tree_depth = 0u8;
+    let mut remaining = tree_size / ARITY as u64;
+    while remaining > 0 {
+        tree_depth += 1;
+        remaining /= ARITY as u64;
+    }
 
     // Ensure the tree depth is within the depth bound.
     match tree_depth <= DEPTH {
@@ -622,3 +622,28 @@ fn test_kary_merkle_tree_size_is_within_bounds() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_tree_depth_is_exact() -> Result<()> {
+    fn check<const ARITY: u8>() -> Result<()> {
+        let arity = ARITY as u64;
+        // Check the depth at, and immediately around, every power of the arity.
+        let mut power = arity;
+        let mut depth = 1u8;
+        while let Some(next_power) = power.checked_mul(arity) {
+            assert_eq!(tree_depth::<64, ARITY>(usize::try_from(power - 1)?)?, depth - 1);
+            assert_eq!(tree_depth::<64, ARITY>(usize::try_from(power)?)?, depth);
+            assert_eq!(tree_depth::<64, ARITY>(usize::try_from(power + 1)?)?, depth);
+            power = next_power;
+            depth += 1;
+        }
+        Ok(())
+    }
+
+    check::<2>()?;
+    check::<3>()?;
+    check::<4>()?;
+    check::<5>()?;
+    check::<8>()?;
+    check::<16>()
+}
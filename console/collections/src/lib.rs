@@ -22,3 +22,6 @@ pub use snarkvm_console_types::prelude::*;
 
 pub mod kary_merkle_tree;
 pub mod merkle_tree;
+
+#[cfg(any(test, feature = "prop-tests"))]
+pub mod prop_tests;
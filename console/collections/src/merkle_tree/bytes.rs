@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8> ToBytes
+    for MerkleTree<E, LH, PH, DEPTH>
+{
+    /// Writes the Merkle tree to a buffer.
+    ///
+    /// Only the hashes of the nodes that cover at least one leaf are written, as the remaining
+    /// nodes are derived from the empty hash when the tree is restored with `prepare_from_bytes_le`.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the depth.
+        DEPTH.write_le(&mut writer)?;
+        // Write the number of leaves.
+        u64::try_from(self.number_of_leaves).map_err(error)?.write_le(&mut writer)?;
+        // Write the root.
+        self.root.write_le(&mut writer)?;
+        // Write the node hashes of each level, from the hashed leaves up to the root of the full tree.
+        for level in 0..self.number_of_levels() {
+            for index in 0..num_covered_nodes(self.number_of_leaves, level) {
+                match self.get_node(level, index) {
+                    Some(node) => node.write_le(&mut writer)?,
+                    None => return Err(error(format!("Missing node {index} in level {level} of the Merkle tree"))),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    MerkleTree<E, LH, PH, DEPTH>
+{
+    /// Returns a new Merkle tree restored from the given bytes, as written by `to_bytes_le`,
+    /// using the leaf hasher and path hasher of this tree.
+    ///
+    /// The node hashes are not recomputed, so the bytes must come from a trusted source,
+    /// such as a checkpoint written by this node. The root is checked against the restored tree.
+    pub fn prepare_from_bytes_le(&self, bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid Merkle tree version");
+        // Read the depth.
+        let depth = u8::read_le(&mut reader)?;
+        // Ensure the depth matches.
+        ensure!(depth == DEPTH, "Expected a Merkle tree of depth {DEPTH}, found depth {depth}");
+        // Read the number of leaves.
+        let number_of_leaves = usize::try_from(u64::read_le(&mut reader)?)?;
+        // Read the root.
+        let root = Field::new(FromBytes::read_le(&mut reader)?);
+
+        // Compute the maximum number of leaves.
+        let max_leaves = match number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves,
+            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
+        };
+        // Compute the tree size as the maximum number of leaves plus the number of nodes.
+        let tree_size = match max_leaves.checked_add(max_leaves - 1) {
+            Some(tree_size) => tree_size,
+            None => bail!("Integer overflow when computing the size of the Merkle tree"),
+        };
+        // Compute the number of levels in the Merkle tree, from the hashed leaves up to the root of the full tree.
+        let number_of_levels = tree_depth::<DEPTH>(tree_size)? as usize + 1;
+
+        // Read the node hashes of each level.
+        let levels = (0..number_of_levels)
+            .map(|level| {
+                (0..num_covered_nodes(number_of_leaves, level))
+                    .map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?)))
+                    .collect::<IoResult<Vec<_>>>()
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+        // Ensure there are no remaining bytes.
+        ensure!(reader.is_empty(), "Found trailing bytes after the Merkle tree");
+
+        // Restore the Merkle tree from the levels.
+        let merkle_tree = self.prepare_from_levels(number_of_leaves, &levels)?;
+        // Ensure the restored root matches the given root.
+        ensure!(merkle_tree.root == root, "The restored Merkle tree does not match the given root");
+        Ok(merkle_tree)
+    }
+}
@@ -22,7 +22,9 @@ use rayon::prelude::*;
 pub trait PathHash: Clone + Send + Sync {
     type Hash: FieldTrait;
 
-    /// Returns the empty hash.
+    /// Returns the empty hash, which fills the slots of the tree that do not hold a leaf.
+    /// Note: The empty hash is a node hash, while leaves are hashed with a distinct prefix (see `LeafHash`),
+    /// so an explicit leaf (even an empty one) never hashes to the empty hash.
     fn hash_empty(&self) -> Result<Self::Hash> {
         self.hash_children(&Self::Hash::zero(), &Self::Hash::zero())
     }
@@ -12,9 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
+
 mod helpers;
 pub use helpers::*;
 
+mod multi_path;
+pub use multi_path::*;
+
 mod path;
 pub use path::*;
 
@@ -165,11 +170,15 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             false => None,
         };
 
+        // Initialize a non-empty index to track the end of the leaves, as the remaining leaves are empty.
+        let nonempty_index = middle_index + new_leaves.len();
+
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
         self.compute_updated_tree(
             &mut tree,
             start_index,
             middle_index,
+            nonempty_index,
             start_precompute_index,
             middle_precompute_index,
         )?;
@@ -480,10 +489,12 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         };
 
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
+        // Note: The leaves after the middle index are all empty.
         self.compute_updated_tree(
             &mut tree,
             start_index,
             middle_index,
+            middle_index,
             start_precompute_index,
             middle_precompute_index,
         )?;
@@ -522,6 +533,71 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         Ok(())
     }
 
+    #[inline]
+    /// Returns a new Merkle tree with the given number of leaves, from the given node hashes of each level.
+    ///
+    /// The levels are ordered from the hashed leaves up to the root of the full tree, and each level
+    /// contains the hashes of the nodes that cover at least one leaf, as returned by `get_node`.
+    /// The remaining nodes are derived from the empty hash, so no leaves or covered nodes are rehashed.
+    pub fn prepare_from_levels(&self, number_of_leaves: usize, levels: &[Vec<PH::Hash>]) -> Result<Self> {
+        let timer = timer!("MerkleTree::prepare_from_levels");
+
+        // Compute the maximum number of leaves.
+        let max_leaves = match number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves,
+            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
+        };
+        // Compute the number of nodes.
+        let num_nodes = max_leaves - 1;
+        // Compute the tree size as the maximum number of leaves plus the number of nodes.
+        let tree_size = num_nodes + max_leaves;
+        // Compute the number of levels in the Merkle tree (i.e. log2(tree_size)).
+        let tree_depth = tree_depth::<DEPTH>(tree_size)?;
+        // Compute the number of padded levels.
+        let padding_depth = DEPTH - tree_depth;
+
+        // Ensure the number of levels is correct.
+        ensure!(levels.len() == tree_depth as usize + 1, "Incorrect number of levels in the Merkle tree");
+
+        // Initialize the Merkle tree.
+        let mut tree = vec![self.empty_hash; tree_size];
+        // Initialize the hash of a node that covers no leaves, for the current level.
+        let mut empty_node = self.empty_hash;
+        for (level, hashes) in levels.iter().enumerate() {
+            // Compute the number of nodes in the current level.
+            let width = max_leaves >> level;
+            // Compute the number of nodes in the current level that cover at least one leaf.
+            let num_covered = num_covered_nodes(number_of_leaves, level);
+            // Ensure the number of node hashes is correct.
+            ensure!(hashes.len() == num_covered, "Incorrect number of nodes in level {level} of the Merkle tree");
+            // Store the node hashes, followed by the hashes of the nodes that cover no leaves.
+            tree[width - 1..width - 1 + num_covered].copy_from_slice(hashes);
+            tree[width - 1 + num_covered..2 * width - 1].fill(empty_node);
+            // Update the hash of a node that covers no leaves, for the next level.
+            empty_node = self.path_hasher.hash_children(&empty_node, &empty_node)?;
+        }
+        lap!(timer, "Restored {} levels", levels.len());
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = tree[0];
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hash)?;
+        }
+        lap!(timer, "Hashed {} padding levels", padding_depth);
+
+        finish!(timer);
+
+        Ok(Self {
+            leaf_hasher: self.leaf_hasher.clone(),
+            path_hasher: self.path_hasher.clone(),
+            root: root_hash,
+            tree,
+            empty_hash: self.empty_hash,
+            number_of_leaves,
+        })
+    }
+
     #[inline]
     /// Returns the Merkle path for the given leaf index and leaf.
     pub fn prove(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<MerklePath<E, DEPTH>> {
@@ -570,9 +646,59 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         MerklePath::try_from((U64::new(leaf_index as u64), path))
     }
 
-    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    /// Returns `true` if the given root is the root of this tree, and the given Merkle path is valid
+    /// for the given root and leaf, with a leaf index within the number of leaves in this tree.
     pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
-        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
+        root == self.root()
+            && path.verify_with_num_leaves(&self.leaf_hasher, &self.path_hasher, root, leaf, self.number_of_leaves as u64)
+    }
+
+    /// Returns the Merkle multi-path for the given leaf indices, which must be in strictly increasing order.
+    pub fn prove_many(&self, leaf_indices: &[usize]) -> Result<MerkleMultiPath<E, DEPTH>> {
+        // Ensure the leaf indices are in strictly increasing order.
+        ensure!(leaf_indices.windows(2).all(|pair| pair[0] < pair[1]), "The Merkle leaf indices must be increasing");
+        // Ensure the leaf indices are valid.
+        ensure!(
+            leaf_indices.last().map_or(false, |leaf_index| *leaf_index < self.number_of_leaves),
+            "The given Merkle leaf index is out of bounds"
+        );
+
+        // Initialize a vector for the Merkle multi-path.
+        let mut path = Vec::new();
+
+        // Iterate from the leaf level to the root level, storing the sibling hashes that cannot be computed.
+        let mut positions = leaf_indices.to_vec();
+        for level in 0..DEPTH as usize {
+            let mut parent_positions = Vec::with_capacity(positions.len());
+
+            let mut i = 0;
+            while i < positions.len() {
+                let position = positions[i];
+                match positions.get(i + 1) {
+                    // If the sibling is the next position, then its hash can be computed.
+                    Some(next) if position % 2 == 0 && *next == position + 1 => i += 1,
+                    // Otherwise, append the sibling hash to the path, or the empty hash for the padded levels.
+                    _ => path.push(*self.get_node(level, position ^ 1).unwrap_or(&self.empty_hash)),
+                }
+                i += 1;
+                parent_positions.push(position / 2);
+            }
+
+            // Update the positions for the next level.
+            positions = parent_positions;
+        }
+
+        // Return the Merkle multi-path.
+        let leaf_indices = leaf_indices.iter().map(|leaf_index| U64::new(*leaf_index as u64)).collect();
+        MerkleMultiPath::try_from((leaf_indices, path))
+    }
+
+    /// Returns `true` if the given root is the root of this tree, and the given Merkle multi-path is valid
+    /// for the given root and leaves, with leaf indices within the number of leaves in this tree.
+    pub fn verify_many(&self, path: &MerkleMultiPath<E, DEPTH>, root: &PH::Hash, leaves: &[LH::Leaf]) -> bool {
+        root == self.root()
+            && path.leaf_indices().last().map_or(false, |leaf_index| **leaf_index < self.number_of_leaves as u64)
+            && path.verify(&self.leaf_hasher, &self.path_hasher, root, leaves)
     }
 
     /// Returns the Merkle root of the tree.
@@ -608,28 +734,60 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         self.number_of_leaves
     }
 
+    /// Returns the number of levels in the Merkle tree, from the hashed leaves up to the root of the full tree.
+    pub fn number_of_levels(&self) -> usize {
+        // The tree contains `2 * max_leaves - 1` nodes, across `log2(max_leaves) + 1` levels.
+        ((self.tree.len() + 1) / 2).trailing_zeros() as usize + 1
+    }
+
+    /// Returns the hash of the node at the given index in the given level,
+    /// where level 0 contains the hashed leaves, and the last level contains the root of the full tree.
+    pub fn get_node(&self, level: usize, index: usize) -> Option<&PH::Hash> {
+        // Compute the number of nodes in the given level.
+        let width = ((self.tree.len() + 1) / 2).checked_shr(u32::try_from(level).ok()?)?;
+        match index < width {
+            true => self.tree.get(width - 1 + index),
+            false => None,
+        }
+    }
+
     /// Compute and store the hashes for each level, iterating from the penultimate level to the root level.
     ///
     /// ```ignore
-    ///  start_index      middle_index                              end_index
+    ///  start_index      middle_index         nonempty_index       end_index
     ///  start_precompute_index         middle_precompute_index     end_index
     /// ```
+    ///
+    /// The nodes from `nonempty_index` onwards only cover empty leaves, so their hashes are
+    /// the empty hash of their level, and are filled in without being recomputed.
     #[inline]
     fn compute_updated_tree(
         &self,
         tree: &mut [Field<E>],
         mut start_index: usize,
         mut middle_index: usize,
+        mut nonempty_index: usize,
         mut start_precompute_index: usize,
         mut middle_precompute_index: Option<usize>,
     ) -> Result<()> {
         // Initialize a timer for the while loop.
         let timer = timer!("MerkleTree::compute_updated_tree");
 
+        // Initialize the empty hash of the current level.
+        let mut empty_hash = self.empty_hash;
+
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
         while let (Some(start), Some(middle)) = (parent(start_index), parent(middle_index)) {
             // Compute the end index of the current level.
             let end = left_child(start);
+            // Compute the empty hash of the current level.
+            empty_hash = self.path_hasher.hash_children(&empty_hash, &empty_hash)?;
+            // Compute the end index of the nodes with non-empty children in the current level.
+            let nonempty = match nonempty_index > start_index {
+                true => parent(nonempty_index - 1).map_or(start, |index| index + 1),
+                false => start,
+            }
+            .clamp(middle, end);
 
             // If the current level has precomputed indices, copy them instead of recomputing them.
             if let Some(start_precompute) = parent(start_precompute_index) {
@@ -687,30 +845,39 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
                 }
             } else {
                 // Construct the children for the new indices in the current level.
-                let tuples = (middle..end).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
+                let tuples =
+                    (middle..nonempty).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
                 // Process the indices that need to be computed for the current level.
                 // If any level requires computing more than 100 nodes, borrow the tree for performance.
                 match tuples.len() >= 100 {
                     // Option 1: Borrow the tree to compute and store the hashes for the new indices in the current level.
-                    true => cfg_iter_mut!(tree[middle..end]).zip_eq(cfg_iter!(tuples)).try_for_each(
+                    true => cfg_iter_mut!(tree[middle..nonempty]).zip_eq(cfg_iter!(tuples)).try_for_each(
                         |(node, (left, right))| {
                             *node = self.path_hasher.hash_children(left, right)?;
                             Ok::<_, Error>(())
                         },
                     )?,
                     // Option 2: Compute and store the hashes for the new indices in the current level.
-                    false => tree[middle..end].iter_mut().zip_eq(&tuples).try_for_each(|(node, (left, right))| {
-                        *node = self.path_hasher.hash_children(left, right)?;
-                        Ok::<_, Error>(())
-                    })?,
+                    false => {
+                        tree[middle..nonempty].iter_mut().zip_eq(&tuples).try_for_each(|(node, (left, right))| {
+                            *node = self.path_hasher.hash_children(left, right)?;
+                            Ok::<_, Error>(())
+                        })?
+                    }
                 }
-                lap!(timer, "Compute: {middle} -> {end}");
+                lap!(timer, "Compute: {middle} -> {nonempty}");
+
+                // Fill in the empty hash for the nodes with only empty children in the current level.
+                tree[nonempty..end].fill(empty_hash);
+                lap!(timer, "Empty: {nonempty} -> {end}");
             }
 
             // Update the start index for the next level.
             start_index = start;
             // Update the middle index for the next level.
             middle_index = middle;
+            // Update the non-empty index for the next level.
+            nonempty_index = nonempty;
         }
 
         // End the timer for the while loop.
@@ -734,6 +901,15 @@ fn tree_depth<const DEPTH: u8>(tree_size: usize) -> Result<u8> {
     }
 }
 
+/// Returns the number of nodes in the given level that cover at least one of the given number of leaves.
+#[inline]
+const fn num_covered_nodes(number_of_leaves: usize, level: usize) -> usize {
+    match number_of_leaves {
+        0 => 0,
+        n => ((n - 1) >> level) + 1,
+    }
+}
+
 /// Returns the index of the left child, given an index.
 #[inline]
 const fn left_child(index: usize) -> usize {
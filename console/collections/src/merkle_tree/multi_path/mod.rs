@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle path for multiple leaves against the same root.
+/// Sibling hashes that are shared across the leaves, or that can be computed from the leaves, are only included once.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the path, in strictly increasing order.
+    leaf_indices: Vec<U64<E>>,
+    /// The `siblings` contains the sibling hashes that cannot be computed from the leaves,
+    /// from the leaf level to the root, and from left to right within each level.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(Vec<U64<E>>, Vec<Field<E>>)> for MerkleMultiPath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle multi-path.
+    fn try_from((leaf_indices, siblings): (Vec<U64<E>>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle multi-path is well-formed.
+        Self::check_well_formed(&leaf_indices, siblings.len())?;
+        // Return the Merkle multi-path.
+        Ok(Self { leaf_indices, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns the leaf indices for the path.
+    pub fn leaf_indices(&self) -> &[U64<E>] {
+        &self.leaf_indices
+    }
+
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Ensures the given leaf indices and number of siblings form a well-formed Merkle multi-path.
+    fn check_well_formed(leaf_indices: &[U64<E>], num_siblings: usize) -> Result<()> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure there is at least one leaf index.
+        ensure!(!leaf_indices.is_empty(), "Found a Merkle multi-path without leaf indices");
+        // Ensure the leaf indices are in strictly increasing order.
+        ensure!(
+            leaf_indices.windows(2).all(|pair| *pair[0] < *pair[1]),
+            "Found Merkle leaf indices that are not strictly increasing"
+        );
+        // Ensure the leaf indices are within the tree depth.
+        ensure!(
+            leaf_indices.iter().all(|leaf_index| (**leaf_index as u128) < (1u128 << DEPTH)),
+            "Found an out of bounds Merkle leaf index"
+        );
+        // Ensure the Merkle multi-path does not contain more siblings than the individual Merkle paths.
+        ensure!(num_siblings <= leaf_indices.len() * DEPTH as usize, "Found an incorrect Merkle multi-path length");
+        Ok(())
+    }
+
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    /// The leaves must be given in the same order as the leaf indices.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> bool {
+        // Ensure the Merkle multi-path is well-formed.
+        if let Err(error) = Self::check_well_formed(&self.leaf_indices, self.siblings.len()) {
+            eprintln!("{error}");
+            return false;
+        }
+        // Ensure there is one leaf for each leaf index.
+        if leaves.len() != self.leaf_indices.len() {
+            eprintln!("Found an incorrect number of Merkle leaves");
+            return false;
+        }
+
+        // Initialize a tracker for the current hashes, by computing the leaf hashes to start.
+        let mut current_hashes = match leaf_hasher.hash_leaves(leaves) {
+            Ok(candidate_leaf_hashes) => candidate_leaf_hashes,
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaves during verification: {error}");
+                return false;
+            }
+        };
+        // Initialize a tracker for the positions of the current hashes in their level.
+        let mut positions = self.leaf_indices.iter().map(|leaf_index| **leaf_index).collect::<Vec<_>>();
+        // Initialize an iterator over the sibling hashes.
+        let mut siblings = self.siblings.iter();
+
+        // Check levels between leaf level and root.
+        for _ in 0..DEPTH {
+            let mut parent_hashes = Vec::with_capacity(current_hashes.len());
+            let mut parent_positions = Vec::with_capacity(positions.len());
+
+            let mut i = 0;
+            while i < positions.len() {
+                let (position, current_hash) = (positions[i], current_hashes[i]);
+                // Construct the ordering of the left & right child hash for this position.
+                let (left, right) = match positions.get(i + 1) {
+                    // If the sibling is the next position, then its hash is already known.
+                    Some(next) if position % 2 == 0 && *next == position + 1 => {
+                        i += 1;
+                        (current_hash, current_hashes[i])
+                    }
+                    // Otherwise, the sibling hash is the next one in the path.
+                    _ => {
+                        let sibling_hash = match siblings.next() {
+                            Some(sibling_hash) => *sibling_hash,
+                            None => {
+                                eprintln!("Found an incorrect Merkle multi-path length");
+                                return false;
+                            }
+                        };
+                        match position % 2 == 0 {
+                            true => (current_hash, sibling_hash),
+                            false => (sibling_hash, current_hash),
+                        }
+                    }
+                };
+                i += 1;
+
+                // Compute the parent hash for the next level.
+                match path_hasher.hash_children(&left, &right) {
+                    Ok(hash) => parent_hashes.push(hash),
+                    Err(error) => {
+                        eprintln!("Failed to hash the Merkle multi-path during verification: {error}");
+                        return false;
+                    }
+                }
+                parent_positions.push(position / 2);
+            }
+
+            // Update the current hashes and positions for the next level.
+            current_hashes = parent_hashes;
+            positions = parent_positions;
+        }
+
+        // Ensure every sibling hash was used.
+        if siblings.next().is_some() {
+            eprintln!("Found an incorrect Merkle multi-path length");
+            return false;
+        }
+
+        // Ensure the final hash matches the given root.
+        current_hashes.len() == 1 && current_hashes[0] == *root
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for MerkleMultiPath<E, DEPTH> {
+    /// Reads in a Merkle multi-path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of leaf indices.
+        let num_leaf_indices = u32::read_le(&mut reader)?;
+        // Read the leaf indices.
+        let leaf_indices =
+            (0..num_leaf_indices).map(|_| Ok(U64::new(u64::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        // Read the number of siblings.
+        let num_siblings = u32::read_le(&mut reader)?;
+        // Ensure the number of siblings is within bounds.
+        if num_siblings as usize > leaf_indices.len() * DEPTH as usize {
+            return Err(error("Found an incorrect Merkle multi-path length"));
+        }
+        // Read the Merkle multi-path siblings.
+        let siblings = (0..num_siblings)
+            .map(|_| Ok(Field::new(FromBytes::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle multi-path.
+        Self::try_from((leaf_indices, siblings)).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for MerkleMultiPath<E, DEPTH> {
+    /// Writes the Merkle multi-path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the number of leaf indices.
+        u32::try_from(self.leaf_indices.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the leaf indices.
+        self.leaf_indices.iter().try_for_each(|leaf_index| leaf_index.write_le(&mut writer))?;
+        // Write the number of siblings.
+        u32::try_from(self.siblings.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the Merkle multi-path siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> Serialize for MerkleMultiPath<E, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for MerkleMultiPath<E, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle multi-path")
+    }
+}
@@ -51,6 +51,24 @@ impl<E: Environment, const DEPTH: u8> MerklePath<E, DEPTH> {
         &self.siblings
     }
 
+    /// Returns `true` if the Merkle path is valid for the given root and leaf,
+    /// and its leaf index is within the given number of leaves in the tree.
+    pub fn verify_with_num_leaves<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaf: &LH::Leaf,
+        num_leaves: u64,
+    ) -> bool {
+        // Ensure the leaf index is within the number of leaves.
+        if *self.leaf_index >= num_leaves {
+            eprintln!("Found a Merkle leaf index that exceeds the number of leaves");
+            return false;
+        }
+        self.verify(leaf_hasher, path_hasher, root, leaf)
+    }
+
     /// Returns `true` if the Merkle path is valid for the given root and leaf.
     pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
         &self,
@@ -54,6 +54,11 @@ fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash
     if !additional_leaves.is_empty() {
         // Append additional leaves to the Merkle tree.
         merkle_tree.append(additional_leaves)?;
+        // Ensure the Merkle tree matches the Merkle tree constructed from all of the leaves.
+        let all_leaves = [leaves, additional_leaves].concat();
+        let expected = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &all_leaves)?;
+        assert_eq!(expected.root(), merkle_tree.root());
+        assert_eq!(expected.tree(), merkle_tree.tree());
         // Check each additional leaf in the Merkle tree.
         for (leaf_index, leaf) in additional_leaves.iter().enumerate() {
             // Compute a Merkle proof for the leaf.
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 33;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Write the Merkle tree to bytes, restore it, and check it matches the original tree.
+/// 3. Check that corrupted bytes are rejected.
+/// 4. Append the additional leaf to both trees, and check they still match.
+fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    additional_leaf: &LH::Leaf,
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Write the Merkle tree to bytes.
+    let bytes = merkle_tree.to_bytes_le()?;
+
+    // Restore the Merkle tree from the bytes.
+    let empty_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[])?;
+    let restored_tree = empty_tree.prepare_from_bytes_le(&bytes)?;
+    assert_eq!(merkle_tree.root(), restored_tree.root());
+    assert_eq!(merkle_tree.tree(), restored_tree.tree());
+    assert_eq!(merkle_tree.number_of_leaves(), restored_tree.number_of_leaves());
+    assert_eq!(bytes, restored_tree.to_bytes_le()?);
+
+    // Ensure an incorrect version fails.
+    let mut invalid_bytes = bytes.clone();
+    invalid_bytes[0] = 0;
+    assert!(empty_tree.prepare_from_bytes_le(&invalid_bytes).is_err());
+    // Ensure an incorrect depth fails.
+    let mut invalid_bytes = bytes.clone();
+    invalid_bytes[1] = DEPTH + 1;
+    assert!(empty_tree.prepare_from_bytes_le(&invalid_bytes).is_err());
+    // Ensure an incorrect root fails.
+    let invalid_root = Field::<E>::one().to_bytes_le()?;
+    let mut invalid_bytes = bytes.clone();
+    invalid_bytes[10..10 + invalid_root.len()].copy_from_slice(&invalid_root);
+    assert!(empty_tree.prepare_from_bytes_le(&invalid_bytes).is_err());
+    // Ensure truncated bytes and trailing bytes fail.
+    assert!(empty_tree.prepare_from_bytes_le(&bytes[..bytes.len() - 1]).is_err());
+    assert!(empty_tree.prepare_from_bytes_le(&[bytes.as_slice(), &[0u8]].concat()).is_err());
+
+    // Append the additional leaf to both Merkle trees.
+    let merkle_tree = merkle_tree.prepare_append(&[additional_leaf.clone()])?;
+    let restored_tree = restored_tree.prepare_append(&[additional_leaf.clone()])?;
+    assert_eq!(merkle_tree.root(), restored_tree.root());
+    assert_eq!(merkle_tree.tree(), restored_tree.tree());
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bytes_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in 0..ITERATIONS {
+            check_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>(),
+                &Field::<CurrentEnvironment>::rand(rng).to_bits_le(),
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    run_tests!(&mut rng, [6, 10, 32]);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bytes_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in 0..ITERATIONS {
+            check_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &(0..num_leaves).map(|_| vec![Field::<CurrentEnvironment>::rand(rng)]).collect::<Vec<_>>(),
+                &vec![Field::<CurrentEnvironment>::rand(rng)],
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    run_tests!(&mut rng, [6, 10, 32]);
+    Ok(())
+}
@@ -15,6 +15,10 @@
 use super::*;
 
 mod append;
+mod bytes;
+mod levels;
+mod multi_path;
+mod padding;
 mod remove;
 mod update;
 mod update_many;
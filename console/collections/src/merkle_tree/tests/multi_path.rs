@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Check that a single-leaf Merkle multi-path matches the Merkle path for the leaf.
+/// 3. Check that the Merkle multi-path for random subsets of the leaves is valid, and no larger than the Merkle paths.
+/// 4. Check that the Merkle multi-path fails on an invalid root, invalid leaves, or tampered siblings.
+fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    rng: &mut TestRng,
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    // Ensure a single-leaf Merkle multi-path matches the Merkle path for the leaf.
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        let path = merkle_tree.prove(leaf_index, leaf)?;
+        let multi_path = merkle_tree.prove_many(&[leaf_index])?;
+        assert_eq!(path.siblings(), multi_path.siblings());
+        assert!(merkle_tree.verify_many(&multi_path, merkle_tree.root(), &[leaf.clone()]));
+    }
+
+    for _ in 0..ITERATIONS {
+        // Sample a random subset of the leaf indices.
+        let leaf_indices = (0..leaves.len()).filter(|_| rng.gen()).collect::<Vec<_>>();
+        if leaf_indices.is_empty() {
+            continue;
+        }
+        let subset = leaf_indices.iter().map(|leaf_index| leaves[*leaf_index].clone()).collect::<Vec<_>>();
+
+        // Compute the Merkle multi-path for the subset.
+        let multi_path = merkle_tree.prove_many(&leaf_indices)?;
+        assert!(multi_path.siblings().len() <= leaf_indices.len() * DEPTH as usize);
+        // Verify the Merkle multi-path succeeds.
+        assert!(merkle_tree.verify_many(&multi_path, merkle_tree.root(), &subset));
+        // Verify the Merkle multi-path round-trips through bytes.
+        assert_eq!(multi_path, MerkleMultiPath::read_le(&multi_path.to_bytes_le()?[..])?);
+
+        // Verify the Merkle multi-path **fails** on an invalid root.
+        assert!(!merkle_tree.verify_many(&multi_path, &PH::Hash::zero(), &subset));
+        assert!(!merkle_tree.verify_many(&multi_path, &PH::Hash::rand(rng), &subset));
+        // Verify the Merkle multi-path **fails** on reordered or missing leaves.
+        if subset.len() > 1 {
+            let mut reordered = subset.clone();
+            reordered.swap(0, 1);
+            assert!(!merkle_tree.verify_many(&multi_path, merkle_tree.root(), &reordered));
+        }
+        assert!(!merkle_tree.verify_many(&multi_path, merkle_tree.root(), &subset[1..]));
+        // Verify the Merkle multi-path **fails** on tampered siblings.
+        let mut siblings = multi_path.siblings().to_vec();
+        siblings[0] = PH::Hash::rand(rng);
+        let tampered = MerkleMultiPath::try_from((multi_path.leaf_indices().to_vec(), siblings))?;
+        assert!(!merkle_tree.verify_many(&tampered, merkle_tree.root(), &subset));
+    }
+
+    // Ensure unsorted, duplicate, and out of bounds leaf indices are rejected.
+    if leaves.len() > 1 {
+        assert!(merkle_tree.prove_many(&[1, 0]).is_err());
+        assert!(merkle_tree.prove_many(&[1, 1]).is_err());
+    }
+    assert!(merkle_tree.prove_many(&[]).is_err());
+    assert!(merkle_tree.prove_many(&[leaves.len()]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_multi_path() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+    for num_leaves in [1, 2, 5, 16, 33] {
+        let leaves =
+            (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le()).collect::<Vec<_>>();
+        check_merkle_tree::<CurrentEnvironment, LH, PH, 8>(&leaf_hasher, &path_hasher, &leaves, &mut rng)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_multi_path() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+    for num_leaves in [1, 2, 5, 16, 33] {
+        let leaves = (0..num_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+        check_merkle_tree::<CurrentEnvironment, LH, PH, 8>(&leaf_hasher, &path_hasher, &leaves, &mut rng)?;
+    }
+    Ok(())
+}
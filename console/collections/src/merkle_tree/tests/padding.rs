@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+/// Runs the following test:
+/// 1. Check that an empty leaf does not hash to the empty hash.
+/// 2. Check that appending an empty leaf changes the root, compared to leaving the slot empty.
+/// 3. Check that appending a duplicate leaf changes the root.
+/// 4. Check that a path is only accepted by a tree that contains its leaf index.
+fn check_merkle_tree<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaf: &LH::Leaf,
+    empty_leaf: &LH::Leaf,
+) -> Result<()> {
+    // Ensure the empty leaf is domain-separated from the empty hash.
+    assert_ne!(leaf_hasher.hash_leaf(empty_leaf)?, path_hasher.hash_empty()?);
+
+    // Construct the Merkle trees.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[leaf.clone()])?;
+    let with_empty_leaf =
+        MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[leaf.clone(), empty_leaf.clone()])?;
+    let with_duplicate_leaf =
+        MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, &[leaf.clone(), leaf.clone()])?;
+
+    // Ensure the roots are distinct.
+    assert_ne!(merkle_tree.root(), with_empty_leaf.root());
+    assert_ne!(merkle_tree.root(), with_duplicate_leaf.root());
+    assert_ne!(with_empty_leaf.root(), with_duplicate_leaf.root());
+
+    // Ensure a path is only accepted by a tree that contains its leaf index.
+    let path = with_duplicate_leaf.prove(1, leaf)?;
+    assert!(with_duplicate_leaf.verify(&path, with_duplicate_leaf.root(), leaf));
+    // Ensure the path and root are valid on their own, so only the leaf index bound rejects them.
+    assert!(path.verify(leaf_hasher, path_hasher, with_duplicate_leaf.root(), leaf));
+    assert!(*path.leaf_index() >= merkle_tree.number_of_leaves() as u64);
+    assert!(!path.verify_with_num_leaves(
+        leaf_hasher,
+        path_hasher,
+        with_duplicate_leaf.root(),
+        leaf,
+        merkle_tree.number_of_leaves() as u64
+    ));
+    // Ensure a tree only accepts paths to its own root.
+    assert!(!merkle_tree.verify(&path, with_duplicate_leaf.root(), leaf));
+    // Ensure the tree still accepts the path of its own leaf, against its own root.
+    let path = merkle_tree.prove(0, leaf)?;
+    assert!(merkle_tree.verify(&path, merkle_tree.root(), leaf));
+    // Ensure the empty slot cannot be proven.
+    assert!(merkle_tree.prove(1, empty_leaf).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_merkle_tree_bhp_padding() -> Result<()> {
+    type LH = BHP1024<CurrentEnvironment>;
+    type PH = BHP512<CurrentEnvironment>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+    let leaf = Field::<CurrentEnvironment>::rand(&mut rng).to_bits_le();
+    check_merkle_tree::<CurrentEnvironment, LH, PH, 8>(&leaf_hasher, &path_hasher, &leaf, &Vec::new())
+}
+
+#[test]
+fn test_merkle_tree_poseidon_padding() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+    let leaf = vec![Uniform::rand(&mut rng)];
+    check_merkle_tree::<CurrentEnvironment, LH, PH, 8>(&leaf_hasher, &path_hasher, &leaf, &Vec::new())
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::merkle_tree::MerklePath;
+use snarkvm_console_types::{prelude::*, prop_tests::any_field};
+
+use proptest::{
+    collection::vec,
+    prelude::{BoxedStrategy, Strategy},
+};
+
+/// Returns a strategy for Merkle paths of the given depth, with a random leaf index and random siblings.
+///
+/// The paths are well-formed, but do not correspond to any tree, which makes them suitable for
+/// fuzzing deserializers and checking that verification rejects them. The leaf index shrinks toward zero.
+pub fn any_merkle_path<E: Environment, const DEPTH: u8>() -> BoxedStrategy<MerklePath<E, DEPTH>> {
+    // Compute the maximum leaf index.
+    let max_leaf_index = if DEPTH >= 64 { u64::MAX } else { (1u64 << DEPTH) - 1 };
+    (0..=max_leaf_index, vec(any_field::<E>(), DEPTH as usize))
+        .prop_map(|(leaf_index, siblings)| {
+            MerklePath::try_from((U64::new(leaf_index), siblings)).expect("Failed to construct a Merkle path")
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::prelude::Console;
+
+    use proptest::prelude::*;
+
+    type CurrentEnvironment = Console;
+
+    proptest! {
+        #[test]
+        fn test_merkle_path_bytes_roundtrip(path in any_merkle_path::<CurrentEnvironment, 32>()) {
+            let bytes = path.to_bytes_le().unwrap();
+            prop_assert_eq!(&path, &MerklePath::read_le(&bytes[..]).unwrap());
+        }
+    }
+}
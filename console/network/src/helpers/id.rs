@@ -210,3 +210,51 @@ impl<F: FieldTrait, const PREFIX: u16> Distribution<AleoID<F, PREFIX>> for Stand
         AleoID::<F, PREFIX>(Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Testnet3;
+    use snarkvm_console_types::Field;
+
+    type CurrentID = AleoID<Field<Testnet3>, { hrp2!("ab") }>;
+    type OtherID = AleoID<Field<Testnet3>, { hrp2!("at") }>;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_string_roundtrip() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let expected: CurrentID = Uniform::rand(rng);
+            let candidate = expected.to_string();
+            assert!(candidate.starts_with("ab1"));
+            assert_eq!(3 + CurrentID::number_of_data_characters() + 6, candidate.len());
+            assert_eq!(expected, CurrentID::from_str(&candidate)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_string_rejects_invalid_encodings() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let id: CurrentID = Uniform::rand(rng);
+            let string = id.to_string();
+
+            // Ensure a different prefix is rejected.
+            assert!(OtherID::from_str(&string).is_err());
+
+            // Ensure a corrupted checksum is rejected.
+            let mut corrupted = string.clone().into_bytes();
+            let last = corrupted.len() - 1;
+            corrupted[last] = if corrupted[last] == b'q' { b'p' } else { b'q' };
+            assert!(CurrentID::from_str(&String::from_utf8(corrupted).unwrap()).is_err());
+
+            // Ensure a truncated string is rejected.
+            assert!(CurrentID::from_str(&string[..string.len() - 1]).is_err());
+        }
+    }
+}
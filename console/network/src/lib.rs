@@ -28,6 +28,9 @@ pub use helpers::*;
 mod testnet3;
 pub use testnet3::*;
 
+#[cfg(feature = "test_vectors")]
+pub mod test_vectors;
+
 pub mod prelude {
     pub use crate::{environment::prelude::*, Network};
 }
@@ -113,6 +116,15 @@ pub trait Network:
     const MAX_SOLUTIONS: usize = 1 << 8; // 256 solutions
     /// The number of blocks per epoch.
     const NUM_BLOCKS_PER_EPOCH: u32 = 3600 / Self::BLOCK_TIME as u32; // 360 blocks == ~1 hour
+    /// The maximum number of blocks a global state root may lag behind the latest block, for it to be used in a transaction.
+    const MAX_STATE_ROOT_AGE: u32 = 24 * Self::NUM_BLOCKS_PER_EPOCH; // 8,640 blocks == ~1 day
+    /// The block height from which a global state root must be at most `MAX_STATE_ROOT_AGE` blocks old.
+    /// Before this height, any global state root in the ledger may be used in a transaction.
+    /// Note: This is set by each network, as it depends on the blocks the network has already produced.
+    const STATE_ROOT_AGE_ACTIVATION_HEIGHT: u32;
+    /// The block height from which a block timestamp must not be before the median time past of the previous blocks.
+    /// Note: This is set by each network, as it depends on the blocks the network has already produced.
+    const MEDIAN_TIME_PAST_ACTIVATION_HEIGHT: u32;
 
     /// The maximum number of entries in data.
     const MAX_DATA_ENTRIES: usize = 32;
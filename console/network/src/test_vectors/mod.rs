@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Known-answer test vectors for the Merkle trees of a network.
+//!
+//! The vectors are sampled deterministically from a seed, and are returned as plain data
+//! (which is also `Serialize`), so alternative implementations can check their outputs against them.
+//! The hash and commitment vectors are in `snarkvm_console_algorithms::test_vectors`, as the network
+//! instances are the algorithms set up with the network domains.
+//! Leaves are encoded as hex bytes, and are hashed as their little-endian bits.
+//! Roots are encoded in their string form (e.g. `1field`).
+
+use crate::{environment::prelude::*, Network};
+
+use snarkvm_console_algorithms::test_vectors::to_hex;
+
+/// The depth of the Merkle trees in the Merkle root vectors.
+pub const MERKLE_TREE_DEPTH: u8 = 8;
+
+/// A known-answer vector for the root of a BHP Merkle tree of depth `MERKLE_TREE_DEPTH`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MerkleRootVector {
+    /// The leaves, as hex strings.
+    pub leaves: Vec<String>,
+    /// The root, as a field element.
+    pub root: String,
+}
+
+/// Returns `num_vectors` Merkle root vectors, sampled from the given seed.
+pub fn merkle_root_vectors<N: Network>(seed: u64, num_vectors: usize) -> Result<Vec<MerkleRootVector>> {
+    let rng = &mut TestRng::from_seed(seed);
+
+    let mut vectors = Vec::with_capacity(num_vectors);
+    for _ in 0..num_vectors {
+        // Sample up to 16 leaves of 32 bytes each.
+        let leaves = (0..rng.gen_range(1..=16)).map(|_| (0..32).map(|_| rng.gen()).collect()).collect::<Vec<Vec<u8>>>();
+        let tree =
+            N::merkle_tree_bhp::<MERKLE_TREE_DEPTH>(&leaves.iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>())?;
+        vectors.push(MerkleRootVector {
+            leaves: leaves.iter().map(|leaf| to_hex(leaf)).collect(),
+            root: tree.root().to_string(),
+        });
+    }
+    Ok(vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Testnet3;
+    use snarkvm_console_algorithms::test_vectors::hash_vectors;
+    use snarkvm_console_types::Field;
+
+    type CurrentNetwork = Testnet3;
+
+    const SEED: u64 = 1234;
+
+    #[test]
+    fn test_vectors_are_deterministic() -> Result<()> {
+        assert_eq!(merkle_root_vectors::<CurrentNetwork>(SEED, 2)?, merkle_root_vectors::<CurrentNetwork>(SEED, 2)?);
+        // Ensure a different seed produces different vectors.
+        assert_ne!(
+            merkle_root_vectors::<CurrentNetwork>(SEED, 2)?,
+            merkle_root_vectors::<CurrentNetwork>(SEED + 1, 2)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_algorithm_vectors_match_network() -> Result<()> {
+        // Ensure the network hash functions match the algorithm vectors.
+        for vector in hash_vectors::<CurrentNetwork>(SEED, 2)? {
+            match vector.function.as_str() {
+                "psd2" => {
+                    let input = vector.input.iter().map(|field| Field::from_str(field)).collect::<Result<Vec<_>>>()?;
+                    assert_eq!(CurrentNetwork::hash_psd2(&input)?.to_string(), vector.output);
+                }
+                "bhp256" => {
+                    let hex = &vector.input[0];
+                    let input = (0..hex.len())
+                        .step_by(2)
+                        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    assert_eq!(CurrentNetwork::hash_bhp256(&input.to_bits_le())?.to_string(), vector.output);
+                }
+                _ => continue,
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_golden_vectors() {
+        let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("src/test_vectors/resources/merkle_root_vectors.json");
+        let vectors = merkle_root_vectors::<CurrentNetwork>(SEED, 2).unwrap();
+        // Run with `UPDATE_EXPECT=1` to regenerate the file.
+        expect_test::expect_file![path].assert_eq(&serde_json::to_string_pretty(&vectors).unwrap());
+    }
+}
@@ -31,6 +31,10 @@ use snarkvm_console_algorithms::{
     BHP512,
     BHP768,
 };
+use snarkvm_parameters::{
+    testnet3::{InclusionProver, InclusionVerifier, INCLUSION_PROVING_KEY, INCLUSION_VERIFYING_KEY},
+    version::{check_circuit_id, check_circuit_key_version, check_metadata},
+};
 
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
@@ -134,11 +138,19 @@ impl Network for Testnet3 {
     /// The network edition.
     const EDITION: u16 = 0;
     /// The network ID.
-    const ID: u16 = 3;
+    const ID: u16 = snarkvm_parameters::testnet3::TESTNET3_NETWORK_ID;
     /// The function name for the inclusion circuit.
     const INCLUSION_FUNCTION_NAME: &'static str = snarkvm_parameters::testnet3::TESTNET3_INCLUSION_FUNCTION_NAME;
     /// The network name.
     const NAME: &'static str = "Aleo Testnet 3";
+    /// The block height from which a global state root must be at most `MAX_STATE_ROOT_AGE` blocks old.
+    /// Note: The blocks of Testnet 3 were produced without this limit, so the limit is scheduled as an upgrade,
+    /// at a height above the current chain, so that nodes on earlier releases agree on every block before it.
+    const STATE_ROOT_AGE_ACTIVATION_HEIGHT: u32 = 1_000_000;
+    /// The block height from which a block timestamp must not be before the median time past of the previous blocks.
+    /// Note: As with the state root age limit, the rule is scheduled as an upgrade above the current chain,
+    /// as the earlier blocks of Testnet 3 were produced without it.
+    const MEDIAN_TIME_PAST_ACTIVATION_HEIGHT: u32 = 1_000_000;
 
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8] {
@@ -163,11 +175,17 @@ impl Network for Testnet3 {
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
         static INSTANCE: OnceCell<Arc<VarunaProvingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
+            let (metadata, bytes) = (InclusionProver::METADATA, &**INCLUSION_PROVING_KEY);
+            // Ensure the parameters are compatible with this version of snarkVM and the network.
+            check_metadata(Self::INCLUSION_FUNCTION_NAME, metadata, Self::ID)
+                .and_then(|_| check_circuit_key_version(Self::INCLUSION_FUNCTION_NAME, bytes))
+                .unwrap_or_else(|error| panic!("Failed to load inclusion proving key - {error}"));
             // Skipping the first byte, which is the encoded version.
-            Arc::new(
-                CircuitProvingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_PROVING_KEY[1..])
-                    .expect("Failed to load inclusion proving key."),
-            )
+            let key = CircuitProvingKey::from_bytes_le(&bytes[1..]).expect("Failed to load inclusion proving key.");
+            // Ensure the key is for the circuit in the parameter metadata.
+            check_circuit_id(Self::INCLUSION_FUNCTION_NAME, metadata, &key)
+                .unwrap_or_else(|error| panic!("Failed to load inclusion proving key - {error}"));
+            Arc::new(key)
         })
     }
 
@@ -175,11 +193,17 @@ impl Network for Testnet3 {
     fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
         static INSTANCE: OnceCell<Arc<VarunaVerifyingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
+            let (metadata, bytes) = (InclusionVerifier::METADATA, &**INCLUSION_VERIFYING_KEY);
+            // Ensure the parameters are compatible with this version of snarkVM and the network.
+            check_metadata(Self::INCLUSION_FUNCTION_NAME, metadata, Self::ID)
+                .and_then(|_| check_circuit_key_version(Self::INCLUSION_FUNCTION_NAME, bytes))
+                .unwrap_or_else(|error| panic!("Failed to load inclusion verifying key - {error}"));
             // Skipping the first byte, which is the encoded version.
-            Arc::new(
-                CircuitVerifyingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_VERIFYING_KEY[1..])
-                    .expect("Failed to load inclusion verifying key."),
-            )
+            let key = CircuitVerifyingKey::from_bytes_le(&bytes[1..]).expect("Failed to load inclusion verifying key.");
+            // Ensure the key is for the circuit in the parameter metadata.
+            check_circuit_id(Self::INCLUSION_FUNCTION_NAME, metadata, &key)
+                .unwrap_or_else(|error| panic!("Failed to load inclusion verifying key - {error}"));
+            Arc::new(key)
         })
     }
 
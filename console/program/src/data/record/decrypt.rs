@@ -18,9 +18,12 @@ impl<N: Network> Record<N, Ciphertext<N>> {
     /// Decrypts `self` into plaintext using the given view key and checks that the owner matches the view key.
     pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
         // Compute the record view key.
-        let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+        let mut record_view_key = (self.nonce * **view_key).to_x_coordinate();
         // Decrypt the record.
-        let record = self.decrypt_symmetric_unchecked(&record_view_key)?;
+        let record = self.decrypt_symmetric_unchecked(&record_view_key);
+        // Erase the record view key.
+        record_view_key.zeroize();
+        let record = record?;
         // Ensure the record owner matches the view key.
         match view_key.to_address() == **record.owner() {
             true => Ok(record),
@@ -28,6 +31,37 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         }
     }
 
+    /// Decrypts each of the given records into plaintext using the given view key,
+    /// and checks that each record owner matches the view key.
+    /// This is equivalent to calling `Self::decrypt` on each record, but computes all of the
+    /// record view keys with a single batch inversion.
+    pub fn decrypt_batch(records: &[Self], view_key: &ViewKey<N>) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        // Compute the record view keys.
+        let shared_secrets = records.iter().map(|record| record.nonce * **view_key).collect::<Vec<_>>();
+        let mut record_view_keys = Group::to_x_coordinates(&shared_secrets);
+        // Compute the address of the view key.
+        let address = view_key.to_address();
+        // Decrypt the records.
+        let records = records
+            .iter()
+            .zip_eq(&record_view_keys)
+            .map(|(record, record_view_key)| {
+                // Decrypt the record.
+                let record = record.decrypt_symmetric_unchecked(record_view_key)?;
+                // Ensure the record owner matches the view key.
+                match address == **record.owner() {
+                    true => Ok(record),
+                    false => bail!(
+                        "Illegal operation: Record::decrypt_batch() view key does not correspond to the record owner."
+                    ),
+                }
+            })
+            .collect();
+        // Erase the record view keys.
+        record_view_keys.zeroize();
+        records
+    }
+
     /// Decrypts `self` into plaintext using the given record view key.
     /// Note: This method does not check that the record view key corresponds to the record owner.
     /// Use `Self::decrypt` for the checked variant.
@@ -35,9 +69,12 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         // Determine the number of randomizers needed to encrypt the record.
         let num_randomizers = self.num_randomizers()?;
         // Prepare a randomizer for each field element.
-        let randomizers = N::hash_many_psd8(&[N::encryption_domain(), *record_view_key], num_randomizers);
+        let mut randomizers = N::hash_many_psd8(&[N::encryption_domain(), *record_view_key], num_randomizers);
         // Decrypt the record.
-        self.decrypt_with_randomizers(&randomizers)
+        let record = self.decrypt_with_randomizers(&randomizers);
+        // Erase the randomizers.
+        randomizers.zeroize();
+        record
     }
 
     /// Decrypts `self` into plaintext using the given randomizers.
@@ -70,13 +107,17 @@ impl<N: Network> Record<N, Ciphertext<N>> {
                 // Public entries do not need to be decrypted.
                 Entry::Public(plaintext) => Entry::Public(plaintext.clone()),
                 // Private entries are decrypted with the given randomizers.
-                Entry::Private(private) => Entry::Private(Plaintext::from_fields(
-                    &private
+                Entry::Private(private) => {
+                    let mut fields = private
                         .iter()
                         .zip_eq(randomizers)
                         .map(|(ciphertext, randomizer)| *ciphertext - randomizer)
-                        .collect::<Vec<_>>(),
-                )?),
+                        .collect::<Vec<_>>();
+                    let plaintext = Plaintext::from_fields(&fields);
+                    // Erase the intermediate plaintext field elements.
+                    fields.zeroize();
+                    Entry::Private(plaintext?)
+                }
             };
             // Insert the decrypted entry.
             if decrypted_data.insert(*id, entry).is_some() {
@@ -156,4 +197,53 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_encrypt_and_decrypt_batch() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a view key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Prepare the records, alternating between public and private owners.
+        let mut records = Vec::new();
+        let mut randomizers = Vec::new();
+        for i in 0..10 {
+            let owner = match i % 2 == 0 {
+                true => Owner::Public(address),
+                false => Owner::Private(Plaintext::from(Literal::Address(address))),
+            };
+            let randomizer = Scalar::rand(&mut rng);
+            records.push(Record {
+                owner,
+                data: IndexMap::from_iter(vec![(
+                    Identifier::from_str("a")?,
+                    Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                )]),
+                nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+            });
+            randomizers.push(randomizer);
+        }
+
+        // Ensure the batch encryption matches the individual encryptions.
+        let ciphertexts = Record::encrypt_batch(&records, &randomizers)?;
+        for ((record, randomizer), ciphertext) in records.iter().zip(&randomizers).zip(&ciphertexts) {
+            assert_eq!(record.encrypt(*randomizer)?, *ciphertext);
+        }
+        // Ensure the batch decryption recovers the records.
+        assert_eq!(records, Record::decrypt_batch(&ciphertexts, &view_key)?);
+
+        // Ensure mismatched randomizers are rejected.
+        assert!(Record::encrypt_batch(&records, &randomizers[1..]).is_err());
+        randomizers.swap(0, 1);
+        assert!(Record::encrypt_batch(&records, &randomizers).is_err());
+
+        // Ensure that decrypting with an incorrect view key fails.
+        let incorrect_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        assert!(Record::decrypt_batch(&ciphertexts, &incorrect_view_key).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static RECORD_DISCLOSURE_DOMAIN: &str = "AleoRecordDisclosure0";
+
+/// A disclosure of a record ciphertext, which allows a third party to decrypt the record,
+/// and to verify the decryption was performed for the record owner, without the owner's view key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecordDisclosure<N: Network> {
+    /// The shared point `view_key * nonce`, whose x-coordinate is the record view key.
+    shared_point: Group<N>,
+    /// The challenge of the proof that `log_G(address) == log_nonce(shared_point)`.
+    challenge: Scalar<N>,
+    /// The response of the proof that `log_G(address) == log_nonce(shared_point)`.
+    response: Scalar<N>,
+}
+
+impl<N: Network> RecordDisclosure<N> {
+    /// Returns the shared point.
+    pub const fn shared_point(&self) -> Group<N> {
+        self.shared_point
+    }
+
+    /// Returns the challenge.
+    pub const fn challenge(&self) -> Scalar<N> {
+        self.challenge
+    }
+
+    /// Returns the response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+
+    /// Returns the record view key.
+    pub fn record_view_key(&self) -> Field<N> {
+        self.shared_point.to_x_coordinate()
+    }
+
+    /// Returns the challenge for the given proof commitments, as:
+    ///     challenge := HashToScalar(domain, r * G, r * nonce, address, nonce, shared_point)
+    fn compute_challenge(
+        g_r: Group<N>,
+        nonce_r: Group<N>,
+        address: &Address<N>,
+        nonce: Group<N>,
+        shared_point: Group<N>,
+    ) -> Result<Scalar<N>> {
+        // Construct the domain separator.
+        let domain = Field::new_domain_separator(RECORD_DISCLOSURE_DOMAIN);
+        // Construct the hash input.
+        let mut preimage = Vec::with_capacity(6);
+        preimage.push(domain);
+        preimage.extend([g_r, nonce_r, **address, nonce, shared_point].map(|point| point.to_x_coordinate()));
+        // Compute the challenge.
+        N::hash_to_scalar_psd8(&preimage)
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns a disclosure of `self` for the given view key of the record owner, where:
+    ///     challenge := HashToScalar(domain, r * G, r * nonce, address, nonce, view_key * nonce)
+    ///     response := r - challenge * view_key
+    pub fn disclose<R: Rng + CryptoRng>(&self, view_key: &ViewKey<N>, rng: &mut R) -> Result<RecordDisclosure<N>> {
+        // Ensure the view key corresponds to the record owner.
+        self.decrypt(view_key)?;
+
+        // Compute the shared point.
+        let shared_point = self.nonce * **view_key;
+        // Compute the address.
+        let address = view_key.to_address();
+
+        // Sample a random nonce from the scalar field.
+        let r = Scalar::rand(rng);
+        // Compute `g_r` as `r * G`.
+        let g_r = N::g_scalar_multiply(&r);
+        // Compute `nonce_r` as `r * nonce`.
+        let nonce_r = self.nonce * r;
+
+        // Compute the challenge.
+        let challenge = RecordDisclosure::compute_challenge(g_r, nonce_r, &address, self.nonce, shared_point)?;
+        // Compute the response.
+        let response = r - (challenge * **view_key);
+
+        // Return the disclosure.
+        Ok(RecordDisclosure { shared_point, challenge, response })
+    }
+
+    /// Decrypts `self` into plaintext using the given disclosure, and checks that the disclosure
+    /// corresponds to the given address, and that the record owner matches the given address.
+    pub fn decrypt_with_disclosure(
+        &self,
+        address: &Address<N>,
+        disclosure: &RecordDisclosure<N>,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Retrieve the challenge and response.
+        let (challenge, response) = (disclosure.challenge, disclosure.response);
+
+        // Compute `g_r` as `(response * G) + (challenge * address)`.
+        let g_r = N::g_scalar_multiply(&response) + (**address * challenge);
+        // Compute `nonce_r` as `(response * nonce) + (challenge * shared_point)`.
+        let nonce_r = (self.nonce * response) + (disclosure.shared_point * challenge);
+
+        // Ensure the shared point was computed from the view key of the given address.
+        let candidate =
+            RecordDisclosure::compute_challenge(g_r, nonce_r, address, self.nonce, disclosure.shared_point)?;
+        ensure!(challenge == candidate, "Illegal operation: the record disclosure is invalid for the given address.");
+
+        // Decrypt the record.
+        let record = self.decrypt_symmetric_unchecked(&disclosure.record_view_key())?;
+        // Ensure the record owner matches the address.
+        match *address == **record.owner() {
+            true => Ok(record),
+            false => bail!("Illegal operation: the record owner does not correspond to the given address."),
+        }
+    }
+}
+
+impl<N: Network> FromBytes for RecordDisclosure<N> {
+    /// Reads the record disclosure from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let shared_point = Group::read_le(&mut reader)?;
+        let challenge = Scalar::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { shared_point, challenge, response })
+    }
+}
+
+impl<N: Network> ToBytes for RecordDisclosure<N> {
+    /// Writes the record disclosure to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.shared_point.write_le(&mut writer)?;
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    fn sample_ciphertext(
+        address: Address<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Result<Record<CurrentNetwork, Ciphertext<CurrentNetwork>>> {
+        // Prepare the record.
+        let randomizer = Scalar::rand(rng);
+        let record = Record {
+            owner: Owner::Private(Plaintext::from(Literal::Address(address))),
+            data: IndexMap::from_iter(vec![(
+                Identifier::from_str("a")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+            )]),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        // Encrypt the record.
+        record.encrypt(randomizer)
+    }
+
+    #[test]
+    fn test_disclose() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Sample a record.
+            let ciphertext = sample_ciphertext(address, &mut rng)?;
+            let record = ciphertext.decrypt(&view_key)?;
+
+            // Disclose the record.
+            let disclosure = ciphertext.disclose(&view_key, &mut rng)?;
+            assert_eq!(record, ciphertext.decrypt_with_disclosure(&address, &disclosure)?);
+
+            // Check the byte representation.
+            let disclosure_bytes = disclosure.to_bytes_le()?;
+            assert_eq!(disclosure, RecordDisclosure::read_le(&disclosure_bytes[..])?);
+
+            // Ensure the disclosure fails for an incorrect address.
+            let incorrect_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            assert!(ciphertext.decrypt_with_disclosure(&incorrect_address, &disclosure).is_err());
+
+            // Ensure the disclosure fails for a tampered shared point.
+            let tampered = RecordDisclosure { shared_point: Group::rand(&mut rng), ..disclosure };
+            assert!(ciphertext.decrypt_with_disclosure(&address, &tampered).is_err());
+
+            // Ensure the disclosure fails for a different record.
+            let other_ciphertext = sample_ciphertext(address, &mut rng)?;
+            assert!(other_ciphertext.decrypt_with_disclosure(&address, &disclosure).is_err());
+
+            // Ensure a disclosure cannot be created with an incorrect view key.
+            let incorrect_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            assert!(ciphertext.disclose(&incorrect_view_key, &mut rng).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_types::U64;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns a new dummy `credits.aleo` record for the given owner, with zero microcredits.
+    ///
+    /// The record has the same layout as a `credits.aleo` record, with a private owner and a private
+    /// `microcredits` entry, and its nonce is derived from the given randomizer. When encrypted with
+    /// this randomizer, the dummy record is indistinguishable from a real `credits.aleo` record.
+    /// Note: The record commitment binds the program ID, so the dummy must be committed under `credits.aleo`.
+    pub fn new_dummy(owner: Address<N>, randomizer: Scalar<N>) -> Result<Self> {
+        Self::from_plaintext(
+            Owner::Private(Plaintext::from(Literal::Address(owner))),
+            IndexMap::from_iter([(
+                Identifier::from_str("microcredits")?,
+                Entry::Private(Plaintext::from(Literal::U64(U64::new(0)))),
+            )]),
+            N::g_scalar_multiply(&randomizer),
+        )
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns a new encrypted dummy `credits.aleo` record for the given owner, with zero microcredits.
+    pub fn new_dummy<R: Rng + CryptoRng>(owner: Address<N>, rng: &mut R) -> Result<Self> {
+        // Sample a random randomizer for the nonce.
+        let randomizer = Scalar::rand(rng);
+        // Encrypt the dummy record.
+        Record::<N, Plaintext<N>>::new_dummy(owner, randomizer)?.encrypt(randomizer)
+    }
+
+    /// Returns the given number of encrypted dummy `credits.aleo` records for the given owner.
+    pub fn new_dummies<R: Rng + CryptoRng>(owner: Address<N>, num_records: usize, rng: &mut R) -> Result<Vec<Self>> {
+        (0..num_records).map(|_| Self::new_dummy(owner, rng)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_new_dummy() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a view key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Prepare a real `credits.aleo` record.
+        let randomizer = Scalar::rand(&mut rng);
+        let real = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, microcredits: 1500000u64.private, _nonce: {}.public }}",
+            CurrentNetwork::g_scalar_multiply(&randomizer)
+        ))?
+        .encrypt(randomizer)?;
+
+        // Sample the dummy records.
+        let dummies = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::new_dummies(address, ITERATIONS, &mut rng)?;
+        assert_eq!(dummies.len(), ITERATIONS);
+
+        for (i, dummy) in dummies.iter().enumerate() {
+            // Ensure the dummy record has the same layout as a real record.
+            assert_eq!(dummy.to_bytes_le()?.len(), real.to_bytes_le()?.len());
+            // Ensure the dummy record belongs to the owner.
+            assert!(dummy.is_owner(&view_key));
+
+            // Ensure the dummy record decrypts to zero microcredits.
+            let record = dummy.decrypt(&view_key)?;
+            assert_eq!(**record.owner(), address);
+            match record.data().get(&Identifier::from_str("microcredits")?) {
+                Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => assert!(amount.is_zero()),
+                _ => bail!("Expected a private 'microcredits' entry"),
+            }
+
+            // Ensure each dummy record has a unique nonce.
+            assert!(dummies[..i].iter().all(|other| other.nonce() != dummy.nonce()));
+        }
+        Ok(())
+    }
+}
@@ -20,14 +20,49 @@ impl<N: Network> Record<N, Plaintext<N>> {
         // Ensure the randomizer corresponds to the record nonce.
         if self.nonce == N::g_scalar_multiply(&randomizer) {
             // Compute the record view key.
-            let record_view_key = (**self.owner * randomizer).to_x_coordinate();
+            let mut record_view_key = (**self.owner * randomizer).to_x_coordinate();
             // Encrypt the record.
-            self.encrypt_symmetric_unchecked(&record_view_key)
+            let record = self.encrypt_symmetric_unchecked(&record_view_key);
+            // Erase the record view key.
+            record_view_key.zeroize();
+            record
         } else {
             bail!("Illegal operation: Record::encrypt() randomizer does not correspond to the record nonce.")
         }
     }
 
+    /// Encrypts each of the given records for its owner, under the corresponding randomizer.
+    /// This is equivalent to calling `Self::encrypt` on each record, but computes all of the
+    /// record view keys with a single batch inversion.
+    pub fn encrypt_batch(records: &[Self], randomizers: &[Scalar<N>]) -> Result<Vec<Record<N, Ciphertext<N>>>> {
+        // Ensure there is one randomizer for each record.
+        if records.len() != randomizers.len() {
+            bail!("Illegal operation: Record::encrypt_batch() expects one randomizer per record.")
+        }
+        // Ensure each randomizer corresponds to its record nonce.
+        for (record, randomizer) in records.iter().zip_eq(randomizers) {
+            if record.nonce != N::g_scalar_multiply(randomizer) {
+                bail!("Illegal operation: Record::encrypt_batch() randomizer does not correspond to the record nonce.")
+            }
+        }
+        // Compute the record view keys.
+        let shared_secrets = records
+            .iter()
+            .zip_eq(randomizers)
+            .map(|(record, randomizer)| **record.owner * *randomizer)
+            .collect::<Vec<_>>();
+        let mut record_view_keys = Group::to_x_coordinates(&shared_secrets);
+        // Encrypt the records.
+        let records = records
+            .iter()
+            .zip_eq(&record_view_keys)
+            .map(|(record, record_view_key)| record.encrypt_symmetric_unchecked(record_view_key))
+            .collect();
+        // Erase the record view keys.
+        record_view_keys.zeroize();
+        records
+    }
+
     /// Encrypts `self` under the given record view key.
     /// Note: This method does not check that the record view key corresponds to the record owner.
     /// Use `Self::encrypt` for the checked variant.
@@ -35,9 +70,12 @@ impl<N: Network> Record<N, Plaintext<N>> {
         // Determine the number of randomizers needed to encrypt the record.
         let num_randomizers = self.num_randomizers()?;
         // Prepare a randomizer for each field element.
-        let randomizers = N::hash_many_psd8(&[N::encryption_domain(), *record_view_key], num_randomizers);
+        let mut randomizers = N::hash_many_psd8(&[N::encryption_domain(), *record_view_key], num_randomizers);
         // Encrypt the record.
-        self.encrypt_with_randomizers(&randomizers)
+        let record = self.encrypt_with_randomizers(&randomizers);
+        // Erase the randomizers.
+        randomizers.zeroize();
+        record
     }
 
     /// Encrypts `self` under the given randomizers.
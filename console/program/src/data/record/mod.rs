@@ -18,8 +18,12 @@ pub use entry::Entry;
 mod helpers;
 pub use helpers::Owner;
 
+mod disclose;
+pub use disclose::RecordDisclosure;
+
 mod bytes;
 mod decrypt;
+mod dummy;
 mod encrypt;
 mod equal;
 mod find;
@@ -40,6 +44,7 @@ use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group, Scalar};
 
 use indexmap::IndexMap;
+use zeroize::Zeroize;
 
 /// A value stored in program record.
 #[derive(Clone)]
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The version of the record envelope encoding.
+const RECORD_ENVELOPE_VERSION: u8 = 1;
+
+impl<N: Network> FromBytes for RecordEnvelope<N> {
+    /// Reads the record envelope from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != RECORD_ENVELOPE_VERSION {
+            return Err(error("Invalid record envelope version"));
+        }
+
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the record name.
+        let record_name = Identifier::read_le(&mut reader)?;
+        // Read the record.
+        let record = Record::read_le(&mut reader)?;
+
+        // Return the record envelope.
+        Ok(Self::new(program_id, record_name, record))
+    }
+}
+
+impl<N: Network> ToBytes for RecordEnvelope<N> {
+    /// Writes the record envelope to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        RECORD_ENVELOPE_VERSION.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the record name.
+        self.record_name.write_le(&mut writer)?;
+        // Write the record.
+        self.record.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Construct a new record envelope.
+        let expected = test_helpers::sample_record_envelope();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, RecordEnvelope::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
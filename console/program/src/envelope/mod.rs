@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+#[cfg(test)]
+mod test_vectors;
+
+use crate::{Identifier, Plaintext, ProgramID, Record};
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+/// A record plaintext, with the program ID and record name it belongs to, in a stable, versioned encoding.
+/// This is the format for exchanging records between wallets, provers, and explorers,
+/// as it carries everything needed to recompute the record commitment.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecordEnvelope<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The record name.
+    record_name: Identifier<N>,
+    /// The record, which contains the owner, the entries, and the nonce.
+    record: Record<N, Plaintext<N>>,
+}
+
+impl<N: Network> RecordEnvelope<N> {
+    /// Initializes a new record envelope.
+    pub const fn new(program_id: ProgramID<N>, record_name: Identifier<N>, record: Record<N, Plaintext<N>>) -> Self {
+        Self { program_id, record_name, record }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the record name.
+    pub const fn record_name(&self) -> &Identifier<N> {
+        &self.record_name
+    }
+
+    /// Returns the record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns the record commitment.
+    pub fn to_commitment(&self) -> Result<Field<N>> {
+        self.record.to_commitment(&self.program_id, &self.record_name)
+    }
+
+    /// Returns the program ID, record name, and record.
+    pub fn into_parts(self) -> (ProgramID<N>, Identifier<N>, Record<N, Plaintext<N>>) {
+        (self.program_id, self.record_name, self.record)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    pub(crate) fn sample_record_envelope() -> RecordEnvelope<CurrentNetwork> {
+        RecordEnvelope::new(
+            ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("credits").unwrap(),
+            Record::from_str(
+                "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, microcredits: 100u64.private, _nonce: 0group.public }",
+            )
+            .unwrap(),
+        )
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for RecordEnvelope<N> {
+    /// Serializes the record envelope into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut envelope = serializer.serialize_struct("RecordEnvelope", 4)?;
+                envelope.serialize_field("version", &1u8)?;
+                envelope.serialize_field("program_id", &self.program_id)?;
+                envelope.serialize_field("record_name", &self.record_name)?;
+                envelope.serialize_field("record", &self.record)?;
+                envelope.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RecordEnvelope<N> {
+    /// Deserializes the record envelope from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the record envelope from a string into a value.
+                let mut envelope = serde_json::Value::deserialize(deserializer)?;
+
+                // Ensure the version is valid.
+                let version: u8 = DeserializeExt::take_from_value::<D>(&mut envelope, "version")?;
+                if version != 1 {
+                    return Err(de::Error::custom("Invalid record envelope version"));
+                }
+
+                // Recover the record envelope.
+                Ok(Self::new(
+                    // Retrieve the program ID.
+                    DeserializeExt::take_from_value::<D>(&mut envelope, "program_id")?,
+                    // Retrieve the record name.
+                    DeserializeExt::take_from_value::<D>(&mut envelope, "record_name")?,
+                    // Retrieve the record.
+                    DeserializeExt::take_from_value::<D>(&mut envelope, "record")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "record envelope"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        // Sample the record envelope.
+        let expected = test_helpers::sample_record_envelope();
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, RecordEnvelope::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Ensure an unknown version is rejected.
+        let mut value = serde_json::to_value(&expected)?;
+        value["version"] = serde_json::Value::from(2u8);
+        assert!(serde_json::from_value::<RecordEnvelope<CurrentNetwork>>(value).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        // Sample the record envelope.
+        let expected = test_helpers::sample_record_envelope();
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, RecordEnvelope::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
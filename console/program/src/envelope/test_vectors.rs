@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_network::Testnet3;
+
+type CurrentNetwork = Testnet3;
+
+/// The record envelope test vectors, which cover records with private and public owners,
+/// entries of every visibility, literal and struct entries, and zero and non-zero nonces.
+/// These must not change, as wallets, provers, and explorers depend on them.
+const TEST_VECTORS: &str = include_str!("./resources/record_envelope_vectors.json");
+
+/// Returns the given field of the test vector, as a string.
+fn get<'a>(case: &'a serde_json::Value, field: &str) -> &'a str {
+    case[field].as_str().unwrap_or_else(|| panic!("Missing the \"{field}\" field in the test vector"))
+}
+
+/// Decodes the given hex string.
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    (0..hex.len()).step_by(2).map(|i| Ok(u8::from_str_radix(&hex[i..i + 2], 16)?)).collect()
+}
+
+#[test]
+fn test_record_envelope_vectors() -> Result<()> {
+    let cases: Vec<serde_json::Value> = serde_json::from_str(TEST_VECTORS)?;
+    assert!(cases.len() >= 5, "Expected at least 5 test vectors");
+
+    for case in &cases {
+        let name = get(case, "name");
+
+        // Construct the record envelope.
+        let expected = RecordEnvelope::<CurrentNetwork>::new(
+            ProgramID::from_str(get(case, "program_id"))?,
+            Identifier::from_str(get(case, "record_name"))?,
+            Record::from_str(get(case, "record"))?,
+        );
+
+        // Check the JSON encoding.
+        assert_eq!(serde_json::to_value(&expected)?, case["json"], "Mismatching JSON for '{name}'");
+        assert_eq!(expected, serde_json::from_value(case["json"].clone())?, "Mismatching JSON for '{name}'");
+
+        // Check the byte encoding.
+        let bytes = decode_hex(get(case, "bytes"))?;
+        assert_eq!(expected.to_bytes_le()?, bytes, "Mismatching bytes for '{name}'");
+        assert_eq!(expected, RecordEnvelope::read_le(&bytes[..])?, "Mismatching bytes for '{name}'");
+
+        // Check the record commitment.
+        assert_eq!(
+            expected.to_commitment()?.to_string(),
+            get(case, "commitment"),
+            "Mismatching commitment for '{name}'"
+        );
+
+        // Ensure an unknown version is rejected in both encodings.
+        let mut invalid_bytes = bytes;
+        invalid_bytes[0] = 2;
+        assert!(RecordEnvelope::<CurrentNetwork>::read_le(&invalid_bytes[..]).is_err());
+        let mut invalid_json = case["json"].clone();
+        invalid_json["version"] = serde_json::Value::from(2u8);
+        assert!(serde_json::from_value::<RecordEnvelope<CurrentNetwork>>(invalid_json).is_err());
+    }
+    Ok(())
+}
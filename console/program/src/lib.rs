@@ -30,6 +30,9 @@ pub use data::*;
 mod data_types;
 pub use data_types::*;
 
+mod envelope;
+pub use envelope::*;
+
 mod id;
 pub use id::*;
 
@@ -47,3 +50,6 @@ pub use response::*;
 
 pub mod state_path;
 pub use state_path::*;
+
+#[cfg(any(test, feature = "prop-tests"))]
+pub mod prop_tests;
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Ciphertext, Entry, Identifier, Literal, Network, Owner, Plaintext, Record};
+use snarkvm_console_types::{
+    prelude::*,
+    prop_tests::{any_address, any_field, any_scalar},
+};
+
+use indexmap::IndexMap;
+use proptest::{
+    collection::vec,
+    prelude::{any, BoxedStrategy, Strategy},
+};
+
+/// A plaintext record, along with the randomizer that derives its nonce.
+pub type RecordWithRandomizer<N> = (Record<N, Plaintext<N>>, Scalar<N>);
+
+/// Returns a strategy for record entries holding a field element, with a random visibility.
+pub fn any_entry<N: Network>() -> BoxedStrategy<Entry<N, Plaintext<N>>> {
+    (any_field::<N>(), 0..3u8)
+        .prop_map(|(field, visibility)| {
+            let plaintext = Plaintext::from(Literal::Field(field));
+            match visibility {
+                0 => Entry::Constant(plaintext),
+                1 => Entry::Public(plaintext),
+                _ => Entry::Private(plaintext),
+            }
+        })
+        .boxed()
+}
+
+/// Returns a strategy for plaintext records with up to `max_entries` entries,
+/// along with the randomizer that derives the record nonce.
+///
+/// The records shrink toward a private owner and no entries.
+pub fn any_record_with_randomizer<N: Network>(max_entries: usize) -> BoxedStrategy<RecordWithRandomizer<N>> {
+    (any_address::<N>(), any::<bool>(), vec(any_entry::<N>(), 0..=max_entries), any_scalar::<N>())
+        .prop_map(|(address, is_public, entries, randomizer)| {
+            // Construct the owner.
+            let owner = match is_public {
+                true => Owner::Public(address),
+                false => Owner::Private(Plaintext::from(Literal::Address(address))),
+            };
+            // Construct the data.
+            let data = entries
+                .into_iter()
+                .enumerate()
+                .map(|(index, entry)| {
+                    (Identifier::from_str(&format!("entry_{index}")).expect("Failed to parse an identifier"), entry)
+                })
+                .collect::<IndexMap<_, _>>();
+            // Compute the nonce.
+            let nonce = N::g_scalar_multiply(&randomizer);
+            // Construct the record.
+            let record =
+                Record::<N, Plaintext<N>>::from_plaintext(owner, data, nonce).expect("Failed to construct a record");
+            (record, randomizer)
+        })
+        .boxed()
+}
+
+/// Returns a strategy for records encrypted for their owner, with up to `max_entries` entries.
+pub fn any_record_ciphertext<N: Network>(max_entries: usize) -> BoxedStrategy<Record<N, Ciphertext<N>>> {
+    any_record_with_randomizer::<N>(max_entries)
+        .prop_map(|(record, randomizer)| record.encrypt(randomizer).expect("Failed to encrypt a record"))
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    use proptest::prelude::*;
+
+    type CurrentNetwork = Testnet3;
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_record_plaintext_bytes_roundtrip((record, _) in any_record_with_randomizer::<CurrentNetwork>(4)) {
+            let bytes = record.to_bytes_le().unwrap();
+            let candidate = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&bytes[..]).unwrap();
+            prop_assert_eq!(bytes, candidate.to_bytes_le().unwrap());
+        }
+
+        #[test]
+        fn test_record_ciphertext_bytes_roundtrip(record in any_record_ciphertext::<CurrentNetwork>(4)) {
+            let bytes = record.to_bytes_le().unwrap();
+            let candidate = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::read_le(&bytes[..]).unwrap();
+            prop_assert_eq!(bytes, candidate.to_bytes_le().unwrap());
+        }
+    }
+}
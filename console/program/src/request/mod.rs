@@ -26,6 +26,8 @@ use snarkvm_console_account::{Address, ComputeKey, GraphKey, PrivateKey, Signatu
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
+use zeroize::Zeroizing;
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct Request<N: Network> {
     /// The request signer.
@@ -35,8 +35,9 @@ impl<N: Network> Request<N> {
             )
         }
 
-        // Retrieve `sk_sig`.
-        let sk_sig = private_key.sk_sig();
+        // Retrieve `sk_sig`. Note: The secrets of this function are wrapped in `Zeroizing`,
+        // so they are erased when they go out of scope, including on an early return.
+        let sk_sig = Zeroizing::new(private_key.sk_sig());
 
         // Derive the compute key.
         let compute_key = ComputeKey::try_from(private_key)?;
@@ -46,21 +47,23 @@ impl<N: Network> Request<N> {
         let pr_sig = compute_key.pr_sig();
 
         // Derive the view key.
-        let view_key = ViewKey::try_from((private_key, &compute_key))?;
+        let view_key = Zeroizing::new(ViewKey::try_from((private_key, &compute_key))?);
         // Derive `sk_tag` from the graph key.
-        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+        let sk_tag = GraphKey::try_from(*view_key)?.sk_tag();
 
         // Sample a random nonce.
-        let nonce = Field::<N>::rand(rng);
+        let nonce = Zeroizing::new(Field::<N>::rand(rng));
         // Compute a `r` as `HashToScalar(sk_sig || nonce)`. Note: This is the transition secret key `tsk`.
-        let r = N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, nonce])?;
+        let r = Zeroizing::new(N::hash_to_scalar_psd4(&[N::serial_number_domain(), sk_sig.to_field()?, *nonce])?);
+        // Erase the nonce.
+        drop(nonce);
         // Compute `g_r` as `r * G`. Note: This is the transition public key `tpk`.
         let g_r = N::g_scalar_multiply(&r);
 
         // Derive the signer from the compute key.
         let signer = Address::try_from(compute_key)?;
         // Compute the transition view key `tvk` as `r * signer`.
-        let tvk = (*signer * r).to_x_coordinate();
+        let tvk = (*signer * *r).to_x_coordinate();
         // Compute the transition commitment `tcm` as `Hash(tvk)`.
         let tcm = N::hash_psd2(&[tvk])?;
 
@@ -173,9 +176,9 @@ impl<N: Network> Request<N> {
                     // Compute the generator `H` as `HashToGroup(commitment)`.
                     let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
                     // Compute `h_r` as `r * H`.
-                    let h_r = h * r;
+                    let h_r = h * *r;
                     // Compute `gamma` as `sk_sig * H`.
-                    let gamma = h * sk_sig;
+                    let gamma = h * *sk_sig;
 
                     // Compute the `serial_number` from `gamma`.
                     let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
@@ -218,7 +221,7 @@ impl<N: Network> Request<N> {
         // Compute `challenge` as `HashToScalar(r * G, pk_sig, pr_sig, signer, [tvk, tcm, function ID, input IDs])`.
         let challenge = N::hash_to_scalar_psd8(&message)?;
         // Compute `response` as `r - challenge * sk_sig`.
-        let response = r - challenge * sk_sig;
+        let response = *r - challenge * *sk_sig;
 
         Ok(Self {
             signer,
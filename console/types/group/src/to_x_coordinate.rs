@@ -19,4 +19,38 @@ impl<E: Environment> Group<E> {
     pub fn to_x_coordinate(&self) -> Field<E> {
         Field::new(self.group.to_affine().to_x_coordinate())
     }
+
+    /// Returns the *x-coordinates* in the affine coordinates of the given groups.
+    /// This normalizes all of the groups with a single batch inversion, instead of one inversion per group.
+    pub fn to_x_coordinates(groups: &[Self]) -> Vec<Field<E>> {
+        // Convert to affine, with a single batch inversion.
+        let affine = E::Projective::batch_normalization_into_affine(groups.iter().map(|group| group.group).collect());
+        // Returns the x-coordinates.
+        affine.into_iter().map(|affine| Field::new(affine.to_x_coordinate())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_to_x_coordinates() {
+        let mut rng = TestRng::default();
+
+        // Sample random groups, including the zero group.
+        let mut groups = (0..ITERATIONS).map(|_| Group::<CurrentEnvironment>::rand(&mut rng)).collect::<Vec<_>>();
+        groups.push(Group::zero());
+
+        // Ensure the batched x-coordinates match the individual x-coordinates.
+        let expected = groups.iter().map(|group| group.to_x_coordinate()).collect::<Vec<_>>();
+        assert_eq!(expected, Group::to_x_coordinates(&groups));
+        // Ensure the empty case is handled.
+        assert!(Group::<CurrentEnvironment>::to_x_coordinates(&[]).is_empty());
+    }
 }
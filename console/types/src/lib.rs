@@ -18,6 +18,9 @@
 
 pub use modules::*;
 
+#[cfg(any(test, feature = "prop-tests"))]
+pub mod prop_tests;
+
 pub mod prelude {
     pub use crate::modules::*;
     pub use snarkvm_console_network_environment::prelude::*;
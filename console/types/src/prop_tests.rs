@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::*;
+
+use proptest::prelude::{any, prop_oneof, BoxedStrategy, Strategy};
+
+/// Returns a strategy for field elements.
+///
+/// Half of the samples are small integers, which shrink toward zero,
+/// and the other half are uniformly random, which shrink toward the sample from seed zero.
+pub fn any_field<E: Environment>() -> BoxedStrategy<Field<E>> {
+    prop_oneof![
+        any::<u64>().prop_map(Field::from_u64),
+        any::<u64>().prop_map(|seed| Field::rand(&mut TestRng::fixed(seed))),
+    ]
+    .boxed()
+}
+
+/// Returns a strategy for scalar elements.
+///
+/// Half of the samples are small integers, which shrink toward zero,
+/// and the other half are uniformly random, which shrink toward the sample from seed zero.
+pub fn any_scalar<E: Environment>() -> BoxedStrategy<Scalar<E>> {
+    prop_oneof![
+        any::<u64>().prop_map(|value| Scalar::new(E::Scalar::from(value))),
+        any::<u64>().prop_map(|seed| Scalar::rand(&mut TestRng::fixed(seed))),
+    ]
+    .boxed()
+}
+
+/// Returns a strategy for group elements in the prime-order subgroup,
+/// sampled as a scalar multiple of the generator, which shrinks toward the identity.
+pub fn any_group<E: Environment>() -> BoxedStrategy<Group<E>> {
+    any_scalar::<E>().prop_map(|scalar| Group::generator() * scalar).boxed()
+}
+
+/// Returns a strategy for addresses, sampled as a scalar multiple of the generator.
+pub fn any_address<E: Environment>() -> BoxedStrategy<Address<E>> {
+    any_group::<E>().prop_map(Address::new).boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    use proptest::prelude::*;
+
+    type CurrentEnvironment = Console;
+
+    proptest! {
+        #[test]
+        fn test_field_bytes_roundtrip(field in any_field::<CurrentEnvironment>()) {
+            let bytes = field.to_bytes_le().unwrap();
+            prop_assert_eq!(field, Field::read_le(&bytes[..]).unwrap());
+        }
+
+        #[test]
+        fn test_scalar_bytes_roundtrip(scalar in any_scalar::<CurrentEnvironment>()) {
+            let bytes = scalar.to_bytes_le().unwrap();
+            prop_assert_eq!(scalar, Scalar::read_le(&bytes[..]).unwrap());
+        }
+
+        #[test]
+        fn test_group_bytes_roundtrip(group in any_group::<CurrentEnvironment>()) {
+            let bytes = group.to_bytes_le().unwrap();
+            prop_assert_eq!(group, Group::read_le(&bytes[..]).unwrap());
+        }
+
+        #[test]
+        fn test_address_string_roundtrip(address in any_address::<CurrentEnvironment>()) {
+            prop_assert_eq!(address, Address::from_str(&address.to_string()).unwrap());
+        }
+    }
+}
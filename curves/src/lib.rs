@@ -28,6 +28,9 @@ pub mod edwards_bls12;
 pub mod errors;
 pub use errors::*;
 
+pub mod prepared_cache;
+pub use prepared_cache::*;
+
 pub mod templates;
 
 #[cfg_attr(test, macro_use)]
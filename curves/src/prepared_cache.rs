@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::traits::PairingCurve;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// A cache of prepared pairing points, for points that are paired repeatedly,
+/// such as the G2 elements of verifying keys or of public keys in aggregate signatures.
+///
+/// Once the cache holds `capacity` points, further points are prepared without being cached.
+#[derive(Debug)]
+pub struct PreparedCache<G: PairingCurve> {
+    /// The maximum number of cached points.
+    capacity: usize,
+    /// The mapping of `point` to its prepared form.
+    prepared: RwLock<HashMap<G, Arc<G::Prepared>>>,
+}
+
+impl<G: PairingCurve> PreparedCache<G> {
+    /// Initializes a new cache, which holds up to `capacity` prepared points.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, prepared: Default::default() }
+    }
+
+    /// Returns the maximum number of cached points.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached points.
+    pub fn len(&self) -> usize {
+        self.prepared.read().unwrap_or_else(|error| error.into_inner()).len()
+    }
+
+    /// Returns `true` if no points are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the prepared form of the given point, preparing and caching it if it is not cached.
+    pub fn get_or_prepare(&self, point: &G) -> Arc<G::Prepared> {
+        // Return the prepared point, if it is cached.
+        if let Some(prepared) = self.prepared.read().unwrap_or_else(|error| error.into_inner()).get(point) {
+            return prepared.clone();
+        }
+        // Prepare the point without holding the lock, as preparation is expensive.
+        let prepared = Arc::new(point.prepare());
+        // Cache the prepared point, if there is room.
+        let mut cache = self.prepared.write().unwrap_or_else(|error| error.into_inner());
+        if cache.len() < self.capacity {
+            // If another thread cached the point in the meantime, return its copy.
+            return cache.entry(*point).or_insert(prepared).clone();
+        }
+        prepared
+    }
+
+    /// Removes all cached points.
+    pub fn clear(&self) {
+        self.prepared.write().unwrap_or_else(|error| error.into_inner()).clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bls12_377::{G2Affine, G2Projective},
+        traits::ProjectiveCurve,
+    };
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    #[test]
+    fn test_get_or_prepare() {
+        let rng = &mut TestRng::default();
+        let cache = PreparedCache::<G2Affine>::new(2);
+        assert!(cache.is_empty());
+
+        let points = (0..3).map(|_| G2Projective::rand(rng).to_affine()).collect::<Vec<_>>();
+        for point in &points {
+            assert_eq!(*cache.get_or_prepare(point), point.prepare());
+        }
+        // Only the first two points are cached.
+        assert_eq!(cache.len(), 2);
+        assert!(Arc::ptr_eq(&cache.get_or_prepare(&points[0]), &cache.get_or_prepare(&points[0])));
+        assert!(!Arc::ptr_eq(&cache.get_or_prepare(&points[2]), &cache.get_or_prepare(&points[2])));
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+}
@@ -42,6 +42,7 @@ macro_rules! impl_sw_curve_serializer {
         }
 
         impl<P: $params> Valid for Projective<P> {
+            /// Checks that the point is on the curve and in the prime-order subgroup.
             fn check(&self) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
                 let point = Affine::<P>::from(*self);
                 if point.is_on_curve() & point.is_in_correct_subgroup_assuming_on_curve() {
@@ -105,6 +106,7 @@ macro_rules! impl_sw_curve_serializer {
         }
 
         impl<P: $params> Valid for Affine<P> {
+            /// Checks that the point is on the curve and in the prime-order subgroup.
             fn check(&self) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
                 if self.is_on_curve() & self.is_in_correct_subgroup_assuming_on_curve() {
                     Ok(())
@@ -164,6 +166,7 @@ macro_rules! impl_edwards_curve_serializer {
         }
 
         impl<P: $params> Valid for Projective<P> {
+            /// Checks that the point is on the curve and in the prime-order subgroup.
             fn check(&self) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
                 let point = Affine::<P>::from(*self);
                 if point.is_on_curve() & point.is_in_correct_subgroup_assuming_on_curve() {
@@ -221,6 +224,7 @@ macro_rules! impl_edwards_curve_serializer {
         }
 
         impl<P: $params> Valid for Affine<P> {
+            /// Checks that the point is on the curve and in the prime-order subgroup.
             #[allow(unused_qualifications)]
             fn check(&self) -> Result<(), snarkvm_utilities::serialize::SerializationError> {
                 if self.is_on_curve() & self.is_in_correct_subgroup_assuming_on_curve() {
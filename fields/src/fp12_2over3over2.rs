@@ -537,6 +537,7 @@ impl<P: Fp12Parameters> CanonicalDeserializeWithFlags for Fp12<P> {
 }
 
 impl<P: Fp12Parameters> Valid for Fp12<P> {
+    /// The coefficients are range-checked while decoding, so there is nothing further to check.
     fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
         Ok(())
     }
@@ -457,6 +457,7 @@ impl<P: Fp2Parameters> CanonicalDeserializeWithFlags for Fp2<P> {
     }
 }
 impl<P: Fp2Parameters> Valid for Fp2<P> {
+    /// The coefficients are range-checked while decoding, so there is nothing further to check.
     fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
         Ok(())
     }
@@ -536,6 +536,7 @@ impl<P: Fp6Parameters> CanonicalSerialize for Fp6<P> {
 }
 
 impl<P: Fp6Parameters> Valid for Fp6<P> {
+    /// The coefficients are range-checked while decoding, so there is nothing further to check.
     fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
         Ok(())
     }
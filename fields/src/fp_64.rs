@@ -0,0 +1,501 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    impl_add_sub_from_field_ref,
+    impl_mul_div_from_field_ref,
+    FftField,
+    Field,
+    FieldError,
+    FieldParameters,
+    LegendreSymbol,
+    One,
+    PoseidonDefaultField,
+    PoseidonDefaultParameters,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    biginteger::{BigInteger as _BigInteger, BigInteger64 as BigInteger},
+    serialize::CanonicalDeserialize,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+use zeroize::Zeroize;
+
+pub trait Fp64Parameters: FieldParameters<BigInteger = BigInteger> {}
+
+/// A prime field element with a modulus of at most 64 bits, in Montgomery form with `R = 2^64`.
+///
+/// Unlike `Fp256` and `Fp384`, the modulus may use the most significant bit of its limb
+/// (e.g. the Goldilocks prime), so intermediate sums and products are computed in 128 bits.
+#[derive(Derivative, Zeroize)]
+#[derivative(
+    Default(bound = ""),
+    Hash(bound = ""),
+    Clone(bound = ""),
+    Copy(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+pub struct Fp64<P>(
+    pub BigInteger,
+    #[derivative(Debug = "ignore")]
+    #[doc(hidden)]
+    pub PhantomData<P>,
+);
+
+impl<P: Fp64Parameters> Fp64<P> {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.0 < P::MODULUS
+    }
+
+    /// Returns `value * R^-1 mod MODULUS`, for a `value` less than `MODULUS * R`.
+    #[inline(always)]
+    fn mont_reduce(value: u128) -> u64 {
+        // The Montgomery reduction here is based on Algorithm 14.32 in
+        // Handbook of Applied Cryptography
+        // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
+        let modulus = P::MODULUS.0[0];
+        let (lo, hi) = (value as u64, (value >> 64) as u64);
+        let k = lo.wrapping_mul(P::INV);
+        let k_times_modulus = u128::from(k) * u128::from(modulus);
+        // The low limb of `value + k * MODULUS` is zero by construction, only its carry remains.
+        let (_, carry) = lo.overflowing_add(k_times_modulus as u64);
+        // This is less than `2 * MODULUS`, and therefore fits in 65 bits.
+        let result = u128::from(hi) + (k_times_modulus >> 64) + u128::from(carry);
+        if result >= u128::from(modulus) { (result - u128::from(modulus)) as u64 } else { result as u64 }
+    }
+}
+
+impl<P: Fp64Parameters> Zero for Fp64<P> {
+    #[inline]
+    fn zero() -> Self {
+        Fp64::<P>(BigInteger::from(0), PhantomData)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: Fp64Parameters> One for Fp64<P> {
+    #[inline]
+    fn one() -> Self {
+        Fp64::<P>(P::R, PhantomData)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self == &Self::one()
+    }
+}
+
+impl<P: Fp64Parameters> Field for Fp64<P> {
+    type BasePrimeField = Self;
+
+    // 64/64 = 1 limb.
+    impl_field_from_random_bytes_with_flags!(1);
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        other
+    }
+
+    fn sum_of_products<'a>(
+        a: impl Iterator<Item = &'a Self> + Clone,
+        b: impl Iterator<Item = &'a Self> + Clone,
+    ) -> Self {
+        a.zip(b).map(|(a, b)| *a * b).sum()
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        let mut temp = *self;
+        temp.double_in_place();
+        temp
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) {
+        let copy = *self;
+        self.add_assign(&copy);
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        let copy = *self;
+        self.mul_assign(&copy);
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // By Fermat's little theorem, the inverse is `self^(MODULUS - 2)`.
+            Some(self.pow([P::MODULUS.0[0] - 2]))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {
+        // No-op: No effect in a prime field.
+    }
+}
+
+impl<P: Fp64Parameters> PrimeField for Fp64<P> {
+    type BigInteger = BigInteger;
+    type Parameters = P;
+
+    #[inline]
+    fn from_bigint(r: BigInteger) -> Option<Self> {
+        let mut r = Fp64(r, PhantomData);
+        if r.is_zero() {
+            Some(r)
+        } else if r.is_valid() {
+            r *= &Fp64(P::R2, PhantomData);
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn to_bigint(&self) -> BigInteger {
+        BigInteger([Self::mont_reduce(u128::from((self.0).0[0]))])
+    }
+
+    /// The GLV decomposition is only defined for the scalar fields of curves with an efficient endomorphism.
+    fn decompose(
+        &self,
+        _q1: &[u64; 4],
+        _q2: &[u64; 4],
+        _b1: Self,
+        _b2: Self,
+        _r128: Self,
+        _half_r: &[u64; 8],
+    ) -> (Self, Self, bool, bool) {
+        unimplemented!("The GLV decomposition is not supported for 64-bit prime fields")
+    }
+}
+
+impl<P: Fp64Parameters> FftField for Fp64<P> {
+    type FftParameters = P;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Some(Self(P::LARGE_SUBGROUP_ROOT_OF_UNITY?, PhantomData))
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self(P::GENERATOR, PhantomData)
+    }
+}
+
+impl<P: Fp64Parameters> SquareRootField for Fp64<P> {
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        use crate::LegendreSymbol::*;
+
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+
+        if s.is_zero() {
+            Zero
+        } else if s.is_one() {
+            QuadraticResidue
+        } else {
+            QuadraticNonResidue
+        }
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_impl!(Self, P, self)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(sqrt) = self.sqrt() {
+            *self = sqrt;
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl<P: Fp64Parameters + PoseidonDefaultParameters> PoseidonDefaultField for Fp64<P> {}
+
+impl<P: Fp64Parameters> From<u128> for Fp64<P> {
+    /// Attempts to convert an integer into a field element.
+    /// Panics if the provided integer is invalid (e.g. larger than the field modulus).
+    fn from(other: u128) -> Self {
+        let other = u64::try_from(other).expect("The integer exceeds the 64-bit field modulus");
+        Self::from_bigint(BigInteger::from(other)).unwrap()
+    }
+}
+
+impl_primefield_from_int!(Fp64, u64, Fp64Parameters);
+impl_primefield_from_int!(Fp64, u32, Fp64Parameters);
+impl_primefield_from_int!(Fp64, u16, Fp64Parameters);
+impl_primefield_from_int!(Fp64, u8, Fp64Parameters);
+
+impl_primefield_standard_sample!(Fp64, Fp64Parameters);
+
+impl_add_sub_from_field_ref!(Fp64, Fp64Parameters);
+impl_mul_div_from_field_ref!(Fp64, Fp64Parameters);
+
+impl<P: Fp64Parameters> ToBits for Fp64<P> {
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        let initial_len = vec.len();
+        self.to_bigint().write_bits_le(vec);
+        vec.truncate(initial_len + P::MODULUS_BITS as usize);
+    }
+
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        let initial_len = vec.len();
+        self.write_bits_le(vec);
+        vec[initial_len..].reverse();
+    }
+
+    fn num_bits() -> Option<usize> {
+        Some(64)
+    }
+}
+
+impl<P: Fp64Parameters> ToBytes for Fp64<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.to_bigint().write_le(writer)
+    }
+}
+
+impl<P: Fp64Parameters> FromBytes for Fp64<P> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        BigInteger::read_le(reader).and_then(|b| match Self::from_bigint(b) {
+            Some(f) => Ok(f),
+            None => Err(FieldError::InvalidFieldElement.into()),
+        })
+    }
+}
+
+/// `Fp` elements are ordered lexicographically.
+impl<P: Fp64Parameters> Ord for Fp64<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bigint().cmp(&other.to_bigint())
+    }
+}
+
+impl<P: Fp64Parameters> PartialOrd for Fp64<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp64Parameters> FromStr for Fp64<P> {
+    type Err = FieldError;
+
+    /// Interpret a string of numbers as a (congruent) prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(FieldError::ParsingEmptyString);
+        }
+
+        if s == "0" {
+            return Ok(Self::zero());
+        }
+
+        let mut res = Self::zero();
+
+        let ten =
+            Self::from_bigint(<Self as PrimeField>::BigInteger::from(10)).ok_or(FieldError::InvalidFieldElement)?;
+
+        let mut first_digit = true;
+
+        for c in s.chars() {
+            match c.to_digit(10) {
+                Some(c) => {
+                    if first_digit {
+                        if c == 0 {
+                            return Err(FieldError::InvalidString);
+                        }
+
+                        first_digit = false;
+                    }
+
+                    res.mul_assign(&ten);
+                    res.add_assign(
+                        &Self::from_bigint(<Self as PrimeField>::BigInteger::from(u64::from(c)))
+                            .ok_or(FieldError::InvalidFieldElement)?,
+                    );
+                }
+                None => return Err(FieldError::ParsingNonDigitCharacter),
+            }
+        }
+
+        if !res.is_valid() { Err(FieldError::InvalidFieldElement) } else { Ok(res) }
+    }
+}
+
+impl<P: Fp64Parameters> Debug for Fp64<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<P: Fp64Parameters> Display for Fp64<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<P: Fp64Parameters> Neg for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    #[must_use]
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            let mut tmp = P::MODULUS;
+            tmp.sub_noborrow(&self.0);
+            Fp64::<P>(tmp, PhantomData)
+        } else {
+            self
+        }
+    }
+}
+
+impl<'a, P: Fp64Parameters> Add<&'a Fp64<P>> for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp64Parameters> Sub<&'a Fp64<P>> for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp64Parameters> Mul<&'a Fp64<P>> for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp64Parameters> Div<&'a Fp64<P>> for Fp64<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp64Parameters> AddAssign<&'a Self> for Fp64<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // The sum may exceed 64 bits, so it is reduced in 128 bits.
+        let modulus = u128::from(P::MODULUS.0[0]);
+        let sum = u128::from((self.0).0[0]) + u128::from((other.0).0[0]);
+        (self.0).0[0] = if sum >= modulus { (sum - modulus) as u64 } else { sum as u64 };
+    }
+}
+
+impl<'a, P: Fp64Parameters> SubAssign<&'a Self> for Fp64<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        // If `other` is larger than `self`, the wrapped difference plus the modulus is in range.
+        let (difference, borrow) = (self.0).0[0].overflowing_sub((other.0).0[0]);
+        (self.0).0[0] = if borrow { difference.wrapping_add(P::MODULUS.0[0]) } else { difference };
+    }
+}
+
+impl<'a, P: Fp64Parameters> MulAssign<&'a Self> for Fp64<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        (self.0).0[0] = Self::mont_reduce(u128::from((self.0).0[0]) * u128::from((other.0).0[0]));
+    }
+}
+
+impl<'a, P: Fp64Parameters> DivAssign<&'a Self> for Fp64<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
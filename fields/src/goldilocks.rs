@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    FftParameters,
+    FieldParameters,
+    Fp64,
+    Fp64Parameters,
+    PoseidonDefaultParameters,
+    PoseidonDefaultParametersEntry,
+};
+use snarkvm_utilities::biginteger::BigInteger64 as BigInteger;
+
+/// The Goldilocks field, of prime order `q = 2^64 - 2^32 + 1`.
+///
+/// Its multiplicative group has a two-adicity of 32, which supports FFTs over domains of up to `2^32` elements,
+/// and its elements fit in a single 64-bit limb, which makes it suited for hash-based (e.g. STARK-style) protocols.
+///
+/// Roots of unity computed from modulus and R using this sage code:
+///
+/// ```ignore
+/// q = 18446744069414584321
+/// R = 4294967295 # Montgomery R
+/// s = 32
+/// o = q - 1
+/// F = GF(q)
+/// g = F(7)
+/// assert g.multiplicative_order() == o
+/// g2 = g ** (o/2**s)
+/// assert g2.multiplicative_order() == 2**s
+/// print("Gen (g * R % q): ", g * R % q)
+/// print("2-adic gen (g2 % q): ", g2 % q)
+/// print("2-adic gen (g2 * R % q): ", g2 * R % q)
+/// ```
+pub type Goldilocks = Fp64<GoldilocksParameters>;
+
+pub struct GoldilocksParameters;
+
+impl Fp64Parameters for GoldilocksParameters {}
+
+impl FftParameters for GoldilocksParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const POWERS_OF_ROOTS_OF_UNITY: &'static [BigInteger] = &[
+        BigInteger([15733474329512464024]),
+        BigInteger([6163429357473536562]),
+        BigInteger([13288442737737738162]),
+        BigInteger([11848168407699165279]),
+        BigInteger([20904484799587963]),
+        BigInteger([10473709294003837707]),
+        BigInteger([8707395988713065759]),
+        BigInteger([7984864372213488273]),
+        BigInteger([14053449032146768478]),
+        BigInteger([3961981891181468770]),
+        BigInteger([16605412514144166046]),
+        BigInteger([3616995587998296759]),
+        BigInteger([6815045114074884550]),
+        BigInteger([10135969988448727187]),
+        BigInteger([430584883067102937]),
+        BigInteger([12267112747022536458]),
+        BigInteger([13797134855221748930]),
+        BigInteger([2523909884358325590]),
+        BigInteger([1205735313231991947]),
+        BigInteger([3878014442329970502]),
+        BigInteger([10781050935026037169]),
+        BigInteger([2135208489130820273]),
+        BigInteger([10087739294013848503]),
+        BigInteger([6647628942875889800]),
+        BigInteger([16576810576923738718]),
+        BigInteger([576460752303421440]),
+        BigInteger([18446744069414584193]),
+        BigInteger([18446673700670406657]),
+        BigInteger([268435456]),
+        BigInteger([18374686475393433601]),
+        BigInteger([18446744069414518785]),
+    ];
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 32;
+    /// TWO_ADIC_ROOT_OF_UNITY = 1753635133440165772
+    /// Encoded in Montgomery form, the value is
+    /// (1753635133440165772 * R % q) = 15733474329512464024
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([15733474329512464024u64]);
+}
+
+impl FieldParameters for GoldilocksParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 7
+    /// Encoded in Montgomery form, so the value is
+    /// (7 * R) % q = 30064771065
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([30064771065u64]);
+    #[rustfmt::skip]
+    const INV: u64 = 18446744069414584319u64;
+    /// MODULUS = 18446744069414584321
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([0xffffffff00000001]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 64;
+    /// (q - 1)/2 = 9223372034707292160
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([0x7fffffff80000000]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([4294967295u64]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([18446744065119617025u64]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 0;
+    // T and T_MINUS_ONE_DIV_TWO, where q - 1 = 2^s * t
+
+    /// t = (q - 1) / 2^s = 4294967295
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([0xffffffff]);
+    /// (t - 1) / 2 = 2147483647
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([0x7fffffff]);
+}
+
+impl PoseidonDefaultParameters for GoldilocksParameters {
+    /// The S-box exponent is 7, the smallest `alpha` with `gcd(alpha, q - 1) = 1`,
+    /// with the round numbers used for 64-bit fields in Plonky2.
+    const PARAMS_OPT_FOR_CONSTRAINTS: [PoseidonDefaultParametersEntry; 7] = [
+        PoseidonDefaultParametersEntry::new(2, 7, 8, 22, 0),
+        PoseidonDefaultParametersEntry::new(3, 7, 8, 22, 0),
+        PoseidonDefaultParametersEntry::new(4, 7, 8, 22, 0),
+        PoseidonDefaultParametersEntry::new(5, 7, 8, 22, 0),
+        PoseidonDefaultParametersEntry::new(6, 7, 8, 22, 0),
+        PoseidonDefaultParametersEntry::new(7, 7, 8, 22, 0),
+        PoseidonDefaultParametersEntry::new(8, 7, 8, 22, 0),
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FftField, Field, LegendreSymbol, One, PoseidonDefaultField, PrimeField, SquareRootField, Zero};
+    use snarkvm_utilities::{
+        rand::{TestRng, Uniform},
+        FromBytes,
+        ToBytes,
+    };
+
+    use rand::Rng;
+    use std::str::FromStr;
+
+    const ITERATIONS: usize = 10_000;
+
+    /// The Goldilocks modulus.
+    const Q: u128 = 0xffffffff00000001;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut rng = TestRng::default();
+
+        // Check the arithmetic against 128-bit integer arithmetic, including the boundaries of the field.
+        let mut values = vec![0u64, 1, 2, (Q - 2) as u64, (Q - 1) as u64];
+        values.extend((0..ITERATIONS).map(|_| rng.gen_range(0..Q as u64)));
+
+        for (a, b) in values.iter().zip(values.iter().rev()) {
+            let (x, y) = (Goldilocks::from(*a), Goldilocks::from(*b));
+            let (a, b) = (u128::from(*a), u128::from(*b));
+
+            assert_eq!(x + y, Goldilocks::from(((a + b) % Q) as u64));
+            assert_eq!(x - y, Goldilocks::from(((a + Q - b) % Q) as u64));
+            assert_eq!(x * y, Goldilocks::from((a * b % Q) as u64));
+            assert_eq!(x.square(), Goldilocks::from((a * a % Q) as u64));
+            assert_eq!(x.double(), Goldilocks::from((2 * a % Q) as u64));
+            assert_eq!(-x, Goldilocks::from(((Q - a) % Q) as u64));
+            assert_eq!(x.to_bigint().0[0] as u128, a);
+
+            match x.inverse() {
+                Some(inverse) => assert_eq!(x * inverse, Goldilocks::one()),
+                None => assert!(x.is_zero()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a = Goldilocks::rand(&mut rng);
+            let square = a.square();
+            let expected = if a.is_zero() { LegendreSymbol::Zero } else { LegendreSymbol::QuadraticResidue };
+            assert_eq!(square.legendre(), expected);
+            let root = square.sqrt().unwrap();
+            assert!(root == a || root == -a);
+        }
+        // The generator is a quadratic nonresidue.
+        assert_eq!(Goldilocks::multiplicative_generator().legendre(), LegendreSymbol::QuadraticNonResidue);
+        assert!(Goldilocks::multiplicative_generator().sqrt().is_none());
+    }
+
+    #[test]
+    fn test_powers_of_root_of_unity() {
+        // Ensure the expected powers of the root of unity match.
+        let powers = GoldilocksParameters::POWERS_OF_ROOTS_OF_UNITY;
+        assert_eq!(powers.len(), (GoldilocksParameters::TWO_ADICITY - 1) as usize);
+
+        let mut expected = Goldilocks::two_adic_root_of_unity();
+        for candidate in powers {
+            assert_eq!(&expected.0, candidate);
+            expected.square_in_place();
+        }
+        // Ensure the root of unity has order exactly 2^32.
+        assert_eq!(expected, -Goldilocks::one());
+        assert_eq!(expected.square(), Goldilocks::one());
+    }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let expected = Goldilocks::multiplicative_generator().pow(GoldilocksParameters::T);
+        assert_eq!(expected, Goldilocks::two_adic_root_of_unity());
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let a = Goldilocks::rand(&mut rng);
+            // Check the byte representation.
+            let bytes = a.to_bytes_le().unwrap();
+            assert_eq!(bytes.len(), 8);
+            assert_eq!(a, Goldilocks::read_le(&bytes[..]).unwrap());
+            // Check the string representation.
+            assert_eq!(a, Goldilocks::from_str(&a.to_string()).unwrap());
+        }
+        // Ensure the modulus is not a valid encoding.
+        assert!(Goldilocks::read_le(&(Q as u64).to_le_bytes()[..]).is_err());
+        assert!(Goldilocks::from_str(&Q.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_poseidon_parameters() {
+        let parameters = Goldilocks::default_poseidon_parameters::<4>().unwrap();
+        assert_eq!(parameters.alpha, 7);
+        assert_eq!(parameters.ark.len(), 30);
+        assert_eq!(parameters.mds.len(), 5);
+    }
+}
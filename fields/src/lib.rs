@@ -27,6 +27,9 @@ mod macros;
 pub mod errors;
 pub use errors::*;
 
+mod fp_64;
+pub use fp_64::*;
+
 mod fp_256;
 pub use fp_256::*;
 
@@ -41,6 +44,9 @@ pub mod fp6_3over2;
 mod fp12_2over3over2;
 pub use fp12_2over3over2::*;
 
+mod goldilocks;
+pub use goldilocks::*;
+
 mod legendre;
 pub use legendre::*;
 
@@ -57,9 +63,11 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
+impl_field_to_biginteger!(Fp64, BigInteger64, Fp64Parameters);
 impl_field_to_biginteger!(Fp256, BigInteger256, Fp256Parameters);
 impl_field_to_biginteger!(Fp384, BigInteger384, Fp384Parameters);
 
+impl_primefield_serializer!(Fp64, Fp64Parameters, 8);
 impl_primefield_serializer!(Fp256, Fp256Parameters, 32);
 impl_primefield_serializer!(Fp384, Fp384Parameters, 48);
 
@@ -94,7 +94,11 @@ macro_rules! sqrt_impl {
                 let v = $self.pow($P::T_MINUS_ONE_DIV_TWO);
                 let x = *$self * v.square();
 
-                let k = ((n - 1) as f64).sqrt().floor() as u64;
+                // Compute `k` as `floor(sqrt(n - 1))`, using integer arithmetic.
+                let mut k = 0u64;
+                while (k + 1) * (k + 1) < n {
+                    k += 1;
+                }
                 // It's important that k_2 results in a number which makes `l_minus_one_times_k`
                 // divisible by `k`, because the native arithmetic will not match the field
                 // arithmetic otherwise (native numbers will divide and round down, but field
@@ -280,6 +284,7 @@ macro_rules! impl_primefield_serializer {
         }
 
         impl<P: $params> snarkvm_utilities::Valid for $field<P> {
+            /// Field elements are range-checked while decoding, so there is nothing further to check.
             fn check(&self) -> Result<(), snarkvm_utilities::SerializationError> {
                 Ok(())
             }
@@ -20,11 +20,20 @@ impl<N: Network> FromBytes for Header<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid header version"));
+        // Read the block header, based on the version.
+        match version {
+            1 => Self::read_le_v1(reader),
+            version if version > Self::VERSION => {
+                Err(error(format!("Unsupported header version ({version}), the latest is {}", Self::VERSION)))
+            }
+            _ => Err(error(format!("Invalid header version ({version})"))),
         }
+    }
+}
 
+impl<N: Network> Header<N> {
+    /// Reads a version 1 block header from the buffer, following the version byte.
+    fn read_le_v1<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read from the buffer.
         let previous_state_root = N::StateRoot::read_le(&mut reader)?;
         let transactions_root = Field::<N>::read_le(&mut reader)?;
@@ -53,7 +62,7 @@ impl<N: Network> ToBytes for Header<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::VERSION.write_le(&mut writer)?;
 
         // Write to the buffer.
         self.previous_state_root.write_le(&mut writer)?;
@@ -85,4 +94,19 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_version() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let mut bytes = crate::header::test_helpers::sample_block_header(rng).to_bytes_le()?;
+        assert_eq!(bytes[0], Header::<CurrentNetwork>::VERSION);
+
+        // Ensure unknown versions are rejected.
+        for version in [0, Header::<CurrentNetwork>::VERSION + 1, u8::MAX] {
+            bytes[0] = version;
+            assert!(Header::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        }
+        Ok(())
+    }
 }
@@ -62,6 +62,16 @@ impl<N: Network> Header<N> {
         }
     }
 
+    /// Returns `true` if the given Merkle path proves the transaction ID is included in the transactions root.
+    /// This check only requires the block header, so it can be performed with a header chain alone.
+    pub fn verify_transaction_inclusion(
+        &self,
+        transaction_id: &N::TransactionID,
+        transactions_path: &TransactionsPath<N>,
+    ) -> bool {
+        N::verify_merkle_path_bhp(transactions_path, &self.transactions_root, &transaction_id.to_bits_le())
+    }
+
     /// Returns an instance of the Merkle tree for the block header.
     pub fn to_tree(&self) -> Result<HeaderTree<N>> {
         // Determine the number of leaves.
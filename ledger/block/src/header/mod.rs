@@ -25,7 +25,7 @@ mod verify;
 use crate::{Ratifications, Transactions};
 use console::{
     network::prelude::*,
-    program::{HeaderLeaf, HeaderPath, HeaderTree, HEADER_DEPTH},
+    program::{HeaderLeaf, HeaderPath, HeaderTree, TransactionsPath, HEADER_DEPTH},
     types::Field,
 };
 use synthesizer_program::FinalizeOperation;
@@ -50,6 +50,12 @@ pub struct Header<N: Network> {
 }
 
 impl<N: Network> Header<N> {
+    /// The latest serialization version of a block header.
+    ///
+    /// As with transactions, a reader rejects any version it does not know, and each new format
+    /// is added as a new version alongside the readers for the prior versions.
+    pub const VERSION: u8 = 1;
+
     /// Initializes a new block header with the given inputs.
     pub fn from(
         previous_state_root: N::StateRoot,
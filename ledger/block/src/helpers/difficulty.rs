@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::target::{coinbase_target, proof_target, retarget};
+use crate::Header;
+use console::{
+    network::Network,
+    prelude::{ensure, Result},
+};
+
+use core::marker::PhantomData;
+
+/// A strategy for retargeting the coinbase target and proof target of the next block.
+pub trait DifficultyTarget<N: Network>: Send + Sync {
+    /// Returns the coinbase target for the next block, given the previous block header and the next block timestamp.
+    fn coinbase_target(&self, previous_header: &Header<N>, timestamp: i64) -> Result<u64>;
+
+    /// Returns the proof target for the given coinbase target.
+    fn proof_target(&self, coinbase_target: u64) -> u64 {
+        proof_target(coinbase_target, N::GENESIS_PROOF_TARGET)
+    }
+}
+
+/// The anchor retargeting rule, which retargets from the last coinbase target and timestamp,
+/// expecting the coinbase target to be reached every `N::ANCHOR_TIME` seconds,
+/// with a half-life of half an epoch.
+#[derive(Copy, Clone, Debug)]
+pub struct AnchorTarget<N: Network>(PhantomData<N>);
+
+impl<N: Network> Default for AnchorTarget<N> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<N: Network> DifficultyTarget<N> for AnchorTarget<N> {
+    fn coinbase_target(&self, previous_header: &Header<N>, timestamp: i64) -> Result<u64> {
+        coinbase_target(
+            previous_header.last_coinbase_target(),
+            previous_header.last_coinbase_timestamp(),
+            timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )
+    }
+}
+
+/// A responsive ASERT retargeting rule, which retargets on every block from the previous block's
+/// coinbase target and timestamp, expecting a block every `anchor_time` seconds.
+#[derive(Copy, Clone, Debug)]
+pub struct AsertTarget<N: Network> {
+    /// The expected number of seconds between blocks.
+    anchor_time: u16,
+    /// The number of seconds for the target to double (or halve).
+    half_life: u32,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> AsertTarget<N> {
+    /// Initializes a new ASERT rule with the given anchor time and half-life (in seconds).
+    pub fn new(anchor_time: u16, half_life: u32) -> Result<Self> {
+        // Ensure the anchor time and half-life are nonzero.
+        ensure!(anchor_time > 0, "The anchor time must be nonzero");
+        ensure!(half_life > 0, "The half-life must be nonzero");
+        Ok(Self { anchor_time, half_life, _phantom: PhantomData })
+    }
+
+    /// Returns the expected number of seconds between blocks.
+    pub const fn anchor_time(&self) -> u16 {
+        self.anchor_time
+    }
+
+    /// Returns the number of seconds for the target to double (or halve).
+    pub const fn half_life(&self) -> u32 {
+        self.half_life
+    }
+}
+
+impl<N: Network> Default for AsertTarget<N> {
+    /// Returns the ASERT rule for the network block time, with a half-life of a tenth of an epoch.
+    fn default() -> Self {
+        let half_life = (N::NUM_BLOCKS_PER_EPOCH / 10).max(1).saturating_mul(N::BLOCK_TIME as u32);
+        Self { anchor_time: N::BLOCK_TIME, half_life, _phantom: PhantomData }
+    }
+}
+
+impl<N: Network> DifficultyTarget<N> for AsertTarget<N> {
+    fn coinbase_target(&self, previous_header: &Header<N>, timestamp: i64) -> Result<u64> {
+        // Compute the new coinbase target.
+        let candidate_target = retarget(
+            previous_header.coinbase_target(),
+            previous_header.timestamp(),
+            timestamp,
+            self.anchor_time,
+            self.half_life,
+            true,
+        )?;
+        // Return the new coinbase target, floored at the genesis target.
+        Ok(candidate_target.max(N::GENESIS_COINBASE_TARGET))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::{prelude::*, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_anchor_target_matches_coinbase_target() {
+        let rng = &mut TestRng::default();
+
+        let header = *crate::test_helpers::sample_genesis_block(rng).header();
+        let difficulty_target = AnchorTarget::<CurrentNetwork>::default();
+
+        for elapsed in [1, CurrentNetwork::ANCHOR_TIME as i64, 1000] {
+            let timestamp = header.last_coinbase_timestamp() + elapsed;
+            let expected = coinbase_target(
+                header.last_coinbase_target(),
+                header.last_coinbase_timestamp(),
+                timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+            assert_eq!(difficulty_target.coinbase_target(&header, timestamp).unwrap(), expected);
+            assert_eq!(
+                difficulty_target.proof_target(expected),
+                proof_target(expected, CurrentNetwork::GENESIS_PROOF_TARGET)
+            );
+        }
+    }
+
+    #[test]
+    fn test_asert_target() {
+        let rng = &mut TestRng::default();
+
+        let header = *crate::test_helpers::sample_genesis_block(rng).header();
+        let difficulty_target = AsertTarget::<CurrentNetwork>::new(10, 100).unwrap();
+        assert!(AsertTarget::<CurrentNetwork>::new(0, 100).is_err());
+        assert!(AsertTarget::<CurrentNetwork>::new(10, 0).is_err());
+
+        // Ensure the target is unchanged when the block arrives on time.
+        let on_time = difficulty_target.coinbase_target(&header, header.timestamp() + 10).unwrap();
+        assert_eq!(on_time, header.coinbase_target());
+
+        // Ensure the target increases when the block arrives early.
+        let early = difficulty_target.coinbase_target(&header, header.timestamp() + 1).unwrap();
+        assert!(early > on_time);
+
+        // Ensure the target decreases when the block arrives late, floored at the genesis target.
+        let late = difficulty_target.coinbase_target(&header, header.timestamp() + 1000).unwrap();
+        assert!(late <= on_time);
+        assert!(late >= CurrentNetwork::GENESIS_COINBASE_TARGET);
+    }
+}
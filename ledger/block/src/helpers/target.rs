@@ -143,7 +143,7 @@ pub fn proof_target(coinbase_target: u64, genesis_proof_target: u64) -> u64 {
 ///     A = Anchor timestamp, defined as expected number of seconds elapsed.
 ///     TAU = Rate of doubling (or half-life) in seconds.
 ///     INV = {-1, 1} depending on whether the target is increasing or decreasing.
-fn retarget(
+pub(crate) fn retarget(
     previous_target: u64,
     previous_block_timestamp: i64,
     block_timestamp: i64,
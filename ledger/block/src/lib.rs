@@ -47,7 +47,7 @@ mod verify;
 use console::{
     account::PrivateKey,
     network::prelude::*,
-    program::{Ciphertext, Record},
+    program::{Ciphertext, Record, TransactionsPath},
     types::{Field, Group, U64},
 };
 use ledger_authority::Authority;
@@ -418,6 +418,14 @@ impl<N: Network> Block<N> {
     }
 }
 
+impl<N: Network> Block<N> {
+    /// Returns the Merkle path of the given transaction ID to the transactions root in the block header,
+    /// which can be checked with `Header::verify_transaction_inclusion`.
+    pub fn transaction_inclusion_proof(&self, transaction_id: &N::TransactionID) -> Result<TransactionsPath<N>> {
+        self.transactions.to_path(*transaction_id)
+    }
+}
+
 impl<N: Network> Block<N> {
     /// Returns the puzzle commitments in this block.
     pub fn puzzle_commitments(&self) -> Option<impl '_ + Iterator<Item = &PuzzleCommitment<N>>> {
@@ -662,6 +670,23 @@ mod tests {
 
     use indexmap::IndexMap;
 
+    #[test]
+    fn test_transaction_inclusion_proof() {
+        let rng = &mut TestRng::default();
+
+        let (block, transaction) = crate::test_helpers::sample_genesis_block_and_transaction(rng);
+
+        // Ensure the inclusion proof verifies against the block header.
+        let path = block.transaction_inclusion_proof(&transaction.id()).unwrap();
+        assert!(block.header().verify_transaction_inclusion(&transaction.id(), &path));
+
+        // Ensure the inclusion proof does not verify for a different transaction ID.
+        let transaction_id = rng.gen();
+        assert!(!block.header().verify_transaction_inclusion(&transaction_id, &path));
+        // Ensure an inclusion proof is not produced for a transaction that is not in the block.
+        assert!(block.transaction_inclusion_proof(&transaction_id).is_err());
+    }
+
     #[test]
     fn test_find_transaction_for_transition_id() {
         let rng = &mut TestRng::default();
@@ -20,11 +20,20 @@ impl<N: Network> FromBytes for Transaction<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
-            return Err(error("Invalid transaction version"));
+        // Read the transaction, based on the version.
+        match version {
+            1 => Self::read_le_v1(reader),
+            version if version > Self::VERSION => {
+                Err(error(format!("Unsupported transaction version ({version}), the latest is {}", Self::VERSION)))
+            }
+            _ => Err(error(format!("Invalid transaction version ({version})"))),
         }
+    }
+}
 
+impl<N: Network> Transaction<N> {
+    /// Reads a version 1 transaction from the buffer, following the version byte.
+    fn read_le_v1<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the variant.
         let variant = u8::read_le(&mut reader)?;
         // Match the variant.
@@ -92,7 +101,7 @@ impl<N: Network> ToBytes for Transaction<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        Self::VERSION.write_le(&mut writer)?;
 
         // Write the transaction.
         match self {
@@ -139,6 +148,9 @@ impl<N: Network> ToBytes for Transaction<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
 
     #[test]
     fn test_bytes() -> Result<()> {
@@ -158,4 +170,20 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_version() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let mut bytes = transaction.to_bytes_le()?;
+        assert_eq!(bytes[0], Transaction::<CurrentNetwork>::VERSION);
+
+        // Ensure unknown versions are rejected.
+        for version in [0, Transaction::<CurrentNetwork>::VERSION + 1, u8::MAX] {
+            bytes[0] = version;
+            assert!(Transaction::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        }
+        Ok(())
+    }
 }
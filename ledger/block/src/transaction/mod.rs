@@ -26,12 +26,25 @@ mod merkle;
 mod serialize;
 mod string;
 
+mod summary;
+pub use summary::TransactionSummary;
+
 use crate::Transition;
 use console::{
     network::prelude::*,
-    program::{Ciphertext, ProgramOwner, Record, TransactionLeaf, TransactionPath, TransactionTree, TRANSACTION_DEPTH},
-    types::{Field, Group, U64},
+    program::{
+        Ciphertext,
+        ProgramID,
+        ProgramOwner,
+        Record,
+        TransactionLeaf,
+        TransactionPath,
+        TransactionTree,
+        TRANSACTION_DEPTH,
+    },
+    types::{Address, Field, Group, U64},
 };
+use synthesizer_snark::Proof;
 
 #[derive(Clone, PartialEq, Eq)]
 pub enum Transaction<N: Network> {
@@ -44,6 +57,13 @@ pub enum Transaction<N: Network> {
 }
 
 impl<N: Network> Transaction<N> {
+    /// The latest serialization version of a transaction.
+    ///
+    /// A reader rejects any version it does not know, so that a change to the transaction format
+    /// fails loudly on older parsers instead of being misread. A new format must be introduced as
+    /// a new version with its own reader, while the readers for prior versions are kept intact.
+    pub const VERSION: u8 = 1;
+
     /// Initializes a new deployment transaction.
     pub fn from_deployment(owner: ProgramOwner<N>, deployment: Deployment<N>, fee: Fee<N>) -> Result<Self> {
         // Ensure the transaction is not empty.
@@ -110,6 +130,17 @@ impl<N: Network> Transaction<N> {
             _ => false,
         }
     }
+
+    /// Returns `true` if this transaction moves credits into or out of the public `account` mapping.
+    #[inline]
+    pub fn contains_public_transfer(&self) -> bool {
+        match self {
+            // Case 1 - The transaction contains a transition that calls a public transfer in 'credits.aleo'.
+            Transaction::Execute(_, execution, _) => execution.transitions().any(Transition::is_public_transfer),
+            // Otherwise, return 'false'.
+            _ => false,
+        }
+    }
 }
 
 impl<N: Network> Transaction<N> {
@@ -212,6 +243,16 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Returns the fee payer, if the transaction pays a public fee.
+    pub fn fee_payer(&self) -> Option<Address<N>> {
+        match self {
+            Self::Deploy(_, _, _, fee) => fee.payer(),
+            Self::Execute(_, _, Some(fee)) => fee.payer(),
+            Self::Execute(_, _, None) => None,
+            Self::Fee(_, fee) => fee.payer(),
+        }
+    }
+
     /// Returns the fee transition.
     pub fn fee_transition(&self) -> Option<Fee<N>> {
         match self {
@@ -222,6 +263,33 @@ impl<N: Network> Transaction<N> {
     }
 }
 
+impl<N: Network> Transaction<N> {
+    /// Returns an iterator over the global state roots that the execution and fee were proven against.
+    pub fn global_state_roots(&self) -> impl '_ + Iterator<Item = N::StateRoot> {
+        let (execution, fee) = match self {
+            Self::Deploy(_, _, _, fee) => (None, Some(fee)),
+            Self::Execute(_, execution, fee) => (Some(execution), fee.as_ref()),
+            Self::Fee(_, fee) => (None, Some(fee)),
+        };
+        execution.map(Execution::global_state_root).into_iter().chain(fee.map(Fee::global_state_root))
+    }
+
+    /// Returns an iterator over the proofs of the execution and fee.
+    pub fn proofs(&self) -> impl '_ + Iterator<Item = &Proof<N>> {
+        let (execution, fee) = match self {
+            Self::Deploy(_, _, _, fee) => (None, Some(fee)),
+            Self::Execute(_, execution, fee) => (Some(execution), fee.as_ref()),
+            Self::Fee(_, fee) => (None, Some(fee)),
+        };
+        execution.and_then(Execution::proof).into_iter().chain(fee.and_then(Fee::proof))
+    }
+
+    /// Returns a summary of the transaction.
+    pub fn to_summary(&self) -> Result<TransactionSummary<N>> {
+        TransactionSummary::new(self)
+    }
+}
+
 impl<N: Network> Transaction<N> {
     /// Returns `true` if the transaction contains the given transition ID.
     pub fn contains_transition(&self, transition_id: &N::TransitionID) -> bool {
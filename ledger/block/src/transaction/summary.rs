@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A summary of a transaction, for explorers and indexers.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransactionSummary<N: Network> {
+    /// The transaction ID.
+    id: N::TransactionID,
+    /// The transaction type, as one of `deploy`, `execute`, or `fee`.
+    transaction_type: &'static str,
+    /// The program IDs of the transitions.
+    program_ids: Vec<ProgramID<N>>,
+    /// The transition IDs.
+    transition_ids: Vec<N::TransitionID>,
+    /// The serial numbers of the input records.
+    serial_numbers: Vec<Field<N>>,
+    /// The commitments of the output records.
+    commitments: Vec<Field<N>>,
+    /// The global state roots that the execution and fee were proven against.
+    global_state_roots: Vec<N::StateRoot>,
+    /// The total fee, in microcredits.
+    fee_amount: u64,
+    /// The number of proofs.
+    num_proofs: usize,
+}
+
+impl<N: Network> TransactionSummary<N> {
+    /// Initializes a new summary of the given transaction.
+    pub fn new(transaction: &Transaction<N>) -> Result<Self> {
+        let transaction_type = match transaction {
+            Transaction::Deploy(..) => "deploy",
+            Transaction::Execute(..) => "execute",
+            Transaction::Fee(..) => "fee",
+        };
+        Ok(Self {
+            id: transaction.id(),
+            transaction_type,
+            program_ids: transaction.transitions().map(|transition| *transition.program_id()).collect(),
+            transition_ids: transaction.transition_ids().copied().collect(),
+            serial_numbers: transaction.serial_numbers().copied().collect(),
+            commitments: transaction.commitments().copied().collect(),
+            global_state_roots: transaction.global_state_roots().collect(),
+            fee_amount: *transaction.fee_amount()?,
+            num_proofs: transaction.proofs().count(),
+        })
+    }
+
+    /// Returns the transaction ID.
+    pub const fn id(&self) -> N::TransactionID {
+        self.id
+    }
+
+    /// Returns the transaction type, as one of `deploy`, `execute`, or `fee`.
+    pub const fn transaction_type(&self) -> &'static str {
+        self.transaction_type
+    }
+
+    /// Returns the program IDs of the transitions.
+    pub fn program_ids(&self) -> &[ProgramID<N>] {
+        &self.program_ids
+    }
+
+    /// Returns the transition IDs.
+    pub fn transition_ids(&self) -> &[N::TransitionID] {
+        &self.transition_ids
+    }
+
+    /// Returns the serial numbers of the input records.
+    pub fn serial_numbers(&self) -> &[Field<N>] {
+        &self.serial_numbers
+    }
+
+    /// Returns the commitments of the output records.
+    pub fn commitments(&self) -> &[Field<N>] {
+        &self.commitments
+    }
+
+    /// Returns the global state roots that the execution and fee were proven against.
+    pub fn global_state_roots(&self) -> &[N::StateRoot] {
+        &self.global_state_roots
+    }
+
+    /// Returns the total fee, in microcredits.
+    pub const fn fee_amount(&self) -> u64 {
+        self.fee_amount
+    }
+
+    /// Returns the number of proofs.
+    pub const fn num_proofs(&self) -> usize {
+        self.num_proofs
+    }
+}
+
+impl<N: Network> Serialize for TransactionSummary<N> {
+    /// Serializes the transaction summary to a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut summary = serializer.serialize_struct("TransactionSummary", 9)?;
+        summary.serialize_field("id", &self.id)?;
+        summary.serialize_field("type", self.transaction_type)?;
+        summary.serialize_field("program_ids", &self.program_ids)?;
+        summary.serialize_field("transition_ids", &self.transition_ids)?;
+        summary.serialize_field("serial_numbers", &self.serial_numbers)?;
+        summary.serialize_field("commitments", &self.commitments)?;
+        summary.serialize_field("global_state_roots", &self.global_state_roots)?;
+        summary.serialize_field("fee_amount", &self.fee_amount)?;
+        summary.serialize_field("num_proofs", &self.num_proofs)?;
+        summary.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TransactionSummary<N> {
+    /// Deserializes the transaction summary from a JSON-string.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Deserialize the transaction summary into a JSON value.
+        let mut summary = serde_json::Value::deserialize(deserializer)?;
+
+        // Recover the transaction type.
+        let transaction_type = match summary
+            .get("type")
+            .ok_or_else(|| de::Error::custom("The \"type\" field is missing"))?
+            .as_str()
+        {
+            Some("deploy") => "deploy",
+            Some("execute") => "execute",
+            Some("fee") => "fee",
+            _ => return Err(de::Error::custom("Invalid transaction type")),
+        };
+
+        // Recover the transaction summary.
+        Ok(Self {
+            id: DeserializeExt::take_from_value::<D>(&mut summary, "id")?,
+            transaction_type,
+            program_ids: DeserializeExt::take_from_value::<D>(&mut summary, "program_ids")?,
+            transition_ids: DeserializeExt::take_from_value::<D>(&mut summary, "transition_ids")?,
+            serial_numbers: DeserializeExt::take_from_value::<D>(&mut summary, "serial_numbers")?,
+            commitments: DeserializeExt::take_from_value::<D>(&mut summary, "commitments")?,
+            global_state_roots: DeserializeExt::take_from_value::<D>(&mut summary, "global_state_roots")?,
+            fee_amount: DeserializeExt::take_from_value::<D>(&mut summary, "fee_amount")?,
+            num_proofs: DeserializeExt::take_from_value::<D>(&mut summary, "num_proofs")?,
+        })
+    }
+}
+
+impl<N: Network> Debug for TransactionSummary<N> {
+    /// Prints the transaction summary as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for TransactionSummary<N> {
+    /// Displays the transaction summary as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_summary() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let summary = transaction.to_summary()?;
+            assert_eq!(summary.id(), transaction.id());
+            assert_eq!(summary.transition_ids(), transaction.transition_ids().copied().collect::<Vec<_>>());
+            assert_eq!(summary.serial_numbers(), transaction.serial_numbers().copied().collect::<Vec<_>>());
+            assert_eq!(summary.commitments(), transaction.commitments().copied().collect::<Vec<_>>());
+            assert_eq!(summary.fee_amount(), *transaction.fee_amount()?);
+            assert_eq!(summary.num_proofs(), transaction.proofs().count());
+            assert!(!summary.global_state_roots().is_empty());
+
+            // Ensure the summary serializes to JSON.
+            let json: serde_json::Value = serde_json::from_str(&summary.to_string())?;
+            assert_eq!(json["id"], serde_json::to_value(transaction.id())?);
+            assert_eq!(json["type"], summary.transaction_type());
+
+            // Ensure the summary round-trips through JSON.
+            assert_eq!(serde_json::from_str::<TransactionSummary<_>>(&summary.to_string())?, summary);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_summary_deserialize_fails() {
+        // Ensure a summary without a known transaction type is rejected.
+        assert!(serde_json::from_str::<TransactionSummary<CurrentNetwork>>("{}").is_err());
+        assert!(serde_json::from_str::<TransactionSummary<CurrentNetwork>>(r#"{"type":"transfer"}"#).is_err());
+    }
+}
@@ -97,6 +97,24 @@ impl<N: Network> Transactions<N> {
         cfg_values!(self.transactions).filter(|tx| tx.is_rejected()).count()
     }
 
+    /// Returns the total fee amount (in microcredits), for all transactions.
+    pub fn total_fee_amount(&self) -> Result<U64<N>> {
+        self.transaction_fee_amounts().try_fold(U64::zero(), |total, amount| match total.checked_add(*amount?) {
+            Some(total) => Ok(U64::new(total)),
+            None => bail!("The total fee amount overflowed"),
+        })
+    }
+
+    /// Returns the total priority fee amount (in microcredits), for all transactions.
+    pub fn total_priority_fee_amount(&self) -> Result<U64<N>> {
+        self.transaction_priority_fee_amounts().try_fold(U64::zero(), |total, amount| {
+            match total.checked_add(*amount?) {
+                Some(total) => Ok(U64::new(total)),
+                None => bail!("The total priority fee amount overflowed"),
+            }
+        })
+    }
+
     /// Returns the number of finalize operations.
     pub fn num_finalize(&self) -> usize {
         cfg_values!(self.transactions).map(|tx| tx.num_finalize()).sum()
@@ -255,6 +273,11 @@ impl<N: Network> Transactions<N> {
         self.iter().map(|tx| tx.fee_amount())
     }
 
+    /// Returns an iterator over the transaction priority fee amounts, for all transactions.
+    pub fn transaction_priority_fee_amounts(&self) -> impl '_ + Iterator<Item = Result<U64<N>>> {
+        self.iter().map(|tx| tx.priority_fee_amount())
+    }
+
     /// Returns an iterator over the finalize operations, for all transactions.
     pub fn finalize_operations(&self) -> impl '_ + Iterator<Item = &FinalizeOperation<N>> {
         self.iter().flat_map(|tx| tx.finalize_operations())
@@ -346,6 +369,23 @@ mod tests {
 
     type CurrentNetwork = console::network::Testnet3;
 
+    #[test]
+    fn test_total_fee_amount() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block transactions.
+        let transactions = crate::transactions::test_helpers::sample_block_transactions(rng);
+
+        // Compute the expected total fee amounts.
+        let expected_fee = transactions.iter().map(|tx| *tx.fee_amount().unwrap()).sum::<u64>();
+        let expected_priority_fee = transactions.iter().map(|tx| *tx.priority_fee_amount().unwrap()).sum::<u64>();
+
+        // Ensure the total fee amounts match.
+        assert_eq!(*transactions.total_fee_amount().unwrap(), expected_fee);
+        assert_eq!(*transactions.total_priority_fee_amount().unwrap(), expected_priority_fee);
+        assert!(*transactions.total_priority_fee_amount().unwrap() <= *transactions.total_fee_amount().unwrap());
+    }
+
     #[test]
     fn test_max_transactions() {
         assert_eq!(
@@ -29,7 +29,9 @@ use console::{
         Ciphertext,
         Identifier,
         InputID,
+        Literal,
         OutputID,
+        Plaintext,
         ProgramID,
         Record,
         Register,
@@ -42,7 +44,7 @@ use console::{
         ValueType,
         TRANSITION_DEPTH,
     },
-    types::{Field, Group},
+    types::{Field, Group, U64},
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -336,6 +338,57 @@ impl<N: Network> Transition<N> {
             && self.program_id.to_string() == "credits.aleo"
             && self.function_name.to_string() == "split"
     }
+
+    /// Returns `true` if this is a `transfer_public` transition.
+    #[inline]
+    pub fn is_transfer_public(&self) -> bool {
+        self.inputs.len() == 2
+            && self.outputs.len() == 1
+            && self.program_id.to_string() == "credits.aleo"
+            && self.function_name.to_string() == "transfer_public"
+    }
+
+    /// Returns `true` if this is a `transfer_private_to_public` transition.
+    #[inline]
+    pub fn is_transfer_private_to_public(&self) -> bool {
+        self.inputs.len() == 3
+            && self.outputs.len() == 2
+            && self.program_id.to_string() == "credits.aleo"
+            && self.function_name.to_string() == "transfer_private_to_public"
+    }
+
+    /// Returns `true` if this is a `transfer_public_to_private` transition.
+    #[inline]
+    pub fn is_transfer_public_to_private(&self) -> bool {
+        self.inputs.len() == 2
+            && self.outputs.len() == 2
+            && self.program_id.to_string() == "credits.aleo"
+            && self.function_name.to_string() == "transfer_public_to_private"
+    }
+
+    /// Returns `true` if this transition moves credits into or out of the public `account` mapping.
+    #[inline]
+    pub fn is_public_transfer(&self) -> bool {
+        self.is_transfer_public() || self.is_transfer_private_to_public() || self.is_transfer_public_to_private()
+    }
+
+    /// Returns the public amount (in microcredits), if this is a public transfer transition.
+    /// Otherwise, returns `None`.
+    pub fn public_transfer_amount(&self) -> Option<U64<N>> {
+        // Determine the input index of the amount.
+        let amount_index = if self.is_transfer_public() || self.is_transfer_public_to_private() {
+            1
+        } else if self.is_transfer_private_to_public() {
+            2
+        } else {
+            return None;
+        };
+        // Retrieve the amount (in microcredits) as a plaintext value.
+        match self.inputs.get(amount_index) {
+            Some(Input::Public(_, Some(Plaintext::Literal(Literal::U64(microcredits), _)))) => Some(*microcredits),
+            _ => None,
+        }
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -478,3 +531,43 @@ pub mod test_helpers {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_transfer_amount() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition (from the genesis block, this is a `transfer_public_to_private`).
+        let transition = crate::transition::test_helpers::sample_transition(rng);
+        assert!(transition.is_transfer_public_to_private());
+        assert!(transition.is_public_transfer());
+        assert!(!transition.is_transfer_public());
+        assert!(!transition.is_transfer_private_to_public());
+
+        // Ensure the public amount matches the amount in the output record.
+        let amount = transition.public_transfer_amount().unwrap();
+        assert!(!amount.is_zero());
+        match transition.inputs().get(1) {
+            Some(Input::Public(_, Some(Plaintext::Literal(Literal::U64(microcredits), _)))) => {
+                assert_eq!(amount, *microcredits)
+            }
+            _ => panic!("Expected a public amount in the transition"),
+        }
+    }
+
+    #[test]
+    fn test_public_transfer_amount_for_fee() {
+        let rng = &mut TestRng::default();
+
+        // Sample a fee transaction, which does not contain a public transfer.
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        assert!(!transaction.contains_public_transfer());
+        for transition in transaction.transitions() {
+            assert!(!transition.is_public_transfer());
+            assert!(transition.public_transfer_amount().is_none());
+        }
+    }
+}
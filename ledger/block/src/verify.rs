@@ -25,7 +25,7 @@ use std::collections::HashSet;
 use rayon::prelude::*;
 
 impl<N: Network> Block<N> {
-    /// Ensures the block is correct.
+    /// Ensures the block is correct, using the anchor retargeting rule for the coinbase and proof targets.
     pub fn verify(
         &self,
         previous_block: &Block<N>,
@@ -35,6 +35,30 @@ impl<N: Network> Block<N> {
         current_epoch_challenge: &EpochChallenge<N>,
         current_timestamp: i64,
         ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+    ) -> Result<()> {
+        self.verify_with_difficulty_target(
+            previous_block,
+            current_state_root,
+            current_committee,
+            current_puzzle,
+            current_epoch_challenge,
+            current_timestamp,
+            ratified_finalize_operations,
+            &AnchorTarget::default(),
+        )
+    }
+
+    /// Ensures the block is correct, using the given retargeting rule for the coinbase and proof targets.
+    pub fn verify_with_difficulty_target(
+        &self,
+        previous_block: &Block<N>,
+        current_state_root: N::StateRoot,
+        current_committee: &Committee<N>,
+        current_puzzle: &CoinbasePuzzle<N>,
+        current_epoch_challenge: &EpochChallenge<N>,
+        current_timestamp: i64,
+        ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+        difficulty_target: &dyn DifficultyTarget<N>,
     ) -> Result<()> {
         // Ensure the block hash is correct.
         self.verify_hash(previous_block.height(), previous_block.hash())?;
@@ -53,7 +77,7 @@ impl<N: Network> Block<N> {
             expected_last_coinbase_timestamp,
             expected_block_reward,
             expected_puzzle_reward,
-        ) = self.verify_solutions(previous_block, current_puzzle, current_epoch_challenge)?;
+        ) = self.verify_solutions(previous_block, current_puzzle, current_epoch_challenge, difficulty_target)?;
 
         // Ensure the block ratifications are correct.
         self.verify_ratifications(expected_block_reward, expected_puzzle_reward)?;
@@ -130,8 +154,8 @@ impl<N: Network> Block<N> {
         Ok(())
     }
 
-    /// Ensures the block authority is correct.
-    fn verify_authority(
+    /// Ensures the block authority is correct, and returns the expected round, height, and timestamp of the block.
+    pub fn verify_authority(
         &self,
         previous_round: u64,
         previous_height: u32,
@@ -256,6 +280,7 @@ impl<N: Network> Block<N> {
         previous_block: &Block<N>,
         current_puzzle: &CoinbasePuzzle<N>,
         current_epoch_challenge: &EpochChallenge<N>,
+        difficulty_target: &dyn DifficultyTarget<N>,
     ) -> Result<(u128, u128, u64, u64, u64, i64, u64, u64)> {
         let height = self.height();
         let timestamp = self.timestamp();
@@ -322,16 +347,9 @@ impl<N: Network> Block<N> {
         let expected_cumulative_weight = previous_block.cumulative_weight().saturating_add(combined_proof_target);
 
         // Construct the next coinbase target.
-        let expected_coinbase_target = coinbase_target(
-            previous_block.last_coinbase_target(),
-            previous_block.last_coinbase_timestamp(),
-            timestamp,
-            N::ANCHOR_TIME,
-            N::NUM_BLOCKS_PER_EPOCH,
-            N::GENESIS_COINBASE_TARGET,
-        )?;
+        let expected_coinbase_target = difficulty_target.coinbase_target(previous_block.header(), timestamp)?;
         // Ensure the proof target is correct.
-        let expected_proof_target = proof_target(expected_coinbase_target, N::GENESIS_PROOF_TARGET);
+        let expected_proof_target = difficulty_target.proof_target(expected_coinbase_target);
 
         // Determine the expected last coinbase target.
         let expected_last_coinbase_target = match is_coinbase_target_reached {
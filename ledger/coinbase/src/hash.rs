@@ -24,13 +24,16 @@ use blake2::Digest;
 use rayon::prelude::*;
 
 pub fn hash_to_coefficients<F: PrimeField>(input: &[u8], num_coefficients: u32) -> Vec<F> {
-    // Hash the input.
-    let hash = blake2::Blake2s256::digest(input);
+    // Hash the input, and expand the hash into coefficients.
+    digest_to_coefficients(&blake2::Blake2s256::digest(input).into(), num_coefficients)
+}
+
+pub fn digest_to_coefficients<F: PrimeField>(hash: &[u8; 32], num_coefficients: u32) -> Vec<F> {
     // Hash with a counter and return the coefficients.
     cfg_into_iter!(0..num_coefficients)
         .map(|counter| {
             let mut input_with_counter = [0u8; 36];
-            input_with_counter[..32].copy_from_slice(&hash);
+            input_with_counter[..32].copy_from_slice(hash);
             input_with_counter[32..].copy_from_slice(&counter.to_le_bytes());
             F::from_bytes_le_mod_order(&blake2::Blake2b512::digest(input_with_counter))
         })
@@ -44,6 +47,13 @@ pub fn hash_to_polynomial<F: PrimeField>(input: &[u8], degree: u32) -> DensePoly
     DensePolynomial::from_coefficients_vec(coefficients)
 }
 
+pub fn digest_to_polynomial<F: PrimeField>(hash: &[u8; 32], degree: u32) -> DensePolynomial<F> {
+    // Expand the hash into coefficients.
+    let coefficients = digest_to_coefficients(hash, degree + 1);
+    // Construct the polynomial from the coefficients.
+    DensePolynomial::from_coefficients_vec(coefficients)
+}
+
 pub fn hash_commitment<E: PairingEngine>(commitment: &KZGCommitment<E>) -> Result<E::Fr> {
     // Convert the commitment into bytes.
     let mut bytes = Vec::with_capacity(96);
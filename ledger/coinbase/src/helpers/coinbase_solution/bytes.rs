@@ -19,6 +19,13 @@ impl<N: Network> FromBytes for CoinbaseSolution<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the number of solutions.
         let num_solutions: u16 = FromBytes::read_le(&mut reader)?;
+        // Ensure the number of solutions is within bounds.
+        if num_solutions as usize > N::MAX_SOLUTIONS {
+            return Err(error(format!(
+                "Number of solutions ({num_solutions}) exceeds the maximum ({})",
+                N::MAX_SOLUTIONS
+            )));
+        }
         // Read the solutions.
         let mut prover_solutions = Vec::with_capacity(num_solutions as usize);
         for _ in 0..num_solutions {
@@ -46,6 +53,8 @@ impl<N: Network> ToBytes for CoinbaseSolution<N> {
 mod tests {
     use super::*;
 
+    type CurrentNetwork = console::network::Testnet3;
+
     #[test]
     fn test_bytes() -> Result<()> {
         let mut rng = TestRng::default();
@@ -58,4 +67,13 @@ mod tests {
         assert_eq!(expected, CoinbaseSolution::read_le(&expected_bytes[..])?);
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_exceeds_max_solutions() {
+        // Write a number of solutions that exceeds the maximum.
+        let bytes = u16::try_from(CurrentNetwork::MAX_SOLUTIONS + 1).unwrap().to_bytes_le().unwrap();
+        // Ensure the solutions are rejected.
+        let error = CoinbaseSolution::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+        assert!(error.to_string().contains("exceeds the maximum"));
+    }
 }
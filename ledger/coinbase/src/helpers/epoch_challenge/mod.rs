@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bytes;
+mod serialize;
 
 use super::*;
 use crate::hash_to_polynomial;
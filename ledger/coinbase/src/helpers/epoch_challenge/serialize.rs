@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for EpochChallenge<N> {
+    /// Serializes the epoch challenge to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut epoch_challenge = serializer.serialize_struct("EpochChallenge", 3)?;
+                epoch_challenge.serialize_field("epoch_number", &self.epoch_number)?;
+                epoch_challenge.serialize_field("epoch_block_hash", &self.epoch_block_hash)?;
+                epoch_challenge.serialize_field("degree", &self.degree())?;
+                epoch_challenge.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for EpochChallenge<N> {
+    /// Deserializes the epoch challenge from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut epoch_challenge = serde_json::Value::deserialize(deserializer)?;
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut epoch_challenge, "epoch_number")?,
+                    DeserializeExt::take_from_value::<D>(&mut epoch_challenge, "epoch_block_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut epoch_challenge, "degree")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "epoch challenge"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new epoch challenge.
+        let degree: u16 = rng.gen(); // Bound the maximal test degree to 2^16.
+        let expected = EpochChallenge::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), degree as u32)?;
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(serde_json::to_value(&expected)?["degree"], serde_json::json!(expected.degree()));
+
+        // Deserialize
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new epoch challenge.
+        let degree: u16 = rng.gen(); // Bound the maximal test degree to 2^16.
+        let expected = EpochChallenge::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), degree as u32)?;
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, EpochChallenge::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::ops::Range;
+
+/// The outcome of a share that was accepted by a mining pool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShareKind {
+    /// The share meets the share target, but not the proof target.
+    Share,
+    /// The share also meets the proof target, and may be submitted to the network as a prover solution.
+    Solution,
+}
+
+/// A mining pool, which assigns nonce ranges to its provers, and accepts prover solutions
+/// for the pool address that meet a lower share target as shares.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MiningPool<N: Network> {
+    /// The address of the pool, to which the prover solutions are bound.
+    address: Address<N>,
+    /// The minimum target of a share.
+    share_target: u64,
+}
+
+impl<N: Network> MiningPool<N> {
+    /// Initializes a new mining pool with the given address and share target.
+    pub fn new(address: Address<N>, share_target: u64) -> Result<Self> {
+        // Ensure the share target is nonzero.
+        ensure!(share_target > 0, "The share target must be nonzero");
+        Ok(Self { address, share_target })
+    }
+
+    /// Initializes a new mining pool, with a share target that is `share_factor` times easier than the proof target.
+    pub fn from_proof_target(address: Address<N>, proof_target: u64, share_factor: u64) -> Result<Self> {
+        // Ensure the share factor is nonzero.
+        ensure!(share_factor > 0, "The share factor must be nonzero");
+        Self::new(address, proof_target.saturating_div(share_factor).max(1))
+    }
+
+    /// Returns the address of the pool.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the minimum target of a share.
+    pub const fn share_target(&self) -> u64 {
+        self.share_target
+    }
+
+    /// Returns a share for the pool address and the given nonce, if the solution meets the share target.
+    pub fn prove_share(
+        &self,
+        coinbase_puzzle: &CoinbasePuzzle<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        nonce: u64,
+    ) -> Result<ProverSolution<N>> {
+        coinbase_puzzle.prove(epoch_challenge, self.address, nonce, Some(self.share_target))
+    }
+
+    /// Ensures the given share is valid for the pool, and returns whether it also meets the proof target.
+    ///
+    /// A share is valid if it is bound to the pool address, its nonce is in the prover's assigned nonce range,
+    /// and it is a valid prover solution for the share target.
+    pub fn verify_share(
+        &self,
+        coinbase_puzzle: &CoinbasePuzzle<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+        nonce_range: &Range<u64>,
+        share: &ProverSolution<N>,
+    ) -> Result<ShareKind> {
+        // Ensure the share is bound to the pool address.
+        ensure!(share.address() == self.address, "The share is not for the pool address '{}'", self.address);
+        // Ensure the nonce is in the assigned nonce range.
+        ensure!(
+            nonce_range.contains(&share.nonce()),
+            "The share nonce '{}' is not in the assigned nonce range ({}..{})",
+            share.nonce(),
+            nonce_range.start,
+            nonce_range.end
+        );
+        // Ensure the share is a valid prover solution for the share target.
+        ensure!(
+            share.verify(coinbase_puzzle.coinbase_verifying_key(), epoch_challenge, self.share_target)?,
+            "The share is an invalid prover solution"
+        );
+        // Determine whether the share also meets the proof target.
+        match share.to_target()? >= proof_target {
+            true => Ok(ShareKind::Solution),
+            false => Ok(ShareKind::Share),
+        }
+    }
+}
+
+/// Splits the given nonce range into `num_partitions` disjoint, contiguous ranges of (nearly) equal size,
+/// so each prover in a pool searches a distinct part of the nonce space.
+pub fn split_nonce_range(nonce_range: Range<u64>, num_partitions: u64) -> Result<Vec<Range<u64>>> {
+    // Ensure the number of partitions is nonzero.
+    ensure!(num_partitions > 0, "The number of partitions must be nonzero");
+    // Ensure there are enough nonces for each partition.
+    let num_nonces = nonce_range.end.saturating_sub(nonce_range.start);
+    ensure!(num_nonces >= num_partitions, "The nonce range is too small for {num_partitions} partitions");
+
+    // Compute the partition size, and distribute the remainder among the first partitions.
+    let (size, remainder) = (num_nonces / num_partitions, num_nonces % num_partitions);
+    let mut start = nonce_range.start;
+    Ok((0..num_partitions)
+        .map(|index| {
+            let end = start + size + u64::from(index < remainder);
+            let partition = start..end;
+            start = end;
+            partition
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_split_nonce_range() {
+        // Ensure the partitions cover the nonce range, in order, without overlap.
+        for (nonce_range, num_partitions) in [(0..10, 3), (5..6, 1), (0..u64::MAX, 7), (100..200, 100)] {
+            let partitions = split_nonce_range(nonce_range.clone(), num_partitions).unwrap();
+            assert_eq!(partitions.len() as u64, num_partitions);
+            assert_eq!(partitions.first().unwrap().start, nonce_range.start);
+            assert_eq!(partitions.last().unwrap().end, nonce_range.end);
+            for window in partitions.windows(2) {
+                assert_eq!(window[0].end, window[1].start);
+                assert!(window[0].end - window[0].start >= window[1].end - window[1].start);
+            }
+        }
+
+        // Ensure the nonce range cannot be split into too many partitions.
+        assert!(split_nonce_range(0..10, 0).is_err());
+        assert!(split_nonce_range(0..10, 11).is_err());
+    }
+
+    #[test]
+    fn test_verify_share() {
+        let mut rng = TestRng::default();
+
+        let degree = (1 << 5) - 1;
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(PuzzleConfig { degree }).unwrap();
+        let puzzle = CoinbasePuzzle::<CurrentNetwork>::trim(&srs, PuzzleConfig { degree }).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        let pool = MiningPool::new(address, 1).unwrap();
+        let nonce_range = split_nonce_range(0..u64::MAX, 4).unwrap().remove(1);
+
+        // Prove a share in the assigned nonce range.
+        let share = pool.prove_share(&puzzle, &epoch_challenge, nonce_range.start).unwrap();
+        let target = share.to_target().unwrap();
+
+        // Ensure the share is accepted, and is a solution only if it meets the proof target.
+        assert_eq!(
+            pool.verify_share(&puzzle, &epoch_challenge, target, &nonce_range, &share).unwrap(),
+            ShareKind::Solution
+        );
+        assert_eq!(
+            pool.verify_share(&puzzle, &epoch_challenge, target.saturating_add(1), &nonce_range, &share).unwrap(),
+            ShareKind::Share
+        );
+
+        // Ensure the share is rejected outside the assigned nonce range.
+        assert!(pool.verify_share(&puzzle, &epoch_challenge, target, &(0..nonce_range.start), &share).is_err());
+
+        // Ensure the share is rejected if it does not meet the share target.
+        let strict_pool = MiningPool::new(address, target.saturating_add(1)).unwrap();
+        assert!(strict_pool.verify_share(&puzzle, &epoch_challenge, target, &nonce_range, &share).is_err());
+
+        // Ensure the share is rejected for a different pool address.
+        let other_address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+        let other_pool = MiningPool::new(other_address, 1).unwrap();
+        assert!(other_pool.verify_share(&puzzle, &epoch_challenge, target, &nonce_range, &share).is_err());
+
+        // Ensure the share is rejected for a different epoch challenge.
+        let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+        assert!(pool.verify_share(&puzzle, &bad_epoch_challenge, target, &nonce_range, &share).is_err());
+
+        // Ensure the share target is derived from the proof target.
+        assert_eq!(MiningPool::from_proof_target(address, 1000, 10).unwrap().share_target(), 100);
+        assert_eq!(MiningPool::from_proof_target(address, 5, 10).unwrap().share_target(), 1);
+        assert!(MiningPool::from_proof_target(address, 1000, 0).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for MiningPreimage<N> {
+    /// Reads the mining pre-image from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let epoch_number = FromBytes::read_le(&mut reader)?;
+        let epoch_block_hash = FromBytes::read_le(&mut reader)?;
+        let address = FromBytes::read_le(&mut reader)?;
+
+        Self::new(epoch_number, epoch_block_hash, address).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for MiningPreimage<N> {
+    /// Writes the mining pre-image to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.epoch_number.write_le(&mut writer)?;
+        self.epoch_block_hash.write_le(&mut writer)?;
+        self.address.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample a new mining pre-image.
+        let expected = MiningPreimage::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), address)?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, MiningPreimage::read_le(&expected_bytes[..])?);
+        assert!(MiningPreimage::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Ensure the resumed midstate matches the original.
+        let resumed = MiningPreimage::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+        assert_eq!(resumed.to_prover_polynomial(7, 31), expected.to_prover_polynomial(7, 31));
+
+        Ok(())
+    }
+}
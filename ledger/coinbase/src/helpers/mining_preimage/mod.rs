@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use super::*;
+use crate::digest_to_polynomial;
+
+use blake2::{Blake2s256, Digest};
+
+/// The number of bytes in a mining pre-image.
+const PREIMAGE_SIZE_IN_BYTES: usize = 68;
+
+/// The canonical mining pre-image of the coinbase puzzle, which is the prover polynomial input without the nonce,
+/// defined as `( epoch_number || epoch_block_hash || address )`.
+///
+/// The pre-image caches the hash midstate after absorbing its bytes, so each nonce only hashes its own 8 bytes.
+/// Its byte representation is a compact mining job, from which a prover can search for solutions.
+#[derive(Clone)]
+pub struct MiningPreimage<N: Network> {
+    /// The epoch number.
+    epoch_number: u32,
+    /// The epoch block hash.
+    epoch_block_hash: N::BlockHash,
+    /// The address of the prover.
+    address: Address<N>,
+    /// The hash midstate, after absorbing the pre-image bytes.
+    midstate: Blake2s256,
+}
+
+impl<N: Network> MiningPreimage<N> {
+    /// The number of bytes in the pre-image.
+    pub const SIZE_IN_BYTES: usize = PREIMAGE_SIZE_IN_BYTES;
+
+    /// Initializes a new mining pre-image from the given epoch number, epoch block hash, and address.
+    pub fn new(epoch_number: u32, epoch_block_hash: N::BlockHash, address: Address<N>) -> Result<Self> {
+        // Construct the pre-image bytes.
+        let mut bytes = [0u8; PREIMAGE_SIZE_IN_BYTES];
+        epoch_number.write_le(&mut bytes[..4])?;
+        epoch_block_hash.write_le(&mut bytes[4..36])?;
+        address.write_le(&mut bytes[36..])?;
+        // Absorb the pre-image bytes into the hash midstate.
+        let midstate = Blake2s256::new().chain_update(bytes);
+        Ok(Self { epoch_number, epoch_block_hash, address, midstate })
+    }
+
+    /// Initializes a new mining pre-image for the given epoch challenge and address.
+    pub fn from_epoch_challenge(epoch_challenge: &EpochChallenge<N>, address: Address<N>) -> Result<Self> {
+        Self::new(epoch_challenge.epoch_number(), epoch_challenge.epoch_block_hash(), address)
+    }
+
+    /// Returns the epoch number.
+    pub const fn epoch_number(&self) -> u32 {
+        self.epoch_number
+    }
+
+    /// Returns the epoch block hash.
+    pub const fn epoch_block_hash(&self) -> N::BlockHash {
+        self.epoch_block_hash
+    }
+
+    /// Returns the address of the prover.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns `true` if the pre-image is for the given epoch challenge.
+    pub fn is_for_epoch(&self, epoch_challenge: &EpochChallenge<N>) -> bool {
+        self.epoch_number == epoch_challenge.epoch_number()
+            && self.epoch_block_hash == epoch_challenge.epoch_block_hash()
+    }
+
+    /// Returns the epoch challenge of the given degree for the pre-image.
+    pub fn to_epoch_challenge(&self, degree: u32) -> Result<EpochChallenge<N>> {
+        EpochChallenge::new(self.epoch_number, self.epoch_block_hash, degree)
+    }
+
+    /// Returns the prover polynomial of the given degree for the given nonce, resuming from the hash midstate.
+    pub fn to_prover_polynomial(
+        &self,
+        nonce: u64,
+        degree: u32,
+    ) -> DensePolynomial<<N::PairingCurve as PairingEngine>::Fr> {
+        // Absorb the nonce, and finalize the hash.
+        let hash = self.midstate.clone().chain_update(nonce.to_le_bytes()).finalize();
+        // Expand the hash into the prover polynomial.
+        digest_to_polynomial(&hash.into(), degree)
+    }
+}
+
+impl<N: Network> PartialEq for MiningPreimage<N> {
+    /// Returns `true` if the pre-images are equal, as the midstate is determined by the pre-image.
+    fn eq(&self, other: &Self) -> bool {
+        self.epoch_number == other.epoch_number
+            && self.epoch_block_hash == other.epoch_block_hash
+            && self.address == other.address
+    }
+}
+
+impl<N: Network> Eq for MiningPreimage<N> {}
+
+impl<N: Network> Debug for MiningPreimage<N> {
+    /// Prints the mining pre-image, without the hash midstate.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MiningPreimage")
+            .field("epoch_number", &self.epoch_number)
+            .field("epoch_block_hash", &self.epoch_block_hash)
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash_to_polynomial;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_prover_polynomial_matches_preimage_hash() {
+        let mut rng = TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        let preimage = MiningPreimage::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), address).unwrap();
+        let preimage_bytes = preimage.to_bytes_le().unwrap();
+        assert_eq!(preimage_bytes.len(), MiningPreimage::<CurrentNetwork>::SIZE_IN_BYTES);
+
+        for _ in 0..ITERATIONS {
+            // Ensure the midstate yields the same polynomial as hashing the full input.
+            let nonce = rng.next_u64();
+            let degree = 31;
+            let input = [preimage_bytes.clone(), nonce.to_le_bytes().to_vec()].concat();
+            let expected = hash_to_polynomial::<<<CurrentNetwork as Environment>::PairingCurve as PairingEngine>::Fr>(
+                &input, degree,
+            );
+            assert_eq!(preimage.to_prover_polynomial(nonce, degree), expected);
+        }
+    }
+}
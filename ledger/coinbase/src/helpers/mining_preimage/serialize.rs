@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for MiningPreimage<N> {
+    /// Serializes the mining pre-image to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut mining_preimage = serializer.serialize_struct("MiningPreimage", 3)?;
+                mining_preimage.serialize_field("epoch_number", &self.epoch_number)?;
+                mining_preimage.serialize_field("epoch_block_hash", &self.epoch_block_hash)?;
+                mining_preimage.serialize_field("address", &self.address)?;
+                mining_preimage.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for MiningPreimage<N> {
+    /// Deserializes the mining pre-image from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut mining_preimage = serde_json::Value::deserialize(deserializer)?;
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut mining_preimage, "epoch_number")?,
+                    DeserializeExt::take_from_value::<D>(&mut mining_preimage, "epoch_block_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut mining_preimage, "address")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "mining pre-image"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample a new mining pre-image.
+        let expected = MiningPreimage::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), address)?;
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(serde_json::to_value(&expected)?["address"], serde_json::json!(address.to_string()));
+
+        // Deserialize
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let address = Address::try_from(private_key)?;
+
+        // Sample a new mining pre-image.
+        let expected = MiningPreimage::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), address)?;
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, MiningPreimage::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
@@ -18,6 +18,12 @@ pub use coinbase_solution::*;
 mod epoch_challenge;
 pub use epoch_challenge::*;
 
+mod mining_pool;
+pub use mining_pool::*;
+
+mod mining_preimage;
+pub use mining_preimage::*;
+
 mod partial_solution;
 pub use partial_solution::*;
 
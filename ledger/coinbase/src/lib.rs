@@ -27,7 +27,7 @@ mod tests;
 
 use console::{
     account::Address,
-    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result, ToBytes},
+    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result},
 };
 use snarkvm_algorithms::{
     fft::{DensePolynomial, EvaluationDomain},
@@ -38,7 +38,11 @@ use snarkvm_fields::Zero;
 use snarkvm_synthesizer_snark::UniversalSRS;
 
 use aleo_std::prelude::*;
-use std::sync::Arc;
+use core::ops::Range;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -112,13 +116,46 @@ impl<N: Network> CoinbasePuzzle<N> {
         nonce: u64,
         minimum_proof_target: Option<u64>,
     ) -> Result<ProverSolution<N>> {
+        // Construct the mining pre-image.
+        let preimage = MiningPreimage::from_epoch_challenge(epoch_challenge, address)?;
+        // Prove the coinbase puzzle.
+        self.prove_with_preimage(epoch_challenge, &preimage, nonce, minimum_proof_target)
+    }
+
+    /// Returns a prover solution to the coinbase puzzle, for the given mining pre-image and nonce.
+    /// The pre-image caches the hash midstate, so it can be reused across nonces.
+    pub fn prove_with_preimage(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        preimage: &MiningPreimage<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<ProverSolution<N>> {
+        match self.try_prove_with_preimage(epoch_challenge, preimage, nonce, minimum_proof_target)? {
+            Some(solution) => Ok(solution),
+            None => bail!("Prover solution was below the necessary proof target ({minimum_proof_target:?})"),
+        }
+    }
+
+    /// Returns a prover solution to the coinbase puzzle, for the given mining pre-image and nonce,
+    /// or `None` if the solution does not meet the minimum proof target.
+    fn try_prove_with_preimage(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        preimage: &MiningPreimage<N>,
+        nonce: u64,
+        minimum_proof_target: Option<u64>,
+    ) -> Result<Option<ProverSolution<N>>> {
         // Retrieve the coinbase proving key.
         let pk = match self {
             Self::Prover(coinbase_proving_key) => coinbase_proving_key,
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
+        // Ensure the pre-image is for the epoch challenge.
+        ensure!(preimage.is_for_epoch(epoch_challenge), "The mining pre-image is not for the epoch challenge");
+        let address = preimage.address();
 
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+        let polynomial = preimage.to_prover_polynomial(nonce, epoch_challenge.degree());
 
         let product_evaluations = {
             let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
@@ -134,11 +171,9 @@ impl<N: Network> CoinbasePuzzle<N> {
 
         // Check that the minimum target is met.
         if let Some(minimum_target) = minimum_proof_target {
-            let proof_target = partial_solution.to_target()?;
-            ensure!(
-                proof_target >= minimum_target,
-                "Prover solution was below the necessary proof target ({proof_target} < {minimum_target})"
-            );
+            if partial_solution.to_target()? < minimum_target {
+                return Ok(None);
+            }
         }
 
         let point = hash_commitment(&commitment)?;
@@ -155,7 +190,97 @@ impl<N: Network> CoinbasePuzzle<N> {
 
         debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
 
-        Ok(ProverSolution::new(partial_solution, proof))
+        Ok(Some(ProverSolution::new(partial_solution, proof)))
+    }
+
+    /// Searches the given nonce range for a prover solution that meets the minimum proof target,
+    /// splitting the range across `num_workers` threads that share the resident proving key.
+    ///
+    /// The search stops once a solution is found, the nonce range is exhausted, or the `terminator` is set,
+    /// in which case `None` is returned. With the `cuda` feature, the commitments are computed on the GPU.
+    /// With the `serial` feature, the partitions are searched sequentially on the calling thread.
+    pub fn mine(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce_range: Range<u64>,
+        minimum_proof_target: u64,
+        terminator: &AtomicBool,
+        num_workers: usize,
+    ) -> Result<Option<ProverSolution<N>>> {
+        // Ensure the coinbase puzzle is a prover.
+        self.coinbase_proving_key()?;
+        // Split the nonce range across the workers.
+        let partitions = split_nonce_range(nonce_range, u64::try_from(num_workers)?)?;
+
+        let minimum_proof_target = Some(minimum_proof_target);
+        // Construct the mining pre-image, which is shared by the workers.
+        let preimage = MiningPreimage::from_epoch_challenge(epoch_challenge, address)?;
+
+        // Initialize a flag to stop the other workers once a solution is found.
+        let is_found = AtomicBool::new(false);
+        // Searches the given partition, until a solution is found, the partition is exhausted, or the search is stopped.
+        let search = |partition: Range<u64>| {
+            for nonce in partition {
+                // Stop if the search was terminated, or another worker found a solution.
+                if terminator.load(Ordering::Relaxed) || is_found.load(Ordering::Relaxed) {
+                    return Ok(None);
+                }
+                // Note: `None` is returned if the solution does not meet the minimum proof target.
+                match self.try_prove_with_preimage(epoch_challenge, &preimage, nonce, minimum_proof_target) {
+                    Ok(Some(solution)) => {
+                        is_found.store(true, Ordering::Relaxed);
+                        return Ok(Some(solution));
+                    }
+                    Ok(None) => continue,
+                    // Stop the other workers, as the prover has failed.
+                    Err(error) => {
+                        is_found.store(true, Ordering::Relaxed);
+                        return Err(error);
+                    }
+                }
+            }
+            Ok(None)
+        };
+
+        // Search the partitions sequentially on the calling thread.
+        #[cfg(feature = "serial")]
+        let results = partitions.into_iter().map(search).collect::<Vec<Result<_>>>();
+        // Search each partition on its own worker.
+        #[cfg(not(feature = "serial"))]
+        let results = std::thread::scope(|scope| {
+            let search = &search;
+            let workers = partitions
+                .into_iter()
+                .map(|partition| scope.spawn(move || search(partition)))
+                .collect::<Vec<_>>();
+            // Join every worker.
+            workers
+                .into_iter()
+                .map(|worker| {
+                    worker.join().unwrap_or_else(|_| {
+                        is_found.store(true, Ordering::Relaxed);
+                        Err(anyhow!("A coinbase puzzle worker panicked"))
+                    })
+                })
+                .collect::<Vec<Result<_>>>()
+        });
+
+        // Return a solution if any worker found one, even if another worker failed, and otherwise the first error.
+        let mut error = None;
+        for result in results {
+            match result {
+                Ok(Some(solution)) => return Ok(Some(solution)),
+                Ok(None) => continue,
+                Err(worker_error) => {
+                    error.get_or_insert(worker_error);
+                }
+            }
+        }
+        match error {
+            Some(error) => Err(error),
+            None => Ok(None),
+        }
     }
 
     /// Returns `true` if the solutions are valid.
@@ -236,15 +361,8 @@ impl<N: Network> CoinbasePuzzle<N> {
         address: Address<N>,
         nonce: u64,
     ) -> Result<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>> {
-        let input = {
-            let mut bytes = [0u8; 76];
-            epoch_challenge.epoch_number().write_le(&mut bytes[..4])?;
-            epoch_challenge.epoch_block_hash().write_le(&mut bytes[4..36])?;
-            address.write_le(&mut bytes[36..68])?;
-            nonce.write_le(&mut bytes[68..])?;
-
-            bytes
-        };
-        Ok(hash_to_polynomial::<<N::PairingCurve as PairingEngine>::Fr>(&input, epoch_challenge.degree()))
+        // Construct the mining pre-image, and absorb the nonce.
+        let preimage = MiningPreimage::from_epoch_challenge(epoch_challenge, address)?;
+        Ok(preimage.to_prover_polynomial(nonce, epoch_challenge.degree()))
     }
 }
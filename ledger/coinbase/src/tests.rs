@@ -14,7 +14,7 @@
 
 use super::*;
 use console::{account::*, network::Testnet3};
-use snarkvm_utilities::Uniform;
+use snarkvm_utilities::{FromBytes, ToBytes, Uniform};
 
 use rand::RngCore;
 
@@ -149,3 +149,64 @@ fn test_profiler() -> Result<()> {
 
     bail!("\n\nRemember to #[ignore] this test!\n\n")
 }
+
+#[test]
+fn test_mine() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 5) - 1;
+    let srs = CoinbasePuzzle::<Testnet3>::setup(PuzzleConfig { degree }).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let terminator = AtomicBool::new(false);
+
+    // Ensure a solution is found in the nonce range, and is valid.
+    let nonce_range = 1000..1100;
+    let solution = puzzle.mine(&epoch_challenge, address, nonce_range.clone(), 1, &terminator, 4).unwrap().unwrap();
+    assert!(nonce_range.contains(&solution.nonce()));
+    assert_eq!(solution.address(), address);
+    assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 1).unwrap());
+
+    // Ensure no solution is found once the search is terminated.
+    terminator.store(true, std::sync::atomic::Ordering::Relaxed);
+    assert!(puzzle.mine(&epoch_challenge, address, nonce_range.clone(), 1, &terminator, 4).unwrap().is_none());
+    terminator.store(false, std::sync::atomic::Ordering::Relaxed);
+
+    // Ensure no solution is found if the minimum proof target is unreachable.
+    assert!(puzzle.mine(&epoch_challenge, address, 0..8, u64::MAX, &terminator, 2).unwrap().is_none());
+
+    // Ensure the nonce range must have a nonce for each worker.
+    assert!(puzzle.mine(&epoch_challenge, address, 0..2, 1, &terminator, 4).is_err());
+}
+
+#[test]
+fn test_prove_with_preimage() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 5) - 1;
+    let srs = CoinbasePuzzle::<Testnet3>::setup(PuzzleConfig { degree }).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+
+    // Ensure the mining job round-trips, and reconstructs the epoch challenge.
+    let preimage = MiningPreimage::from_epoch_challenge(&epoch_challenge, address).unwrap();
+    let job = MiningPreimage::<Testnet3>::read_le(&preimage.to_bytes_le().unwrap()[..]).unwrap();
+    assert_eq!(job.to_epoch_challenge(degree).unwrap(), epoch_challenge);
+
+    // Ensure proving with the pre-image matches proving with the address.
+    for nonce in 0..10 {
+        let expected = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+        assert_eq!(puzzle.prove_with_preimage(&epoch_challenge, &job, nonce, None).unwrap(), expected);
+    }
+
+    // Ensure the pre-image must be for the epoch challenge.
+    let other_epoch_challenge =
+        EpochChallenge::new(epoch_challenge.epoch_number() + 1, Default::default(), degree).unwrap();
+    assert!(puzzle.prove_with_preimage(&other_epoch_challenge, &job, 0, None).is_err());
+}
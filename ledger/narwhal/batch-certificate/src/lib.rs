@@ -49,6 +49,11 @@ pub enum BatchCertificate<N: Network> {
     },
 }
 
+impl<N: Network> BatchCertificate<N> {
+    /// The maximum number of signatures in a batch certificate.
+    pub const MAX_SIGNATURES: usize = BatchHeader::<N>::MAX_CERTIFICATES;
+}
+
 impl<N: Network> BatchCertificate<N> {
     // TODO (howardwu): For mainnet - Delete V1 and switch everyone to V2 as the default.
     /// Initializes a (deprecated) V1 batch certificate.
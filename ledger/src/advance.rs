@@ -83,14 +83,18 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Adds the given block as the next block in the ledger.
     pub fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
-        // Acquire the write lock on the current block.
-        let mut current_block = self.current_block.write();
+        let _span = debug_span!("Ledger::advance_to_next_block", height = block.height()).entered();
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        // Acquire the advance lock.
+        // Note: The current block is not locked while the VM is updated, so readers continue
+        // to see the previous block until the VM update succeeds.
+        let _advance_lock = self.advance_lock.lock();
         // Update the VM.
         self.vm.add_next_block(block)?;
         // Update the current block.
-        *current_block = block.clone();
-        // Drop the write lock on the current block.
-        drop(current_block);
+        *self.current_block.write() = block.clone();
 
         // Update the cached committee from storage.
         if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
@@ -103,6 +107,54 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
         }
 
+        // If pruning is enabled, prune the block that is now `depth` blocks behind the latest block.
+        // Note: The genesis block is never pruned.
+        if let Some(depth) = self.pruning_depth {
+            match block.height().checked_sub(depth) {
+                Some(height) if height > 0 => self.vm.block_store().prune(height)?,
+                _ => (),
+            }
+        }
+
+        // Notify the subscribers.
+        self.subscribers.lock().notify_block_applied(block);
+
+        #[cfg(feature = "metrics")]
+        {
+            metrics::increment_counter(metrics::ledger::BLOCKS, 1);
+            metrics::increment_counter(metrics::ledger::TRANSACTIONS, block.transactions().len() as u64);
+            metrics::histogram_elapsed(metrics::ledger::ADVANCE_TIME, start);
+        }
+
+        Ok(())
+    }
+
+    /// Validates the given block, and adds it as the next block in the ledger.
+    ///
+    /// The block is first checked to extend the latest block, so a stale block is rejected before any proofs are verified.
+    /// The transactions are then checked for conflicts with each other and verified in parallel (proofs, signatures,
+    /// and Merkle paths), and the state is only applied once the entire block is valid.
+    pub fn validate_block_parallel<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
+        let timer = timer!("Ledger::validate_block_parallel");
+
+        // Ensure the block extends the latest block.
+        let latest_height = self.latest_height();
+        if block.height() != latest_height.saturating_add(1) {
+            bail!("Block {} does not extend the latest block height {latest_height}", block.height())
+        }
+        if block.previous_hash() != self.latest_hash() {
+            bail!("Block {} does not extend the latest block hash '{}'", block.height(), self.latest_hash())
+        }
+        lap!(timer, "Check the block extends the latest block");
+
+        // Ensure the block is valid, verifying the transactions in parallel.
+        self.check_next_block(block, rng)?;
+        lap!(timer, "Verify the block");
+
+        // Add the block to the ledger.
+        self.advance_to_next_block(block)?;
+        finish!(timer, "Add the block to the ledger");
+
         Ok(())
     }
 }
@@ -110,7 +162,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Constructs a block template for the next block in the ledger.
     #[allow(clippy::type_complexity)]
-    fn construct_block_template(
+    pub(crate) fn construct_block_template(
         &self,
         previous_block: &Block<N>,
         subdag: Option<&Subdag<N>>,
@@ -183,16 +235,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             false => next_cumulative_proof_target,
         };
         // Construct the next coinbase target.
-        let next_coinbase_target = coinbase_target(
-            previous_block.last_coinbase_target(),
-            previous_block.last_coinbase_timestamp(),
-            next_timestamp,
-            N::ANCHOR_TIME,
-            N::NUM_BLOCKS_PER_EPOCH,
-            N::GENESIS_COINBASE_TARGET,
-        )?;
+        let next_coinbase_target = self.difficulty_target.coinbase_target(previous_block.header(), next_timestamp)?;
         // Construct the next proof target.
-        let next_proof_target = proof_target(next_coinbase_target, N::GENESIS_PROOF_TARGET);
+        let next_proof_target = self.difficulty_target.proof_target(next_coinbase_target);
 
         // Construct the next last coinbase target and next last coinbase timestamp.
         let (next_last_coinbase_target, next_last_coinbase_timestamp) = match is_coinbase_target_reached {
@@ -0,0 +1,155 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::time::{Duration, Instant};
+
+/// The statistics of a benchmark scenario.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    /// The name of the scenario.
+    pub name: &'static str,
+    /// The number of items processed in the scenario (e.g. leaves, transactions).
+    pub num_items: usize,
+    /// The wall-clock time taken by the scenario.
+    pub elapsed: Duration,
+    /// The size in bytes of the artifacts produced by the scenario, if any.
+    pub num_bytes: Option<usize>,
+}
+
+impl BenchStats {
+    /// Returns the average time taken per item, or `None` if no items were processed.
+    pub fn per_item(&self) -> Option<Duration> {
+        u32::try_from(self.num_items).ok().filter(|num_items| *num_items > 0).map(|num_items| self.elapsed / num_items)
+    }
+}
+
+/// Builds a BHP Merkle tree of the given depth, over `num_leaves` random leaves.
+pub fn merkle_tree<N: Network, const DEPTH: u8, R: Rng>(num_leaves: usize, rng: &mut R) -> Result<BenchStats> {
+    // Sample the leaves.
+    let leaves = (0..num_leaves).map(|_| Field::<N>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+
+    // Build the Merkle tree.
+    let timer = Instant::now();
+    N::merkle_tree_bhp::<DEPTH>(&leaves)?;
+    Ok(BenchStats { name: "merkle_tree", num_items: num_leaves, elapsed: timer.elapsed(), num_bytes: None })
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Generates `num_transactions` public transfers of 1 microcredit from the given private key to itself,
+    /// and returns the transactions.
+    pub fn bench_execute<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        num_transactions: usize,
+        rng: &mut R,
+    ) -> Result<(Vec<Transaction<N>>, BenchStats)> {
+        // Prepare the inputs.
+        let address = Address::try_from(private_key)?;
+        let inputs = [Value::from_str(&address.to_string())?, Value::from_str("1u64")?];
+
+        // Generate the transactions.
+        let timer = Instant::now();
+        let transactions = (0..num_transactions)
+            .map(|_| {
+                let inputs = inputs.clone().into_iter();
+                self.vm.execute(private_key, ("credits.aleo", "transfer_public"), inputs, None, 0, None, rng)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let elapsed = timer.elapsed();
+
+        // Compute the size of the transactions.
+        let mut num_bytes = 0;
+        for transaction in &transactions {
+            num_bytes += transaction.to_bytes_le()?.len();
+        }
+        Ok((
+            transactions,
+            BenchStats { name: "execute", num_items: num_transactions, elapsed, num_bytes: Some(num_bytes) },
+        ))
+    }
+
+    /// Verifies each of the given transactions.
+    pub fn bench_verify<R: Rng + CryptoRng>(&self, transactions: &[Transaction<N>], rng: &mut R) -> Result<BenchStats> {
+        let timer = Instant::now();
+        for transaction in transactions {
+            self.check_transaction_basic(transaction, None, rng)?;
+        }
+        Ok(BenchStats { name: "verify", num_items: transactions.len(), elapsed: timer.elapsed(), num_bytes: None })
+    }
+
+    /// Produces, checks, and adds the next beacon block with the given transactions.
+    pub fn bench_advance<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        transactions: Vec<Transaction<N>>,
+        rng: &mut R,
+    ) -> Result<BenchStats> {
+        let num_transactions = transactions.len();
+
+        let timer = Instant::now();
+        // Prepare the next block.
+        let block = self.prepare_advance_to_next_beacon_block(private_key, vec![], vec![], transactions, rng)?;
+        // Check the next block.
+        self.check_next_block(&block, rng)?;
+        // Add the block to the ledger.
+        self.advance_to_next_block(&block)?;
+        let elapsed = timer.elapsed();
+
+        Ok(BenchStats {
+            name: "advance",
+            num_items: num_transactions,
+            elapsed,
+            num_bytes: Some(block.to_bytes_le()?.len()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::CurrentNetwork;
+
+    #[test]
+    fn test_merkle_tree() {
+        let rng = &mut TestRng::default();
+
+        let stats = merkle_tree::<CurrentNetwork, 8, _>(100, rng).unwrap();
+        assert_eq!(stats.num_items, 100);
+        assert!(stats.per_item().is_some());
+        // Ensure the tree cannot exceed its depth.
+        assert!(merkle_tree::<CurrentNetwork, 2, _>(5, rng).is_err());
+    }
+
+    #[test]
+    fn test_execute_verify_advance() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the test environment.
+        let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+        // Generate and verify the transactions.
+        let (transactions, stats) = ledger.bench_execute(&private_key, 2, rng).unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert!(stats.num_bytes.unwrap() > 0);
+        assert_eq!(ledger.bench_verify(&transactions, rng).unwrap().num_items, 2);
+
+        // Add the transactions to the ledger.
+        let height = ledger.latest_height();
+        let stats = ledger.bench_advance(&private_key, transactions, rng).unwrap();
+        assert_eq!(stats.num_items, 2);
+        assert_eq!(ledger.latest_height(), height + 1);
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A template for the next block, which contains everything except the block authority.
+#[derive(Clone)]
+pub struct BlockTemplate<N: Network> {
+    /// The hash of the previous block.
+    previous_hash: N::BlockHash,
+    /// The header of the next block.
+    header: Header<N>,
+    /// The ratifications in the next block.
+    ratifications: Ratifications<N>,
+    /// The solutions in the next block.
+    solutions: Option<CoinbaseSolution<N>>,
+    /// The transactions in the next block.
+    transactions: Transactions<N>,
+    /// The aborted transaction IDs in the next block.
+    aborted_transaction_ids: Vec<N::TransactionID>,
+}
+
+impl<N: Network> BlockTemplate<N> {
+    /// Returns the hash of the previous block.
+    pub const fn previous_hash(&self) -> N::BlockHash {
+        self.previous_hash
+    }
+
+    /// Returns the header of the next block.
+    pub const fn header(&self) -> &Header<N> {
+        &self.header
+    }
+
+    /// Returns the ratifications in the next block.
+    pub const fn ratifications(&self) -> &Ratifications<N> {
+        &self.ratifications
+    }
+
+    /// Returns the solutions in the next block.
+    pub const fn solutions(&self) -> Option<&CoinbaseSolution<N>> {
+        self.solutions.as_ref()
+    }
+
+    /// Returns the transactions in the next block.
+    pub const fn transactions(&self) -> &Transactions<N> {
+        &self.transactions
+    }
+
+    /// Returns the aborted transaction IDs in the next block.
+    pub fn aborted_transaction_ids(&self) -> &[N::TransactionID] {
+        &self.aborted_transaction_ids
+    }
+
+    /// Returns an iterator over the serial numbers spent by the next block.
+    pub fn serial_numbers(&self) -> impl '_ + Iterator<Item = &Field<N>> {
+        self.transactions.serial_numbers()
+    }
+
+    /// Returns an iterator over the commitments created by the next block.
+    pub fn commitments(&self) -> impl '_ + Iterator<Item = &Field<N>> {
+        self.transactions.commitments()
+    }
+
+    /// Returns the pre-image of the block hash, which is the previous block hash and the header root.
+    pub fn to_preimage(&self) -> Result<Vec<bool>> {
+        Ok(to_bits_le![self.previous_hash, self.header.to_root()?])
+    }
+
+    /// Returns the block hash of the next block.
+    pub fn to_block_hash(&self) -> Result<N::BlockHash> {
+        Ok(N::hash_bhp1024(&self.to_preimage()?)?.into())
+    }
+
+    /// Returns the next beacon block, by signing the block template with the given private key.
+    pub fn into_beacon_block<R: Rng + CryptoRng>(self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Block<N>> {
+        Block::new_beacon(
+            private_key,
+            self.previous_hash,
+            self.header,
+            self.ratifications,
+            self.solutions,
+            self.transactions,
+            self.aborted_transaction_ids,
+            rng,
+        )
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a block template for the next block, using the candidate transactions from the given mempool,
+    /// ordered by highest fee first, and the given candidate solutions.
+    pub fn build_block_template(
+        &self,
+        mempool: &Mempool<N>,
+        candidate_solutions: Vec<ProverSolution<N>>,
+    ) -> Result<BlockTemplate<N>> {
+        // Retrieve the latest block as the previous block (for the next block).
+        let previous_block = self.latest_block();
+
+        // Select the candidate transactions from the mempool.
+        let candidate_transactions =
+            mempool.candidates(Transactions::<N>::MAX_TRANSACTIONS).into_iter().cloned().collect::<Vec<_>>();
+
+        // Construct the block template.
+        let (header, ratifications, solutions, transactions, aborted_transaction_ids) =
+            self.construct_block_template(&previous_block, None, vec![], candidate_solutions, candidate_transactions)?;
+
+        Ok(BlockTemplate {
+            previous_hash: previous_block.hash(),
+            header,
+            ratifications,
+            solutions,
+            transactions,
+            aborted_transaction_ids,
+        })
+    }
+}
@@ -14,8 +14,6 @@
 
 use super::*;
 
-use rand::{rngs::StdRng, SeedableRng};
-
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
@@ -41,13 +39,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
 
         // Ensure each transaction is well-formed and unique.
-        // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
-        let transactions = block.transactions().iter().collect::<Vec<_>>();
-        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-        cfg_iter!(transactions).zip(rngs).try_for_each(|(transaction, mut rng)| {
-            self.check_transaction_basic(*transaction, transaction.to_rejected_id()?, &mut rng)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|transaction| Ok((transaction.deref(), transaction.to_rejected_id()?)))
+            .collect::<Result<Vec<_>>>()?;
+        self.check_transactions_basic(&transactions, rng)
+            .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))?;
 
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
         {
@@ -72,6 +70,21 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             // }
         }
 
+        // Retrieve the current timestamp.
+        let current_timestamp = OffsetDateTime::now_utc().unix_timestamp();
+
+        // If the median time past rule is active, ensure the timestamp is not before the median time past.
+        // Note: The other consensus rules for the header are checked in `Block::verify`.
+        if height >= N::MEDIAN_TIME_PAST_ACTIVATION_HEIGHT {
+            // Retrieve the headers in the median time past window, ending with the latest header.
+            let latest_height = self.latest_height();
+            let start_height =
+                latest_height.saturating_sub(u32::try_from(consensus_rules::MEDIAN_TIME_PAST_WINDOW)? - 1);
+            let previous_headers =
+                (start_height..=latest_height).map(|height| self.get_header(height)).collect::<Result<Vec<_>>>()?;
+            consensus_rules::check_median_time_past(&previous_headers, block.header())?;
+        }
+
         // Construct the finalize state.
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
@@ -86,14 +99,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             self.vm.check_speculate(state, block.ratifications(), block.solutions(), block.transactions())?;
 
         // Ensure the block is correct.
-        block.verify(
+        block.verify_with_difficulty_target(
             &self.latest_block(),
             self.latest_state_root(),
             &self.latest_committee()?,
             self.coinbase_puzzle(),
             &self.latest_epoch_challenge()?,
-            OffsetDateTime::now_utc().unix_timestamp(),
+            current_timestamp,
             ratified_finalize_operations,
+            self.difficulty_target(),
         )?;
 
         Ok(())
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The consensus rules for a block header, which can be checked from the previous headers alone.
+//!
+//! These rules do not cover the fields that commit to the block body or to the ledger state
+//! (e.g. the transactions root, or the previous state root), which require the block or the block tree.
+//! The ledger checks the other rules for each new block in `Block::verify`, and from
+//! `N::MEDIAN_TIME_PAST_ACTIVATION_HEIGHT`, it also checks the median time past rule.
+
+use console::network::Network;
+use ledger_block::{DifficultyTarget, Header};
+
+use anyhow::{bail, ensure, Result};
+
+/// The number of previous headers whose median timestamp bounds the timestamp of the next header.
+pub const MEDIAN_TIME_PAST_WINDOW: usize = 11;
+
+/// Returns the median timestamp of the last `MEDIAN_TIME_PAST_WINDOW` headers, or `None` if there are no headers.
+pub fn median_time_past<N: Network>(previous_headers: &[Header<N>]) -> Option<i64> {
+    let start = previous_headers.len().saturating_sub(MEDIAN_TIME_PAST_WINDOW);
+    let mut timestamps = previous_headers[start..].iter().map(|header| header.timestamp()).collect::<Vec<_>>();
+    timestamps.sort_unstable();
+    timestamps.get(timestamps.len() / 2).copied()
+}
+
+/// Ensures the timestamp of the given header is not before the median time past of the given previous headers,
+/// if the rule is active at the height of the header.
+pub fn check_median_time_past<N: Network>(previous_headers: &[Header<N>], header: &Header<N>) -> Result<()> {
+    let height = header.height();
+    if let Some(median_time_past) =
+        median_time_past(previous_headers).filter(|_| height >= N::MEDIAN_TIME_PAST_ACTIVATION_HEIGHT)
+    {
+        ensure!(
+            header.timestamp() >= median_time_past,
+            "Timestamp is before the median time past in block {height} (found '{}', expected at least '{median_time_past}')",
+            header.timestamp()
+        );
+    }
+    Ok(())
+}
+
+/// Ensures the given header is a valid successor of the given previous headers, which must end with
+/// the latest header, for the given retargeting rule and current (wall clock) timestamp.
+///
+/// This checks the header is well-formed, the height and round advance, the coinbase and proof targets
+/// follow from the previous header, the cumulative weight does not decrease, the last coinbase target
+/// and timestamp are carried over or updated, and the timestamp is not in the future.
+/// From `N::MEDIAN_TIME_PAST_ACTIVATION_HEIGHT`, this also checks the timestamp is not before the median time past.
+pub fn validate_header<N: Network>(
+    previous_headers: &[Header<N>],
+    header: &Header<N>,
+    difficulty_target: &dyn DifficultyTarget<N>,
+    current_timestamp: i64,
+) -> Result<()> {
+    // Retrieve the previous header.
+    let Some(previous_header) = previous_headers.last() else {
+        bail!("Cannot validate a header without the previous header");
+    };
+    let height = previous_header.height().saturating_add(1);
+
+    // Ensure the header is well-formed.
+    ensure!(header.is_valid(), "Header is malformed in block {height}");
+    // Ensure the height is correct.
+    ensure!(header.height() == height, "Height is incorrect (found '{}', expected '{height}')", header.height());
+    // Ensure the round is after the previous round.
+    ensure!(
+        header.round() > previous_header.round(),
+        "Round is not after the previous round in block {height} (found '{}', expected after '{}')",
+        header.round(),
+        previous_header.round()
+    );
+
+    // Ensure the coinbase target is correct.
+    let expected_coinbase_target = difficulty_target.coinbase_target(previous_header, header.timestamp())?;
+    ensure!(
+        header.coinbase_target() == expected_coinbase_target,
+        "Coinbase target is incorrect in block {height} (found '{}', expected '{expected_coinbase_target}')",
+        header.coinbase_target()
+    );
+    // Ensure the proof target is correct.
+    let expected_proof_target = difficulty_target.proof_target(expected_coinbase_target);
+    ensure!(
+        header.proof_target() == expected_proof_target,
+        "Proof target is incorrect in block {height} (found '{}', expected '{expected_proof_target}')",
+        header.proof_target()
+    );
+    // Ensure the cumulative weight does not decrease.
+    ensure!(
+        header.cumulative_weight() >= previous_header.cumulative_weight(),
+        "Cumulative weight decreased in block {height} (found '{}', expected at least '{}')",
+        header.cumulative_weight(),
+        previous_header.cumulative_weight()
+    );
+    // Ensure the last coinbase target and timestamp are either carried over, or updated to this block.
+    let is_carried_over = header.last_coinbase_target() == previous_header.last_coinbase_target()
+        && header.last_coinbase_timestamp() == previous_header.last_coinbase_timestamp();
+    let is_updated = header.last_coinbase_target() == expected_coinbase_target
+        && header.last_coinbase_timestamp() == header.timestamp();
+    ensure!(is_carried_over || is_updated, "Last coinbase target or timestamp is incorrect in block {height}");
+
+    // Ensure the timestamp is not before the median time past, if the rule is active.
+    check_median_time_past(previous_headers, header)?;
+    // Ensure the timestamp is not in the future.
+    ensure!(
+        header.timestamp() <= current_timestamp,
+        "Timestamp is in the future in block {height} (found '{}', expected before '{current_timestamp}')",
+        header.timestamp()
+    );
+    Ok(())
+}
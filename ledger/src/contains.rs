@@ -20,6 +20,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().contains_state_root(state_root)
     }
 
+    /// Returns `true` if the given state root exists, and is recent enough to be used in a new transaction.
+    pub fn contains_recent_state_root(&self, state_root: &N::StateRoot) -> Result<bool> {
+        self.vm.block_store().contains_recent_state_root(state_root)
+    }
+
     /// Returns `true` if the given block height exists.
     pub fn contains_block_height(&self, height: u32) -> Result<bool> {
         self.vm.block_store().contains_block_height(height)
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// An event emitted when a block is applied to the ledger.
+#[derive(Clone)]
+pub struct BlockAppliedEvent<N: Network> {
+    /// The height of the block.
+    pub height: u32,
+    /// The hash of the block.
+    pub block_hash: N::BlockHash,
+    /// The number of confirmed transactions in the block.
+    pub num_transactions: usize,
+}
+
+/// An event emitted for each confirmed transaction in a block that is applied to the ledger.
+#[derive(Clone)]
+pub struct TransactionAppliedEvent<N: Network> {
+    /// The height of the block that contains the transaction.
+    pub height: u32,
+    /// The hash of the block that contains the transaction.
+    pub block_hash: N::BlockHash,
+    /// The confirmed transaction.
+    pub transaction: ConfirmedTransaction<N>,
+}
+
+/// The subscribers to the ledger events.
+pub(crate) struct Subscribers<N: Network> {
+    /// The subscribers to the block applied events.
+    blocks: Vec<Sender<BlockAppliedEvent<N>>>,
+    /// The subscribers to the transaction applied events.
+    transactions: Vec<Sender<TransactionAppliedEvent<N>>>,
+}
+
+impl<N: Network> Default for Subscribers<N> {
+    fn default() -> Self {
+        Self { blocks: Vec::new(), transactions: Vec::new() }
+    }
+}
+
+impl<N: Network> Subscribers<N> {
+    /// Sends the events for the given applied block, and drops the subscribers whose receiver is gone.
+    pub(crate) fn notify_block_applied(&mut self, block: &Block<N>) {
+        // Send the transaction events first, so a block event signals that its transactions were delivered.
+        if !self.transactions.is_empty() {
+            for transaction in block.transactions().iter() {
+                let event = TransactionAppliedEvent {
+                    height: block.height(),
+                    block_hash: block.hash(),
+                    transaction: transaction.clone(),
+                };
+                self.transactions.retain(|sender| sender.send(event.clone()).is_ok());
+            }
+        }
+        let event = BlockAppliedEvent {
+            height: block.height(),
+            block_hash: block.hash(),
+            num_transactions: block.transactions().len(),
+        };
+        self.blocks.retain(|sender| sender.send(event.clone()).is_ok());
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a receiver of an event for each block that is applied to the ledger.
+    ///
+    /// The channel is unbounded, so the receiver should be drained promptly.
+    /// Dropping the receiver unsubscribes it.
+    pub fn on_block_applied(&self) -> Receiver<BlockAppliedEvent<N>> {
+        let (sender, receiver) = channel();
+        self.subscribers.lock().blocks.push(sender);
+        receiver
+    }
+
+    /// Returns a receiver of an event for each confirmed transaction in a block that is applied to the ledger.
+    /// The transaction events of a block are sent before its block event.
+    ///
+    /// The channel is unbounded, so the receiver should be drained promptly.
+    /// Dropping the receiver unsubscribes it.
+    pub fn on_transaction_applied(&self) -> Receiver<TransactionAppliedEvent<N>> {
+        let (sender, receiver) = channel();
+        self.subscribers.lock().transactions.push(sender);
+        receiver
+    }
+}
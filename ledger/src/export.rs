@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A row of block data, for export to analytics formats (e.g. CSV or Parquet).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockRow {
+    /// The height of the block.
+    pub height: u32,
+    /// The hash of the block.
+    pub block_hash: String,
+    /// The hash of the previous block.
+    pub previous_hash: String,
+    /// The round of the block.
+    pub round: u64,
+    /// The Unix timestamp (UTC) of the block, in seconds.
+    pub timestamp: i64,
+    /// The coinbase target of the block.
+    pub coinbase_target: u64,
+    /// The proof target of the block.
+    pub proof_target: u64,
+    /// The number of confirmed transactions in the block.
+    pub num_transactions: usize,
+    /// The number of aborted transactions in the block.
+    pub num_aborted_transactions: usize,
+    /// The number of prover solutions in the block.
+    pub num_solutions: usize,
+}
+
+/// A row of confirmed transaction data, for export to analytics formats (e.g. CSV or Parquet).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionRow {
+    /// The height of the block that contains the transaction.
+    pub height: u32,
+    /// The index of the transaction in the block.
+    pub index: u32,
+    /// The ID of the transaction.
+    pub transaction_id: String,
+    /// The confirmed variant, i.e. `accepted_deploy`, `accepted_execute`, `rejected_deploy`, or `rejected_execute`.
+    pub variant: String,
+    /// The total fee of the transaction, in microcredits.
+    pub fee: u64,
+    /// The number of transitions in the transaction.
+    pub num_transitions: usize,
+}
+
+/// A row of record commitment data, for export to analytics formats (e.g. CSV or Parquet).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentRow {
+    /// The height of the block that contains the record.
+    pub height: u32,
+    /// The ID of the transaction that contains the record.
+    pub transaction_id: String,
+    /// The ID of the transition that contains the record.
+    pub transition_id: String,
+    /// The program ID of the transition.
+    pub program_id: String,
+    /// The function name of the transition.
+    pub function_name: String,
+    /// The index of the record in the transition outputs.
+    pub output_index: u8,
+    /// The commitment of the record.
+    pub commitment: String,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns an iterator over the block rows, for the blocks in the given range of heights.
+    ///
+    /// The blocks are loaded one at a time, so the export does not hold the range in memory.
+    pub fn export_blocks(&self, range: Range<u32>) -> impl '_ + Iterator<Item = Result<BlockRow>> {
+        self.export_block_range(range).map(|block| {
+            let block = block?;
+            Ok(BlockRow {
+                height: block.height(),
+                block_hash: block.hash().to_string(),
+                previous_hash: block.previous_hash().to_string(),
+                round: block.round(),
+                timestamp: block.timestamp(),
+                coinbase_target: block.coinbase_target(),
+                proof_target: block.proof_target(),
+                num_transactions: block.transactions().len(),
+                num_aborted_transactions: block.aborted_transaction_ids().len(),
+                num_solutions: block.solutions().map_or(0, |solutions| solutions.len()),
+            })
+        })
+    }
+
+    /// Returns an iterator over the transaction rows, for the confirmed transactions in the given range of heights.
+    pub fn export_transactions(&self, range: Range<u32>) -> impl '_ + Iterator<Item = Result<TransactionRow>> {
+        self.export_block_range(range).flat_map(|block| {
+            let rows = block.and_then(|block| {
+                let height = block.height();
+                block
+                    .transactions()
+                    .iter()
+                    .map(|confirmed| {
+                        let transaction = confirmed.transaction();
+                        Ok(TransactionRow {
+                            height,
+                            index: confirmed.index(),
+                            transaction_id: transaction.id().to_string(),
+                            variant: confirmed.variant().to_string(),
+                            fee: *transaction.fee_amount()?,
+                            num_transitions: transaction.transitions().count(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            });
+            Self::flatten_rows(rows)
+        })
+    }
+
+    /// Returns an iterator over the commitment rows, for the record outputs in the given range of heights.
+    pub fn export_commitments(&self, range: Range<u32>) -> impl '_ + Iterator<Item = Result<CommitmentRow>> {
+        self.export_block_range(range).flat_map(|block| {
+            let rows = block.and_then(|block| {
+                let height = block.height();
+                let mut rows = Vec::new();
+                for confirmed in block.transactions().iter() {
+                    let transaction_id = confirmed.id().to_string();
+                    for transition in confirmed.transaction().transitions() {
+                        for (output_index, output) in transition.outputs().iter().enumerate() {
+                            let Some(commitment) = output.commitment() else { continue };
+                            rows.push(CommitmentRow {
+                                height,
+                                transaction_id: transaction_id.clone(),
+                                transition_id: transition.id().to_string(),
+                                program_id: transition.program_id().to_string(),
+                                function_name: transition.function_name().to_string(),
+                                output_index: u8::try_from(output_index)?,
+                                commitment: commitment.to_string(),
+                            });
+                        }
+                    }
+                }
+                Ok(rows)
+            });
+            Self::flatten_rows(rows)
+        })
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns an iterator over the blocks in the given range of heights, bounded by the latest block height.
+    fn export_block_range(&self, range: Range<u32>) -> impl '_ + Iterator<Item = Result<Block<N>>> {
+        let end = range.end.min(self.latest_height().saturating_add(1));
+        (range.start..end).map(move |height| self.get_block(height))
+    }
+
+    /// Flattens the rows of a block into individual items, or a single error if the block could not be exported.
+    fn flatten_rows<T>(rows: Result<Vec<T>>) -> Vec<Result<T>> {
+        match rows {
+            Ok(rows) => rows.into_iter().map(Ok).collect(),
+            Err(error) => vec![Err(error)],
+        }
+    }
+}
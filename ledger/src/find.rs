@@ -51,6 +51,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transition_store().find_transition_id(id)
     }
 
+    /// Returns the program ID of the transition that contains the given `commitment`.
+    pub fn find_program_id_from_commitment(&self, commitment: &Field<N>) -> Result<Option<ProgramID<N>>> {
+        // Retrieve the transition ID that contains the commitment.
+        let transition_id = self.find_transition_id(commitment)?;
+        // Retrieve the program ID of the transition.
+        self.vm.transition_store().get_program_id(&transition_id)
+    }
+
     /// Returns the record ciphertexts that belong to the given view key.
     pub fn find_record_ciphertexts<'a>(
         &'a self,
@@ -30,6 +30,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_state_root(block_height)
     }
 
+    /// Returns the number of blocks the given state root lags behind the latest block, if the state root exists.
+    pub fn get_state_root_age(&self, state_root: &N::StateRoot) -> Result<Option<u32>> {
+        self.vm.block_store().get_state_root_age(state_root)
+    }
+
+    /// Returns the state roots that may be used in a new transaction, as `(block height, state root)` pairs,
+    /// from the oldest to the latest block.
+    pub fn recent_state_roots(&self) -> Result<Vec<(u32, N::StateRoot)>> {
+        self.vm.block_store().recent_state_roots()
+    }
+
     /// Returns a state path for the given commitment.
     pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
@@ -182,6 +193,22 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the block height and the Merkle path of the given transaction ID to the transactions root of its block,
+    /// which proves the transaction is included in the block (see `HeaderChain::verify_transaction_inclusion`).
+    pub fn get_transaction_inclusion_proof(
+        &self,
+        transaction_id: N::TransactionID,
+    ) -> Result<(u32, TransactionsPath<N>)> {
+        // Retrieve the block hash.
+        let Some(block_hash) = self.find_block_hash(&transaction_id)? else {
+            bail!("Missing block hash for transaction ID {transaction_id}");
+        };
+        // Retrieve the block height.
+        let height = self.get_height(&block_hash)?;
+        // Compute the transactions path.
+        Ok((height, self.get_transactions(height)?.to_path(transaction_id)?))
+    }
+
     /// Returns the unconfirmed transaction for the given `transaction ID`.
     pub fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
         // Retrieve the unconfirmed transaction.
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::consensus_rules::validate_header;
+use console::{
+    network::{prelude::*, Network},
+    program::{BlockTree, StatePath, TransactionsPath},
+    types::Field,
+};
+use ledger_block::{AnchorTarget, DifficultyTarget, Header};
+
+use anyhow::{ensure, Result};
+use std::sync::Arc;
+
+/// A chain of block headers, which verifies the headers without the block bodies.
+///
+/// The header chain checks each header extends the chain: the header follows the consensus rules
+/// in `consensus_rules::validate_header`, and the previous state root matches the block tree of the chain.
+/// Note: The prover solutions and the block authority are not part of the header,
+/// and are therefore not verified by the header chain.
+#[derive(Clone)]
+pub struct HeaderChain<N: Network> {
+    /// The block headers, from the genesis block to the latest block.
+    headers: Vec<Header<N>>,
+    /// The block hashes, from the genesis block to the latest block.
+    block_hashes: Vec<N::BlockHash>,
+    /// The block tree of the block hashes.
+    block_tree: BlockTree<N>,
+    /// The retargeting rule for the coinbase and proof targets.
+    difficulty_target: Arc<dyn DifficultyTarget<N>>,
+}
+
+impl<N: Network> HeaderChain<N> {
+    /// Initializes a new header chain from the given genesis header.
+    pub fn new(genesis_header: Header<N>) -> Result<Self> {
+        // Ensure the header is a genesis header.
+        ensure!(genesis_header.is_genesis(), "The header chain must start from a genesis header");
+        // Compute the genesis block hash.
+        let genesis_hash = Self::compute_block_hash(N::BlockHash::default(), &genesis_header)?;
+        // Construct the block tree.
+        let block_tree = N::merkle_tree_bhp(&[genesis_hash.to_bits_le()])?;
+        // Return the header chain.
+        Ok(Self {
+            headers: vec![genesis_header],
+            block_hashes: vec![genesis_hash],
+            block_tree,
+            difficulty_target: Arc::new(AnchorTarget::default()),
+        })
+    }
+
+    /// Sets the retargeting rule for the coinbase and proof targets of the headers.
+    pub fn with_difficulty_target(mut self, difficulty_target: impl DifficultyTarget<N> + 'static) -> Self {
+        self.difficulty_target = Arc::new(difficulty_target);
+        self
+    }
+
+    /// Returns the latest block height.
+    pub fn latest_height(&self) -> u32 {
+        self.latest_header().height()
+    }
+
+    /// Returns the latest block hash.
+    pub fn latest_hash(&self) -> N::BlockHash {
+        // Note: The header chain always contains the genesis block hash.
+        self.block_hashes[self.block_hashes.len() - 1]
+    }
+
+    /// Returns the latest block header.
+    pub fn latest_header(&self) -> &Header<N> {
+        // Note: The header chain always contains the genesis header.
+        &self.headers[self.headers.len() - 1]
+    }
+
+    /// Returns the latest state root.
+    pub fn latest_state_root(&self) -> N::StateRoot {
+        (*self.block_tree.root()).into()
+    }
+
+    /// Returns the block header for the given block height, if it exists.
+    pub fn get_header(&self, height: u32) -> Option<&Header<N>> {
+        self.headers.get(height as usize)
+    }
+
+    /// Returns the block hash for the given block height, if it exists.
+    pub fn get_hash(&self, height: u32) -> Option<N::BlockHash> {
+        self.block_hashes.get(height as usize).copied()
+    }
+
+    /// Checks the given header is a valid next header, and returns its block hash.
+    pub fn check_next_header(&self, header: &Header<N>, current_timestamp: i64) -> Result<N::BlockHash> {
+        // Ensure the header is a valid successor of the latest header.
+        validate_header(&self.headers, header, &*self.difficulty_target, current_timestamp)?;
+        // Ensure the previous state root is correct.
+        ensure!(
+            header.previous_state_root() == self.latest_state_root(),
+            "Previous state root is incorrect in block {} (found '{}', expected '{}')",
+            header.height(),
+            header.previous_state_root(),
+            self.latest_state_root()
+        );
+
+        // Compute the block hash.
+        Self::compute_block_hash(self.latest_hash(), header)
+    }
+
+    /// Adds the given header as the next header in the chain, and returns its block hash.
+    pub fn add_next_header(&mut self, header: Header<N>, current_timestamp: i64) -> Result<N::BlockHash> {
+        // Ensure the header is valid.
+        let block_hash = self.check_next_header(&header, current_timestamp)?;
+        // Update the block tree.
+        self.block_tree.append(&[block_hash.to_bits_le()])?;
+        // Add the header.
+        self.headers.push(header);
+        self.block_hashes.push(block_hash);
+        Ok(block_hash)
+    }
+
+    /// Ensures the given transaction ID is included in the block at the given height,
+    /// using the Merkle path from the transaction ID to the transactions root of the block header.
+    pub fn verify_transaction_inclusion(
+        &self,
+        height: u32,
+        transaction_id: &N::TransactionID,
+        transactions_path: &TransactionsPath<N>,
+    ) -> Result<()> {
+        // Retrieve the header.
+        let Some(header) = self.get_header(height) else {
+            bail!("Block {height} does not exist in the header chain");
+        };
+        // Ensure the transactions path is valid.
+        ensure!(
+            header.verify_transaction_inclusion(transaction_id, transactions_path),
+            "Transaction '{transaction_id}' is not included in block {height}"
+        );
+        Ok(())
+    }
+
+    /// Ensures the given global state path is valid for the latest state root of the header chain.
+    /// Note: The block path is verified by the block tree of the chain, so it must prove a block in the chain.
+    pub fn verify_state_path(&self, state_path: &StatePath<N>) -> Result<()> {
+        // Ensure the state path is for the latest state root.
+        ensure!(
+            state_path.global_state_root() == self.latest_state_root(),
+            "State path is for '{}', not the latest state root '{}'",
+            state_path.global_state_root(),
+            self.latest_state_root()
+        );
+        // Ensure the state path is valid.
+        // Note: The local state root is not used for a global state path.
+        state_path.verify(true, Field::zero())?;
+        // Ensure the block path is valid for the block tree.
+        ensure!(
+            self.block_tree.verify(
+                state_path.block_path(),
+                self.block_tree.root(),
+                &state_path.block_hash().to_bits_le()
+            ),
+            "Block '{}' is not in the header chain",
+            state_path.block_hash()
+        );
+        Ok(())
+    }
+
+    /// Returns the block hash for the given previous block hash and header.
+    fn compute_block_hash(previous_hash: N::BlockHash, header: &Header<N>) -> Result<N::BlockHash> {
+        Ok(N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?.into())
+    }
+}
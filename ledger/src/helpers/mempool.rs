@@ -0,0 +1,199 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::Network, program::ProgramID, types::Field};
+use ledger_block::{Block, Transaction};
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// A pooled transaction, with its fee and the block height at which it was inserted.
+#[derive(Clone)]
+struct PooledTransaction<N: Network> {
+    /// The transaction.
+    transaction: Transaction<N>,
+    /// The total fee of the transaction, in microcredits.
+    fee: u64,
+    /// The block height at which the transaction was inserted.
+    height: u32,
+}
+
+/// A pool of unconfirmed transactions, which rejects transactions that conflict with a pooled transaction.
+///
+/// The mempool does not verify transactions; the caller is expected to check each transaction
+/// (e.g. with `Ledger::check_transaction_basic`) before inserting it.
+#[derive(Clone)]
+pub struct Mempool<N: Network> {
+    /// The maximum number of transactions in the mempool.
+    capacity: usize,
+    /// The pooled transactions, in insertion order.
+    transactions: IndexMap<N::TransactionID, PooledTransaction<N>>,
+    /// The mapping of `serial number` to the ID of the transaction that spends it.
+    serial_numbers: HashMap<Field<N>, N::TransactionID>,
+    /// The mapping of `program ID` to the ID of the transaction that deploys it.
+    program_ids: HashMap<ProgramID<N>, N::TransactionID>,
+}
+
+impl<N: Network> Mempool<N> {
+    /// Initializes a new mempool with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, transactions: IndexMap::new(), serial_numbers: HashMap::new(), program_ids: HashMap::new() }
+    }
+
+    /// Returns the maximum number of transactions in the mempool.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of transactions in the mempool.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if the mempool is empty.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Returns `true` if the mempool contains the given transaction ID.
+    pub fn contains(&self, transaction_id: &N::TransactionID) -> bool {
+        self.transactions.contains_key(transaction_id)
+    }
+
+    /// Returns the transaction for the given transaction ID.
+    pub fn get(&self, transaction_id: &N::TransactionID) -> Option<&Transaction<N>> {
+        self.transactions.get(transaction_id).map(|pooled| &pooled.transaction)
+    }
+
+    /// Returns an iterator over the transactions, in insertion order.
+    pub fn transactions(&self) -> impl '_ + Iterator<Item = &Transaction<N>> {
+        self.transactions.values().map(|pooled| &pooled.transaction)
+    }
+
+    /// Returns the ID of the pooled transaction that conflicts with the given transaction, if one exists.
+    /// Two transactions conflict if they spend the same serial number, or deploy the same program.
+    pub fn find_conflict(&self, transaction: &Transaction<N>) -> Option<N::TransactionID> {
+        transaction
+            .serial_numbers()
+            .find_map(|serial_number| self.serial_numbers.get(serial_number))
+            .or_else(|| transaction.deployment().and_then(|deployment| self.program_ids.get(deployment.program_id())))
+            .copied()
+    }
+
+    /// Inserts the given transaction into the mempool, at the given block height.
+    ///
+    /// If the mempool is full, the transaction with the lowest fee (and, among those, the oldest)
+    /// is evicted, if its fee is lower than the fee of the given transaction.
+    pub fn insert(&mut self, transaction: Transaction<N>, height: u32) -> Result<()> {
+        // Retrieve the transaction ID.
+        let transaction_id = transaction.id();
+        // Ensure the transaction is not already in the mempool.
+        if self.contains(&transaction_id) {
+            bail!("Transaction '{transaction_id}' already exists in the mempool")
+        }
+        // Ensure the transaction does not conflict with a pooled transaction.
+        if let Some(conflict_id) = self.find_conflict(&transaction) {
+            bail!("Transaction '{transaction_id}' conflicts with transaction '{conflict_id}' in the mempool")
+        }
+        // Retrieve the fee.
+        let fee = *transaction.fee_amount()?;
+
+        // If the mempool is full, evict the lowest-fee transaction, if the given transaction pays more.
+        if self.len() >= self.capacity {
+            match self.lowest_fee_transaction() {
+                Some((lowest_id, lowest_fee)) if lowest_fee < fee => {
+                    self.remove(&lowest_id);
+                }
+                _ => bail!("Transaction '{transaction_id}' does not pay enough to enter the full mempool"),
+            }
+        }
+
+        // Index the serial numbers and the program ID.
+        for serial_number in transaction.serial_numbers() {
+            self.serial_numbers.insert(*serial_number, transaction_id);
+        }
+        if let Some(deployment) = transaction.deployment() {
+            self.program_ids.insert(*deployment.program_id(), transaction_id);
+        }
+        // Insert the transaction.
+        self.transactions.insert(transaction_id, PooledTransaction { transaction, fee, height });
+        Ok(())
+    }
+
+    /// Removes the given transaction ID from the mempool, and returns the transaction, if it exists.
+    pub fn remove(&mut self, transaction_id: &N::TransactionID) -> Option<Transaction<N>> {
+        // Remove the transaction, preserving the insertion order of the remaining transactions.
+        let pooled = self.transactions.shift_remove(transaction_id)?;
+        // Remove the serial numbers and the program ID.
+        for serial_number in pooled.transaction.serial_numbers() {
+            self.serial_numbers.remove(serial_number);
+        }
+        if let Some(deployment) = pooled.transaction.deployment() {
+            self.program_ids.remove(deployment.program_id());
+        }
+        Some(pooled.transaction)
+    }
+
+    /// Removes the transactions in the given block, and the transactions that conflict with them,
+    /// and returns the removed transactions.
+    pub fn remove_confirmed(&mut self, block: &Block<N>) -> Result<Vec<Transaction<N>>> {
+        let mut removed = Vec::new();
+        for confirmed in block.transactions().iter() {
+            // Remove the unconfirmed transaction, if it is pooled.
+            removed.extend(self.remove(&confirmed.to_unconfirmed_transaction_id()?));
+            // Remove the pooled transactions that conflict with the confirmed transaction.
+            while let Some(conflict_id) = self.find_conflict(confirmed.transaction()) {
+                removed.extend(self.remove(&conflict_id));
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Removes the transactions that were inserted more than `max_age` blocks before the given block height,
+    /// and returns the removed transactions.
+    pub fn remove_expired(&mut self, height: u32, max_age: u32) -> Vec<Transaction<N>> {
+        // Determine the expired transaction IDs.
+        let expired_ids = self
+            .transactions
+            .iter()
+            .filter(|(_, pooled)| height.saturating_sub(pooled.height) > max_age)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        // Remove the expired transactions.
+        expired_ids.iter().filter_map(|id| self.remove(id)).collect()
+    }
+
+    /// Returns up to `max_transactions` candidate transactions for the next block,
+    /// ordered by highest fee first, and then by oldest first.
+    pub fn candidates(&self, max_transactions: usize) -> Vec<&Transaction<N>> {
+        let mut candidates = self.transactions.values().enumerate().collect::<Vec<_>>();
+        // Sort by fee (descending), then by height and insertion order (ascending).
+        candidates.sort_by(|(index_a, a), (index_b, b)| {
+            b.fee.cmp(&a.fee).then(a.height.cmp(&b.height)).then(index_a.cmp(index_b))
+        });
+        candidates.into_iter().take(max_transactions).map(|(_, pooled)| &pooled.transaction).collect()
+    }
+
+    /// Returns the ID and fee of the transaction with the lowest fee, preferring the oldest among equal fees.
+    fn lowest_fee_transaction(&self) -> Option<(N::TransactionID, u64)> {
+        self.transactions
+            .iter()
+            .enumerate()
+            .min_by(|(index_a, (_, a)), (index_b, (_, b))| {
+                a.fee.cmp(&b.fee).then(a.height.cmp(&b.height)).then(index_a.cmp(index_b))
+            })
+            .map(|(_, (id, pooled))| (*id, pooled.fee))
+    }
+}
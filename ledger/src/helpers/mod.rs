@@ -15,5 +15,14 @@
 mod bft;
 pub use bft::*;
 
+mod header_chain;
+pub use header_chain::*;
+
+mod mempool;
+pub use mempool::*;
+
+mod record_selector;
+pub use record_selector::*;
+
 mod supply;
 pub use supply::*;
@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::Network,
+    program::{Entry, Identifier, Literal, Plaintext, Record},
+};
+
+use anyhow::{bail, Result};
+use core::str::FromStr;
+
+/// A strategy for selecting the records to spend, to cover a target amount.
+///
+/// Selectors operate on the balances of the candidate records, and return indices into them,
+/// so they can be used with any collection of records.
+pub trait RecordSelector {
+    /// Returns the indices of at least one and at most `max_records` of the given balances,
+    /// whose sum is at least `target`, or `None` if no such selection exists.
+    fn select(&self, balances: &[u64], target: u64, max_records: usize) -> Option<Vec<usize>>;
+}
+
+/// Selects the records with the largest balances first, until the target is covered.
+///
+/// This minimizes the number of records spent, and consolidates large records over time.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LargestFirst;
+
+impl RecordSelector for LargestFirst {
+    fn select(&self, balances: &[u64], target: u64, max_records: usize) -> Option<Vec<usize>> {
+        // Sort the indices by balance, in descending order (ties keep their original order).
+        let mut indices = (0..balances.len()).collect::<Vec<_>>();
+        indices.sort_by(|a, b| balances[*b].cmp(&balances[*a]));
+
+        // Select the largest records until the target is covered.
+        let mut selected = Vec::new();
+        let mut total = 0u64;
+        for index in indices.into_iter().take(max_records) {
+            if !selected.is_empty() && total >= target {
+                break;
+            }
+            selected.push(index);
+            total = total.saturating_add(balances[index]);
+        }
+        (!selected.is_empty() && total >= target).then_some(selected)
+    }
+}
+
+/// Selects the records whose sum exceeds the target by the least amount (i.e. the least change),
+/// preferring fewer records among equal sums.
+///
+/// The search is a depth-first branch-and-bound over the records, sorted by balance, that stops after
+/// `max_iterations` steps. If no selection is found within the budget, it falls back to `LargestFirst`.
+#[derive(Copy, Clone, Debug)]
+pub struct BranchAndBound {
+    /// The maximum number of search steps.
+    pub max_iterations: usize,
+}
+
+impl Default for BranchAndBound {
+    fn default() -> Self {
+        Self { max_iterations: 100_000 }
+    }
+}
+
+impl RecordSelector for BranchAndBound {
+    fn select(&self, balances: &[u64], target: u64, max_records: usize) -> Option<Vec<usize>> {
+        // Sort the indices by balance, in descending order (ties keep their original order).
+        let mut indices = (0..balances.len()).collect::<Vec<_>>();
+        indices.sort_by(|a, b| balances[*b].cmp(&balances[*a]));
+        let values = indices.iter().map(|index| balances[*index]).collect::<Vec<_>>();
+
+        // Compute the suffix sums, to prune the branches that cannot reach the target.
+        let mut suffix_sums = vec![0u64; values.len() + 1];
+        for i in (0..values.len()).rev() {
+            suffix_sums[i] = suffix_sums[i + 1].saturating_add(values[i]);
+        }
+
+        // Search for the selection with the least change.
+        let mut search = Search {
+            values: &values,
+            suffix_sums: &suffix_sums,
+            target,
+            max_records,
+            iterations_left: self.max_iterations,
+            current: Vec::new(),
+            best: None,
+        };
+        search.run(0, 0);
+
+        match search.best {
+            Some((_, best)) => Some(best.into_iter().map(|i| indices[i]).collect()),
+            None => LargestFirst.select(balances, target, max_records),
+        }
+    }
+}
+
+/// The state of a branch-and-bound search.
+struct Search<'a> {
+    /// The balances, in descending order.
+    values: &'a [u64],
+    /// The sum of the balances from each index onwards.
+    suffix_sums: &'a [u64],
+    /// The target amount.
+    target: u64,
+    /// The maximum number of selected balances.
+    max_records: usize,
+    /// The remaining number of search steps.
+    iterations_left: usize,
+    /// The selected positions on the current branch.
+    current: Vec<usize>,
+    /// The change and the positions of the best selection found so far.
+    best: Option<(u64, Vec<usize>)>,
+}
+
+impl Search<'_> {
+    /// Explores the selections that extend the current branch with the balances from `index` onwards.
+    fn run(&mut self, index: usize, sum: u64) {
+        // Stop if the budget is exhausted.
+        if self.iterations_left == 0 {
+            return;
+        }
+        self.iterations_left -= 1;
+
+        // If the target is covered, record the selection, as adding more balances only increases the change.
+        if !self.current.is_empty() && sum >= self.target {
+            let change = sum - self.target;
+            let is_better = match &self.best {
+                Some((best_change, best)) => (change, self.current.len()) < (*best_change, best.len()),
+                None => true,
+            };
+            if is_better {
+                self.best = Some((change, self.current.clone()));
+            }
+            return;
+        }
+        // Stop if an exact match with no more records than this branch needs was already found.
+        if matches!(&self.best, Some((0, best)) if best.len() <= self.current.len() + 1) {
+            return;
+        }
+        // Stop if no balances are left, or the remaining balances cannot reach the target.
+        if index == self.values.len()
+            || self.current.len() == self.max_records
+            || sum.saturating_add(self.suffix_sums[index]) < self.target
+        {
+            return;
+        }
+
+        // Explore the branch that includes the balance at `index`.
+        self.current.push(index);
+        self.run(index + 1, sum.saturating_add(self.values[index]));
+        self.current.pop();
+        // Explore the branch that excludes the balance at `index`.
+        self.run(index + 1, sum);
+    }
+}
+
+/// Returns the balance of the given `credits.aleo` record, in microcredits.
+pub(crate) fn microcredits<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<u64> {
+    match record.data().get(&Identifier::from_str("microcredits")?) {
+        Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => Ok(**amount),
+        _ => bail!("The record is not a 'credits.aleo' record"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BALANCES: [u64; 6] = [50, 10, 30, 5, 25, 100];
+
+    fn sum(indices: &[usize]) -> u64 {
+        indices.iter().map(|index| BALANCES[*index]).sum()
+    }
+
+    #[test]
+    fn test_largest_first() {
+        // Ensure the largest records are selected first.
+        assert_eq!(LargestFirst.select(&BALANCES, 120, 6), Some(vec![5, 0]));
+        assert_eq!(LargestFirst.select(&BALANCES, 100, 6), Some(vec![5]));
+        // Ensure at least one record is selected.
+        assert_eq!(LargestFirst.select(&BALANCES, 0, 6), Some(vec![5]));
+        // Ensure the maximum number of records is respected.
+        assert_eq!(LargestFirst.select(&BALANCES, 160, 2), None);
+        assert_eq!(LargestFirst.select(&BALANCES, 221, 6), None);
+        assert_eq!(LargestFirst.select(&[], 0, 6), None);
+    }
+
+    #[test]
+    fn test_branch_and_bound() {
+        let selector = BranchAndBound::default();
+        // Ensure an exact match is found.
+        let selected = selector.select(&BALANCES, 65, 6).unwrap();
+        assert_eq!(sum(&selected), 65);
+        let selected = selector.select(&BALANCES, 40, 6).unwrap();
+        assert_eq!(sum(&selected), 40);
+        // Ensure the least change is preferred.
+        let selected = selector.select(&BALANCES, 4, 1).unwrap();
+        assert_eq!(selected, vec![3]);
+        // Ensure fewer records are preferred among equal sums.
+        assert_eq!(selector.select(&BALANCES, 30, 6), Some(vec![2]));
+        // Ensure the maximum number of records is respected.
+        let selected = selector.select(&BALANCES, 160, 2).unwrap_or_default();
+        assert!(selected.is_empty());
+        let selected = selector.select(&BALANCES, 170, 3).unwrap();
+        assert_eq!((selected.len(), sum(&selected)), (3, 175));
+        // Ensure an unreachable target fails.
+        assert_eq!(selector.select(&BALANCES, 221, 6), None);
+    }
+
+    #[test]
+    fn test_branch_and_bound_falls_back() {
+        // Ensure an exhausted budget falls back to the largest records first.
+        let selector = BranchAndBound { max_iterations: 0 };
+        assert_eq!(selector.select(&BALANCES, 120, 6), Some(vec![5, 0]));
+    }
+}
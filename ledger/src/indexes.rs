@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The location of a record output in the ledger.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CommitmentLocation<N: Network> {
+    /// The height of the block that contains the record.
+    height: u32,
+    /// The ID of the transaction that contains the record.
+    transaction_id: N::TransactionID,
+    /// The ID of the transition that contains the record.
+    transition_id: N::TransitionID,
+    /// The index of the record in the transition outputs.
+    output_index: u8,
+}
+
+impl<N: Network> CommitmentLocation<N> {
+    /// Returns the height of the block that contains the record.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the ID of the transaction that contains the record.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the ID of the transition that contains the record.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the index of the record in the transition outputs.
+    pub const fn output_index(&self) -> u8 {
+        self.output_index
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the location of the record output with the given `commitment`, if it exists.
+    pub fn find_commitment_location(&self, commitment: &Field<N>) -> Result<Option<CommitmentLocation<N>>> {
+        // Ensure the commitment exists.
+        if !self.contains_commitment(commitment)? {
+            return Ok(None);
+        }
+        // Retrieve the transition ID that contains the commitment.
+        let transition_id = self.find_transition_id(commitment)?;
+        // Determine the index of the commitment in the transition outputs.
+        let output_ids = self.vm.transition_store().get_output_ids(&transition_id)?;
+        let output_index = match output_ids.iter().position(|output_id| output_id == commitment) {
+            Some(output_index) => u8::try_from(output_index)?,
+            None => bail!("Missing commitment '{commitment}' in the outputs of transition '{transition_id}'"),
+        };
+        // Retrieve the transaction ID that contains the transition.
+        let transaction_id = self.find_containing_transaction_id(&transition_id)?;
+        // Retrieve the height of the block that contains the transaction.
+        let height = self.find_containing_block_height(&transaction_id)?;
+        // Return the location.
+        Ok(Some(CommitmentLocation { height, transaction_id, transition_id, output_index }))
+    }
+
+    /// Returns the ID of the transaction that spends the given `serial number`, if it exists.
+    pub fn find_spending_transaction_id(&self, serial_number: &Field<N>) -> Result<Option<N::TransactionID>> {
+        // Ensure the serial number exists.
+        if !self.contains_serial_number(serial_number)? {
+            return Ok(None);
+        }
+        // Retrieve the transition ID that contains the serial number.
+        let transition_id = self.find_transition_id(serial_number)?;
+        // Retrieve the transaction ID that contains the transition.
+        self.find_containing_transaction_id(&transition_id).map(Some)
+    }
+
+    /// Returns an iterator over the `(commitment, location)` pairs, for all record outputs.
+    pub fn commitment_locations(&self) -> impl '_ + Iterator<Item = Result<(Field<N>, CommitmentLocation<N>)>> {
+        self.commitments().map(move |commitment| {
+            let commitment = commitment.into_owned();
+            match self.find_commitment_location(&commitment)? {
+                Some(location) => Ok((commitment, location)),
+                None => bail!("Missing location for commitment '{commitment}'"),
+            }
+        })
+    }
+
+    /// Returns an iterator over the `(serial number, transaction ID)` pairs, for all record inputs,
+    /// where the transaction ID is the ID of the transaction that spends the serial number.
+    pub fn spent_serial_numbers(&self) -> impl '_ + Iterator<Item = Result<(Field<N>, N::TransactionID)>> {
+        self.serial_numbers().map(move |serial_number| {
+            let serial_number = serial_number.into_owned();
+            match self.find_spending_transaction_id(&serial_number)? {
+                Some(transaction_id) => Ok((serial_number, transaction_id)),
+                None => bail!("Missing spending transaction for serial number '{serial_number}'"),
+            }
+        })
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the ID of the transaction that contains the given `transition ID`, or an error if it does not exist.
+    fn find_containing_transaction_id(&self, transition_id: &N::TransitionID) -> Result<N::TransactionID> {
+        match self.find_transaction_id_from_transition_id(transition_id)? {
+            Some(transaction_id) => Ok(transaction_id),
+            None => bail!("Missing transaction ID for transition '{transition_id}'"),
+        }
+    }
+
+    /// Returns the height of the block that contains the given `transaction ID`, or an error if it does not exist.
+    fn find_containing_block_height(&self, transaction_id: &N::TransactionID) -> Result<u32> {
+        match self.find_block_hash(transaction_id)? {
+            Some(block_hash) => self.get_height(&block_hash),
+            None => bail!("Missing block hash for transaction '{transaction_id}'"),
+        }
+    }
+}
@@ -31,16 +31,33 @@ pub use crate::block::*;
 #[cfg(feature = "test-helpers")]
 pub use ledger_test_helpers;
 
+#[cfg(feature = "bench")]
+pub mod bench;
+
 mod helpers;
 pub use helpers::*;
 
+pub mod consensus_rules;
+
 mod advance;
+mod block_template;
+pub use block_template::*;
 mod check_next_block;
 mod check_transaction_basic;
 mod contains;
+mod events;
+pub use events::*;
+mod export;
+pub use export::*;
 mod find;
 mod get;
+mod indexes;
+pub use indexes::*;
 mod iterators;
+mod scan;
+pub use scan::*;
+mod snapshot;
+pub use snapshot::*;
 
 #[cfg(test)]
 mod tests;
@@ -48,7 +65,18 @@ mod tests;
 use console::{
     account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, Value},
+    program::{
+        Ciphertext,
+        Entry,
+        Identifier,
+        Literal,
+        Plaintext,
+        ProgramID,
+        Record,
+        StatePath,
+        TransactionsPath,
+        Value,
+    },
     types::{Field, Group},
 };
 use ledger_authority::Authority;
@@ -65,9 +93,9 @@ use synthesizer::{
 
 use aleo_std::prelude::{finish, lap, timer};
 use anyhow::Result;
-use core::ops::Range;
+use core::ops::{Range, RangeInclusive};
 use indexmap::IndexMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rand::{prelude::IteratorRandom, rngs::OsRng};
 use std::{borrow::Cow, sync::Arc};
 use time::OffsetDateTime;
@@ -105,6 +133,14 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The lock that serializes block updates, so readers are not blocked while a block is applied.
+    advance_lock: Arc<Mutex<()>>,
+    /// The number of latest blocks whose transaction bodies are retained, if pruning is enabled.
+    pruning_depth: Option<u32>,
+    /// The retargeting rule for the coinbase and proof targets.
+    difficulty_target: Arc<dyn DifficultyTarget<N>>,
+    /// The subscribers to the ledger events.
+    subscribers: Arc<Mutex<Subscribers<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -166,6 +202,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            advance_lock: Default::default(),
+            pruning_depth: None,
+            difficulty_target: Arc::new(AnchorTarget::default()),
+            subscribers: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -194,6 +234,48 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(ledger)
     }
 
+    /// Enables pruning, retaining the transaction proofs and record ciphertexts of only the latest `depth` blocks.
+    /// Pruning applies to the blocks that are added after it is enabled; headers are always retained.
+    pub fn with_pruning(mut self, depth: u32) -> Result<Self> {
+        // Ensure the pruning depth retains at least the latest block.
+        ensure!(depth >= 1, "The pruning depth must be at least 1");
+        self.pruning_depth = Some(depth);
+        Ok(self)
+    }
+
+    /// Returns the pruning depth, if pruning is enabled.
+    pub const fn pruning_depth(&self) -> Option<u32> {
+        self.pruning_depth
+    }
+
+    /// Returns the range of block heights whose headers are available.
+    pub fn available_headers(&self) -> RangeInclusive<u32> {
+        0..=self.latest_height()
+    }
+
+    /// Returns the range of block heights whose transaction bodies are available,
+    /// which starts after the highest pruned block height in storage.
+    /// Note: The genesis block is never pruned, but it is excluded from the range once any block is pruned.
+    pub fn available_bodies(&self) -> Result<RangeInclusive<u32>> {
+        let latest_height = self.latest_height();
+        match self.vm.block_store().pruned_height()? {
+            Some(pruned_height) => Ok(pruned_height.saturating_add(1)..=latest_height),
+            None => Ok(0..=latest_height),
+        }
+    }
+
+    /// Sets the retargeting rule for the coinbase and proof targets of the blocks that are produced and verified.
+    /// Note: All nodes on a network must use the same retargeting rule.
+    pub fn with_difficulty_target(mut self, difficulty_target: impl DifficultyTarget<N> + 'static) -> Self {
+        self.difficulty_target = Arc::new(difficulty_target);
+        self
+    }
+
+    /// Returns the retargeting rule for the coinbase and proof targets.
+    pub fn difficulty_target(&self) -> &dyn DifficultyTarget<N> {
+        self.difficulty_target.as_ref()
+    }
+
     /// Returns the VM.
     pub const fn vm(&self) -> &VM<N, C> {
         &self.vm
@@ -302,17 +384,37 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the unspent records of the given program, that belong to the given view key.
+    ///
+    /// Note: As a record commitment is bound to its program ID, the program ID identifies the asset
+    /// (e.g. a token) that the records hold.
+    pub fn find_unspent_program_records(
+        &self,
+        view_key: &ViewKey<N>,
+        program_id: &ProgramID<N>,
+    ) -> Result<RecordMap<N>> {
+        Ok(self
+            .find_records(view_key, RecordsFilter::Unspent)?
+            .filter(|(commitment, _)| match self.find_program_id_from_commitment(commitment) {
+                Ok(Some(record_program_id)) => record_program_id == *program_id,
+                Ok(None) => false,
+                Err(e) => {
+                    warn!("Failed to find the program ID for record commitment '{commitment}': {e}");
+                    false
+                }
+            })
+            .collect::<IndexMap<_, _>>())
+    }
+
     /// Returns the unspent `credits.aleo` records.
     pub fn find_unspent_credits_records(&self, view_key: &ViewKey<N>) -> Result<RecordMap<N>> {
         let microcredits = Identifier::from_str("microcredits")?;
         Ok(self
-            .find_records(view_key, RecordsFilter::Unspent)?
-            .filter(|(_, record)| {
-                // TODO (raychu86): Find cleaner approach and check that the record is associated with the `credits.aleo` program
-                match record.data().get(&microcredits) {
-                    Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
-                    _ => false,
-                }
+            .find_unspent_program_records(view_key, &ProgramID::from_str("credits.aleo")?)?
+            .into_iter()
+            .filter(|(_, record)| match record.data().get(&microcredits) {
+                Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
+                _ => false,
             })
             .collect::<IndexMap<_, _>>())
     }
@@ -331,13 +433,22 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Fetch the unspent records.
         let records = self.find_unspent_credits_records(&ViewKey::try_from(private_key)?)?;
         ensure!(!records.len().is_zero(), "The Aleo account has no records to spend.");
-        let mut records = records.values();
-
-        // Prepare the fee record.
-        let fee_record = Some(records.next().unwrap().clone());
+        let records = records.into_values().collect::<Vec<_>>();
+        let balances = records.iter().map(helpers::microcredits).collect::<Result<Vec<_>>>()?;
 
-        // Create a new deploy transaction.
-        self.vm.deploy(private_key, program, fee_record, priority_fee_in_microcredits, query, rng)
+        // Create a new deploy transaction, paying the total fee with the largest record.
+        self.vm.deploy_with_fee_selector(
+            private_key,
+            program,
+            |base_fee_in_microcredits| {
+                let fee_index =
+                    Self::select_fee_record(&balances, base_fee_in_microcredits, priority_fee_in_microcredits)?;
+                Ok(Some(records[fee_index].clone()))
+            },
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
     }
 
     /// Creates a transfer transaction.
@@ -354,30 +465,58 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     ) -> Result<Transaction<N>> {
         // Fetch the unspent records.
         let records = self.find_unspent_credits_records(&ViewKey::try_from(private_key)?)?;
-        ensure!(!records.len().is_zero(), "The Aleo account has no records to spend.");
-        let mut records = records.values();
+        ensure!(records.len() >= 2, "The Aleo account needs a record to transfer, and another to pay the fee.");
+        let mut records = records.into_values().collect::<Vec<_>>();
+        let mut balances = records.iter().map(helpers::microcredits).collect::<Result<Vec<_>>>()?;
+
+        // Select the record to transfer from, leaving the least change.
+        let record = match BranchAndBound::default().select(&balances, amount_in_microcredits, 1) {
+            Some(selected) => {
+                balances.remove(selected[0]);
+                records.remove(selected[0])
+            }
+            None => bail!("The Aleo account has no record to transfer {amount_in_microcredits} from"),
+        };
 
         // Prepare the inputs.
         let inputs = [
-            Value::Record(records.next().unwrap().clone()),
+            Value::Record(record),
             Value::from_str(&format!("{to}"))?,
             Value::from_str(&format!("{amount_in_microcredits}u64"))?,
         ];
 
-        // Prepare the fee.
-        let fee_record = Some(records.next().unwrap().clone());
-
-        // Create a new execute transaction.
-        self.vm.execute(
+        // Create a new execute transaction, paying the total fee with the largest remaining record.
+        self.vm.execute_with_fee_selector(
             private_key,
             ("credits.aleo", "transfer_private"),
-            inputs.iter(),
-            fee_record,
+            inputs.into_iter(),
+            |base_fee_in_microcredits| {
+                let fee_index =
+                    Self::select_fee_record(&balances, base_fee_in_microcredits, priority_fee_in_microcredits)?;
+                Ok(Some(records[fee_index].clone()))
+            },
             priority_fee_in_microcredits,
             query,
             rng,
         )
     }
+
+    /// Returns the index of the largest record, if it covers the total fee,
+    /// which is the minimum (base) fee of the transaction plus the priority fee.
+    fn select_fee_record(
+        balances: &[u64],
+        base_fee_in_microcredits: u64,
+        priority_fee_in_microcredits: u64,
+    ) -> Result<usize> {
+        let total_fee = base_fee_in_microcredits.saturating_add(priority_fee_in_microcredits);
+        match LargestFirst.select(balances, total_fee, 1) {
+            Some(selected) => Ok(selected[0]),
+            None => bail!(
+                "The Aleo account has no record to pay the fee of {total_fee} microcredits \
+                 ({base_fee_in_microcredits} base fee + {priority_fee_in_microcredits} priority fee)"
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
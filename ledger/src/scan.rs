@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A change to the records of an account, observed while scanning the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordDelta<N: Network> {
+    /// A record belonging to the account was created in the block at the given height.
+    Received { height: u32, program_id: ProgramID<N>, commitment: Field<N>, record: Record<N, Plaintext<N>> },
+    /// A record belonging to the account was spent in the block at the given height.
+    Spent { height: u32, program_id: ProgramID<N>, commitment: Field<N>, record: Record<N, Plaintext<N>> },
+}
+
+impl<N: Network> RecordDelta<N> {
+    /// Returns the height of the block in which the delta occurred.
+    pub const fn height(&self) -> u32 {
+        match self {
+            Self::Received { height, .. } | Self::Spent { height, .. } => *height,
+        }
+    }
+
+    /// Returns the program ID of the record.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        match self {
+            Self::Received { program_id, .. } | Self::Spent { program_id, .. } => program_id,
+        }
+    }
+
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        match self {
+            Self::Received { commitment, .. } | Self::Spent { commitment, .. } => commitment,
+        }
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        match self {
+            Self::Received { record, .. } | Self::Spent { record, .. } => record,
+        }
+    }
+
+    /// Returns `true` if the delta is a spend of the record.
+    pub const fn is_spent(&self) -> bool {
+        matches!(self, Self::Spent { .. })
+    }
+
+    /// Returns the signed change in the private `credits.aleo` balance, in microcredits.
+    /// Returns `None` if the record is not a `credits.aleo` record.
+    pub fn balance_delta(&self) -> Option<i128> {
+        // Ensure the record belongs to `credits.aleo`.
+        if self.program_id().to_string() != "credits.aleo" {
+            return None;
+        }
+        // Retrieve the amount of microcredits in the record.
+        let amount = match self.record().data().get(&Identifier::from_str("microcredits").ok()?) {
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => **amount as i128,
+            _ => return None,
+        };
+        match self {
+            Self::Received { .. } => Some(amount),
+            Self::Spent { .. } => Some(-amount),
+        }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Scans the blocks in the given range of heights for records that belong to the given view key,
+    /// and returns the record deltas for the account, ordered by block height.
+    ///
+    /// The blocks are trial-decrypted in parallel. A spend is detected by matching the tags of the
+    /// block inputs against the tags of the records received, so only records received within the
+    /// given range are reported as spent. To track an account from scratch, scan from genesis.
+    pub fn scan(&self, view_key: &ViewKey<N>, range: Range<u32>) -> Result<Vec<RecordDelta<N>>> {
+        let timer = timer!("Ledger::scan");
+
+        // Derive the x-coordinate of the address corresponding to the given view key.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+
+        // Bound the range by the latest block height.
+        let end = range.end.min(self.latest_height().saturating_add(1));
+        let heights = (range.start..end).collect::<Vec<_>>();
+
+        // For each block, collect the tagged records that belong to the account, and the tags of the inputs.
+        let scanned = cfg_into_iter!(heights)
+            .map(|height| {
+                let block = self.get_block(height)?;
+
+                let mut received = Vec::new();
+                for transition in block.transitions() {
+                    for (commitment, record) in transition.records() {
+                        // Skip the record if it does not belong to the account.
+                        if !record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                            continue;
+                        }
+                        // Decrypt the record, and compute its tag.
+                        let record = record.decrypt(view_key)?;
+                        let tag = Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?;
+                        let (program_id, commitment) = (*transition.program_id(), *commitment);
+                        received.push((tag, RecordDelta::Received { height, program_id, commitment, record }));
+                    }
+                }
+                let tags = block.tags().copied().collect::<Vec<_>>();
+
+                Ok((height, received, tags))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Trial-decrypt {} blocks", scanned.len());
+
+        // Match the input tags against the records received, in order of block height.
+        let mut unspent = IndexMap::new();
+        let mut deltas = Vec::new();
+        for (height, received, tags) in scanned {
+            for (tag, delta) in received {
+                unspent.insert(tag, delta.clone());
+                deltas.push(delta);
+            }
+            for tag in tags {
+                if let Some(delta) = unspent.swap_remove(&tag) {
+                    let program_id = *delta.program_id();
+                    let commitment = *delta.commitment();
+                    let record = delta.record().clone();
+                    deltas.push(RecordDelta::Spent { height, program_id, commitment, record });
+                }
+            }
+        }
+        finish!(timer);
+
+        Ok(deltas)
+    }
+}
@@ -0,0 +1,391 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// A snapshot of the ledger state up to a block height, consisting of the block headers,
+/// and the serial numbers and commitments in the blocks.
+///
+/// The headers are authenticated by their block hashes, which chain from the genesis block.
+/// The serial numbers and commitments are not authenticated by the headers alone,
+/// so a snapshot must come from a trusted source, or be authenticated against the blocks with `Ledger::bootstrap`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Snapshot<N: Network> {
+    /// The block headers, from the genesis block to the snapshot height.
+    headers: Vec<Header<N>>,
+    /// The block hashes, computed from the block headers.
+    block_hashes: Vec<N::BlockHash>,
+    /// The serial numbers in the blocks.
+    serial_numbers: IndexSet<Field<N>>,
+    /// The commitments in the blocks.
+    commitments: IndexSet<Field<N>>,
+}
+
+impl<N: Network> Snapshot<N> {
+    /// The version of the snapshot encoding.
+    pub const VERSION: u8 = 1;
+
+    /// Initializes a new snapshot from the given block headers, serial numbers, and commitments.
+    /// This method ensures the headers form a chain from the genesis block.
+    pub fn new(
+        headers: Vec<Header<N>>,
+        serial_numbers: IndexSet<Field<N>>,
+        commitments: IndexSet<Field<N>>,
+    ) -> Result<Self> {
+        // Ensure there is at least one header.
+        ensure!(!headers.is_empty(), "A snapshot must contain at least the genesis header");
+
+        // Ensure the header heights are sequential.
+        for (index, header) in headers.iter().enumerate() {
+            ensure!(header.height() as usize == index, "Invalid header height {} in the snapshot", header.height());
+        }
+        // Compute the header roots in parallel, as they are independent of one another.
+        let header_roots = cfg_iter!(headers).map(|header| header.to_root()).collect::<Result<Vec<_>>>()?;
+        // Compute the block hashes, each of which chains the previous block hash.
+        let mut block_hashes = Vec::with_capacity(headers.len());
+        let mut previous_hash = N::BlockHash::default();
+        for header_root in header_roots {
+            let block_hash: N::BlockHash = N::hash_bhp1024(&to_bits_le![previous_hash, header_root])?.into();
+            block_hashes.push(block_hash);
+            previous_hash = block_hash;
+        }
+
+        // Ensure the last header commits to the state root of the preceding blocks.
+        if let Some((last_header, _)) = headers.split_last().filter(|(_, preceding)| !preceding.is_empty()) {
+            let expected_state_root = Self::compute_state_root(&block_hashes[..block_hashes.len() - 1])?;
+            ensure!(
+                last_header.previous_state_root() == expected_state_root,
+                "The previous state root in the snapshot header {} is incorrect",
+                last_header.height()
+            );
+        }
+
+        Ok(Self { headers, block_hashes, serial_numbers, commitments })
+    }
+
+    /// Returns the block height of the snapshot.
+    pub fn height(&self) -> u32 {
+        // Note: The headers are non-empty, and the height of each header is its index.
+        self.headers.last().map_or(0, |header| header.height())
+    }
+
+    /// Returns the block headers, from the genesis block to the snapshot height.
+    pub fn headers(&self) -> &[Header<N>] {
+        &self.headers
+    }
+
+    /// Returns the block hashes, from the genesis block to the snapshot height.
+    pub fn block_hashes(&self) -> &[N::BlockHash] {
+        &self.block_hashes
+    }
+
+    /// Returns the serial numbers in the snapshot.
+    pub const fn serial_numbers(&self) -> &IndexSet<Field<N>> {
+        &self.serial_numbers
+    }
+
+    /// Returns the commitments in the snapshot.
+    pub const fn commitments(&self) -> &IndexSet<Field<N>> {
+        &self.commitments
+    }
+
+    /// Returns `true` if the snapshot contains the given serial number.
+    pub fn contains_serial_number(&self, serial_number: &Field<N>) -> bool {
+        self.serial_numbers.contains(serial_number)
+    }
+
+    /// Returns `true` if the snapshot contains the given commitment.
+    pub fn contains_commitment(&self, commitment: &Field<N>) -> bool {
+        self.commitments.contains(commitment)
+    }
+
+    /// Returns the state root at the snapshot height.
+    pub fn state_root(&self) -> Result<N::StateRoot> {
+        Self::compute_state_root(&self.block_hashes)
+    }
+
+    /// Returns the checksum of the snapshot.
+    ///
+    /// The checksum detects a corrupted snapshot, but it is not authenticated, as anyone can recompute it.
+    pub fn checksum(&self) -> Result<Field<N>> {
+        // Prepare the preimage, starting with the snapshot height.
+        let mut preimage =
+            Vec::with_capacity(1 + self.headers.len() + self.serial_numbers.len() + self.commitments.len());
+        preimage.push(Field::from_u32(self.height()));
+        // Include the block hashes, which commit to the block headers.
+        preimage.extend(self.block_hashes.iter().map(|block_hash| **block_hash));
+        // Include the serial numbers and commitments.
+        preimage.extend(self.serial_numbers.iter().copied());
+        preimage.extend(self.commitments.iter().copied());
+        // Hash the preimage.
+        N::hash_psd8(&preimage)
+    }
+
+    /// Returns the state root of the block tree over the given block hashes.
+    fn compute_state_root(block_hashes: &[N::BlockHash]) -> Result<N::StateRoot> {
+        let leaves = block_hashes.iter().map(|block_hash| block_hash.to_bits_le()).collect::<Vec<_>>();
+        Ok((*N::merkle_tree_bhp::<{ console::program::BLOCKS_DEPTH }>(&leaves)?.root()).into())
+    }
+}
+
+impl<N: Network> FromBytes for Snapshot<N> {
+    /// Reads the snapshot from the buffer, and ensures the checksum is correct.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != Self::VERSION {
+            return Err(error("Invalid snapshot version"));
+        }
+        // Read the height.
+        let height = u32::read_le(&mut reader)?;
+        // Read the headers.
+        let headers = (0..=height).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        // Read the serial numbers.
+        let num_serial_numbers = u64::read_le(&mut reader)?;
+        let serial_numbers =
+            (0..num_serial_numbers).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<IndexSet<_>, _>>()?;
+        // Read the commitments.
+        let num_commitments = u64::read_le(&mut reader)?;
+        let commitments =
+            (0..num_commitments).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<IndexSet<_>, _>>()?;
+        // Read the checksum.
+        let checksum = Field::read_le(&mut reader)?;
+
+        // Ensure the serial numbers and commitments are unique.
+        if serial_numbers.len() as u64 != num_serial_numbers || commitments.len() as u64 != num_commitments {
+            return Err(error("Found a duplicate serial number or commitment in the snapshot"));
+        }
+        // Construct the snapshot.
+        let snapshot = Self::new(headers, serial_numbers, commitments).map_err(|e| error(e.to_string()))?;
+        // Ensure the checksum is correct.
+        if snapshot.checksum().map_err(|e| error(e.to_string()))? != checksum {
+            return Err(error("Invalid snapshot checksum"));
+        }
+        Ok(snapshot)
+    }
+}
+
+impl<N: Network> ToBytes for Snapshot<N> {
+    /// Writes the snapshot to the buffer, followed by its checksum.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        Self::VERSION.write_le(&mut writer)?;
+        // Write the height.
+        self.height().write_le(&mut writer)?;
+        // Write the headers.
+        self.headers.iter().try_for_each(|header| header.write_le(&mut writer))?;
+        // Write the serial numbers.
+        (self.serial_numbers.len() as u64).write_le(&mut writer)?;
+        self.serial_numbers.iter().try_for_each(|serial_number| serial_number.write_le(&mut writer))?;
+        // Write the commitments.
+        (self.commitments.len() as u64).write_le(&mut writer)?;
+        self.commitments.iter().try_for_each(|commitment| commitment.write_le(&mut writer))?;
+        // Write the checksum.
+        self.checksum().map_err(|e| error(e.to_string()))?.write_le(&mut writer)
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Writes a snapshot of the ledger state up to the given block height to the given writer.
+    pub fn export_snapshot<W: Write>(&self, height: u32, writer: W) -> Result<()> {
+        // Ensure the height exists in the ledger.
+        ensure!(height <= self.latest_height(), "Block {height} does not exist in the ledger");
+
+        // Collect the headers, serial numbers, and commitments of each block.
+        let mut headers = Vec::with_capacity(height as usize + 1);
+        let mut serial_numbers = IndexSet::new();
+        let mut commitments = IndexSet::new();
+        for block_height in 0..=height {
+            let block = self.get_block(block_height)?;
+            headers.push(*block.header());
+            serial_numbers.extend(block.serial_numbers().copied());
+            commitments.extend(block.commitments().copied());
+        }
+
+        // Construct the snapshot, and ensure it matches the ledger.
+        let snapshot = Snapshot::new(headers, serial_numbers, commitments)?;
+        ensure!(
+            snapshot.block_hashes()[height as usize] == self.get_hash(height)?,
+            "The snapshot does not match block {height}"
+        );
+        // Write the snapshot.
+        Ok(snapshot.write_le(writer)?)
+    }
+
+    /// Reads a snapshot from the given reader, and checks it against the ledger.
+    /// This method ensures the snapshot is well-formed, starts from the genesis block of the ledger,
+    /// and matches the block hashes of the ledger at the heights they share.
+    ///
+    /// The ledger is not modified, and the serial numbers and commitments in the snapshot are not authenticated.
+    pub fn read_snapshot<R: Read>(&self, reader: R) -> Result<Snapshot<N>> {
+        // Read the snapshot, which checks the checksum and the header chain.
+        let snapshot = Snapshot::<N>::read_le(reader)?;
+        // Ensure the snapshot matches the ledger.
+        self.check_snapshot(&snapshot)?;
+        Ok(snapshot)
+    }
+
+    /// Adds the given blocks to the ledger in bulk, e.g. as received from peers while fast-syncing,
+    /// authenticating them against the given snapshot and trusted checkpoint instead of verifying each block.
+    ///
+    /// The checkpoint is the block hash at the snapshot height, obtained from a trusted source (e.g. a hardcoded
+    /// checkpoint in the client). As each block hash chains the previous block hash, this authenticates every header
+    /// in the snapshot. This method ensures the snapshot ends at the checkpoint and matches the ledger, the blocks extend
+    /// the latest block of the ledger, and each block matches the header and block hash in the snapshot at its height.
+    /// As each header commits to the transactions root of its block, this authenticates the serial numbers and
+    /// commitments in the blocks, which must be in the snapshot. If the blocks reach the snapshot height, this method
+    /// also ensures each serial number and commitment in the snapshot is in the ledger or the blocks.
+    ///
+    /// The blocks are stored in a single atomic batch, with one update to the block tree. Each block authority is then
+    /// verified against the committee, and the block is finalized, in order, as each block is finalized against the
+    /// state of the previous block. If a block fails, it is removed from the ledger along with the blocks after it.
+    pub fn bootstrap(&self, snapshot: &Snapshot<N>, checkpoint: N::BlockHash, blocks: Vec<Block<N>>) -> Result<()> {
+        // Ensure the snapshot ends at the trusted checkpoint.
+        ensure!(
+            snapshot.block_hashes().last() == Some(&checkpoint),
+            "The snapshot does not end at the checkpoint '{checkpoint}'"
+        );
+        // Ensure the snapshot matches the ledger.
+        self.check_snapshot(snapshot)?;
+
+        // Ensure there are blocks to bootstrap.
+        let Some(last_block) = blocks.last() else { bail!("There are no blocks to bootstrap") };
+        // Ensure the blocks extend the latest block of the ledger, and are within the snapshot.
+        let start_height = self.latest_height() + 1;
+        for (block, height) in blocks.iter().zip(start_height..) {
+            ensure!(block.height() == height, "Expected block {height} to bootstrap, found block {}", block.height());
+            ensure!(height <= snapshot.height(), "Block {height} is beyond the snapshot height");
+        }
+
+        // Authenticate the blocks against the snapshot in parallel, as they are independent of one another.
+        cfg_iter!(blocks).try_for_each(|block| {
+            let height = block.height() as usize;
+            // Ensure the block matches the snapshot header and block hash.
+            ensure!(block.header() == &snapshot.headers()[height], "Block {height} does not match the snapshot header");
+            ensure!(block.hash() == snapshot.block_hashes()[height], "Block {height} does not match the snapshot hash");
+            // Ensure the transactions match the transactions root in the header.
+            ensure!(
+                block.transactions().to_transactions_root()? == block.header().transactions_root(),
+                "Block {height} has an incorrect transactions root"
+            );
+            // Ensure the serial numbers and commitments are in the snapshot.
+            ensure!(
+                block.serial_numbers().all(|serial_number| snapshot.contains_serial_number(serial_number)),
+                "Block {height} has a serial number that is not in the snapshot"
+            );
+            ensure!(
+                block.commitments().all(|commitment| snapshot.contains_commitment(commitment)),
+                "Block {height} has a commitment that is not in the snapshot"
+            );
+            Ok(())
+        })?;
+
+        // If the blocks reach the snapshot height, ensure each serial number and commitment in the snapshot
+        // is in the ledger or the blocks.
+        if last_block.height() == snapshot.height() {
+            let serial_numbers = blocks.iter().flat_map(|block| block.serial_numbers()).collect::<IndexSet<_>>();
+            let commitments = blocks.iter().flat_map(|block| block.commitments()).collect::<IndexSet<_>>();
+            snapshot.serial_numbers().iter().try_for_each(|serial_number| {
+                match serial_numbers.contains(serial_number) || self.contains_serial_number(serial_number)? {
+                    true => Ok(()),
+                    false => bail!("The snapshot serial number '{serial_number}' is not in the blocks"),
+                }
+            })?;
+            snapshot.commitments().iter().try_for_each(|commitment| {
+                match commitments.contains(commitment) || self.contains_commitment(commitment)? {
+                    true => Ok(()),
+                    false => bail!("The snapshot commitment '{commitment}' is not in the blocks"),
+                }
+            })?;
+        }
+
+        // Acquire the advance lock.
+        let _advance_lock = self.advance_lock.lock();
+        // Retrieve the latest block, which precedes the blocks.
+        let previous_block = self.latest_block();
+        // Store the blocks in bulk.
+        self.vm.block_store().insert_bulk(&blocks)?;
+
+        // Verify the authority of each block, and finalize it, in order.
+        let mut previous_round = previous_block.round();
+        let mut num_finalized = 0;
+        let result = blocks.iter().try_for_each(|block| {
+            // Ensure the block authority is correct for the current committee.
+            let committee = self.vm.finalize_store().committee_store().current_committee()?;
+            let (expected_round, _, _) =
+                block.verify_authority(previous_round, block.height().saturating_sub(1), &committee)?;
+            ensure!(block.round() == expected_round, "Block {} has an incorrect round", block.height());
+            // Finalize the block.
+            let state = FinalizeGlobalState::new::<N>(
+                block.round(),
+                block.height(),
+                block.cumulative_weight(),
+                block.cumulative_proof_target(),
+                block.previous_hash(),
+            )?;
+            self.vm.finalize(state, block.ratifications(), block.solutions(), block.transactions())?;
+            previous_round = block.round();
+            num_finalized += 1;
+            Ok(())
+        });
+        // If a block failed, remove it from the ledger, along with the blocks after it.
+        if result.is_err() {
+            self.vm.block_store().remove_last_n(u32::try_from(blocks.len() - num_finalized)?)?;
+        }
+
+        // Update the ledger to the last finalized block.
+        if let Some(block) = num_finalized.checked_sub(1).map(|index| &blocks[index]) {
+            // Update the current block.
+            *self.current_block.write() = block.clone();
+            // Update the cached committee from storage.
+            if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
+                *self.current_committee.write() = Some(current_committee);
+            }
+            // Update the current epoch challenge.
+            self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
+            // If pruning is enabled, prune the blocks that are now `depth` blocks behind the latest block.
+            // Note: The genesis block is never pruned.
+            if let Some(depth) = self.pruning_depth {
+                for block in &blocks[..num_finalized] {
+                    match block.height().checked_sub(depth) {
+                        Some(height) if height > 0 => self.vm.block_store().prune(height)?,
+                        _ => (),
+                    }
+                }
+            }
+            // Notify the subscribers.
+            let mut subscribers = self.subscribers.lock();
+            blocks[..num_finalized].iter().for_each(|block| subscribers.notify_block_applied(block));
+        }
+        result
+    }
+
+    /// Ensures the given snapshot starts from the genesis block of the ledger,
+    /// and matches the ledger at the latest height they share.
+    /// As each block hash chains the previous block hash, this implies they match at every height they share.
+    fn check_snapshot(&self, snapshot: &Snapshot<N>) -> Result<()> {
+        // Ensure the snapshot starts from the genesis block of the ledger.
+        ensure!(snapshot.block_hashes()[0] == self.genesis_block.hash(), "The snapshot has a different genesis block");
+        // Ensure the snapshot matches the ledger at the latest height they share.
+        let shared_height = snapshot.height().min(self.latest_height());
+        ensure!(
+            snapshot.block_hashes()[shared_height as usize] == self.get_hash(shared_height)?,
+            "The snapshot does not match block {shared_height} in the ledger"
+        );
+        Ok(())
+    }
+}
@@ -13,18 +13,28 @@
 // limitations under the License.
 
 use crate::{
+    consensus_rules,
     test_helpers::{CurrentLedger, CurrentNetwork},
+    AnchorTarget,
+    AsertTarget,
+    DifficultyTarget,
+    HeaderChain,
+    Mempool,
     RecordsFilter,
+    Snapshot,
 };
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
-use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_block::{Block, ConfirmedTransaction, Rejected, Transaction};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use synthesizer::{program::Program, vm::VM};
 
+use time::OffsetDateTime;
+
 #[test]
 fn test_load() {
     let rng = &mut TestRng::default();
@@ -80,6 +90,551 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_recent_state_roots() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    // Retrieve the genesis state root.
+    let state_root = ledger.latest_state_root();
+
+    // Ensure the genesis state root is recent.
+    assert_eq!(ledger.get_state_root_age(&state_root).unwrap(), Some(0));
+    assert!(ledger.contains_recent_state_root(&state_root).unwrap());
+    assert_eq!(ledger.recent_state_roots().unwrap(), vec![(0, state_root)]);
+
+    // Ensure an unknown state root is not recent.
+    let unknown = <CurrentNetwork as console::network::Network>::StateRoot::from(Field::rand(rng));
+    assert_eq!(ledger.get_state_root_age(&unknown).unwrap(), None);
+    assert!(!ledger.contains_recent_state_root(&unknown).unwrap());
+}
+
+#[test]
+fn test_find_unspent_program_records() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Ensure the genesis records are found for `credits.aleo`.
+    let credits_program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+    let records = ledger.find_unspent_program_records(&view_key, &credits_program_id).unwrap();
+    assert!(!records.is_empty());
+    assert_eq!(records, ledger.find_unspent_credits_records(&view_key).unwrap());
+
+    // Ensure each record commitment is associated with `credits.aleo`.
+    for commitment in records.keys() {
+        assert_eq!(ledger.find_program_id_from_commitment(commitment).unwrap(), Some(credits_program_id));
+    }
+
+    // Ensure no records are found for a different program.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+    assert!(ledger.find_unspent_program_records(&view_key, &program_id).unwrap().is_empty());
+}
+
+#[test]
+fn test_scan() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Scan the genesis block.
+    let deltas = ledger.scan(&view_key, 0..u32::MAX).unwrap();
+    assert!(!deltas.is_empty());
+    assert!(deltas.iter().all(|delta| delta.height() == 0 && !delta.is_spent()));
+
+    // Ensure every unspent credits record is found by the scan.
+    let records = ledger.find_unspent_credits_records(&view_key).unwrap();
+    for (commitment, record) in &records {
+        assert!(deltas.iter().any(|delta| delta.commitment() == commitment && delta.record() == record));
+    }
+
+    // Ensure the balance delta is positive.
+    let balance = deltas.iter().filter_map(|delta| delta.balance_delta()).sum::<i128>();
+    assert!(balance > 0);
+
+    // Ensure a different view key finds no records.
+    let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(ledger.scan(&other_view_key, 0..u32::MAX).unwrap().is_empty());
+    // Ensure an empty range finds no records.
+    assert!(ledger.scan(&view_key, 1..u32::MAX).unwrap().is_empty());
+}
+
+#[test]
+fn test_mempool() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Create two private transfers that spend the same records.
+    let transfer_a = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let transfer_b = ledger.create_transfer(&private_key, address, 100, 1, None, rng).unwrap();
+
+    let mut mempool = Mempool::<CurrentNetwork>::new(2);
+    assert!(mempool.is_empty());
+
+    // Insert the first transfer.
+    mempool.insert(transfer_a.clone(), 0).unwrap();
+    assert!(mempool.contains(&transfer_a.id()));
+    assert_eq!(mempool.len(), 1);
+    // Ensure a duplicate transaction is rejected.
+    assert!(mempool.insert(transfer_a.clone(), 0).is_err());
+    // Ensure a conflicting transaction is rejected.
+    assert_eq!(mempool.find_conflict(&transfer_b), Some(transfer_a.id()));
+    assert!(mempool.insert(transfer_b.clone(), 0).is_err());
+
+    // Confirm the second transfer, and ensure the conflicting first transfer is removed.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer_b], rng).unwrap();
+    let removed = mempool.remove_confirmed(&block).unwrap();
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].id(), transfer_a.id());
+    assert!(mempool.is_empty());
+
+    // Create two public transfers with different priority fees, which do not conflict.
+    let execute_public = |priority_fee: u64, rng: &mut TestRng| {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+        ledger
+            .vm
+            .execute(
+                &private_key,
+                ("credits.aleo", "transfer_public"),
+                inputs.into_iter(),
+                None,
+                priority_fee,
+                None,
+                rng,
+            )
+            .unwrap()
+    };
+    let low_fee = execute_public(0, rng);
+    let high_fee = execute_public(1000, rng);
+
+    // Ensure a full mempool evicts the lowest-fee transaction only for a higher-fee transaction.
+    let mut mempool = Mempool::<CurrentNetwork>::new(1);
+    mempool.insert(high_fee.clone(), 0).unwrap();
+    assert!(mempool.insert(low_fee.clone(), 0).is_err());
+    assert!(mempool.remove(&high_fee.id()).is_some());
+    mempool.insert(low_fee.clone(), 0).unwrap();
+    mempool.insert(high_fee.clone(), 1).unwrap();
+    assert!(!mempool.contains(&low_fee.id()));
+    assert!(mempool.contains(&high_fee.id()));
+
+    // Ensure the candidates are ordered by fee.
+    let mut mempool = Mempool::<CurrentNetwork>::new(2);
+    mempool.insert(low_fee.clone(), 0).unwrap();
+    mempool.insert(high_fee.clone(), 1).unwrap();
+    let candidates = mempool.candidates(2);
+    assert_eq!(candidates[0].id(), high_fee.id());
+    assert_eq!(candidates[1].id(), low_fee.id());
+    assert_eq!(mempool.candidates(1).len(), 1);
+
+    // Ensure only the expired transactions are removed.
+    let expired = mempool.remove_expired(10, 9);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id(), low_fee.id());
+    assert!(mempool.contains(&high_fee.id()));
+}
+
+#[test]
+fn test_validate_block_parallel() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct the next block with a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+
+    // Validate and add the block.
+    ledger.validate_block_parallel(&block, rng).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+    assert_eq!(ledger.latest_hash(), block.hash());
+
+    // Ensure the same block is rejected, as it no longer extends the latest block.
+    assert!(ledger.validate_block_parallel(&block, rng).is_err());
+    assert_eq!(ledger.latest_height(), 1);
+}
+
+#[test]
+fn test_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add a block with a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Export a snapshot at the latest height.
+    let mut bytes = Vec::new();
+    ledger.export_snapshot(1, &mut bytes).unwrap();
+
+    // Read the snapshot, and ensure it matches the ledger.
+    let snapshot = ledger.read_snapshot(&bytes[..]).unwrap();
+    assert_eq!(snapshot.height(), 1);
+    assert_eq!(snapshot.block_hashes(), &[ledger.get_hash(0).unwrap(), block.hash()]);
+    assert_eq!(snapshot.state_root().unwrap(), ledger.latest_state_root());
+    for serial_number in block.serial_numbers() {
+        assert!(snapshot.contains_serial_number(serial_number));
+    }
+    for commitment in block.commitments() {
+        assert!(snapshot.contains_commitment(commitment));
+    }
+
+    // Export a snapshot at the genesis height.
+    let mut genesis_bytes = Vec::new();
+    ledger.export_snapshot(0, &mut genesis_bytes).unwrap();
+    assert_eq!(ledger.read_snapshot(&genesis_bytes[..]).unwrap().height(), 0);
+
+    // Ensure a snapshot beyond the latest height cannot be exported.
+    assert!(ledger.export_snapshot(2, &mut Vec::new()).is_err());
+
+    // Ensure a corrupted snapshot is rejected.
+    let last = bytes.len() - 1;
+    bytes[last] ^= 1;
+    assert!(ledger.read_snapshot(&bytes[..]).is_err());
+
+    // Initialize a new ledger from the same genesis block.
+    let new_ledger = CurrentLedger::load(ledger.get_block(0).unwrap(), None).unwrap();
+    assert_eq!(new_ledger.latest_height(), 0);
+
+    // Ensure a snapshot with a serial number that is not in the blocks is rejected.
+    let mut serial_numbers = snapshot.serial_numbers().clone();
+    serial_numbers.insert(Field::from_u32(1));
+    let forged = Snapshot::new(snapshot.headers().to_vec(), serial_numbers, snapshot.commitments().clone()).unwrap();
+    assert!(new_ledger.bootstrap(&forged, block.hash(), vec![block.clone()]).is_err());
+    // Ensure a snapshot missing a commitment in the blocks is rejected.
+    let mut commitments = snapshot.commitments().clone();
+    commitments.pop();
+    let forged = Snapshot::new(snapshot.headers().to_vec(), snapshot.serial_numbers().clone(), commitments).unwrap();
+    assert!(new_ledger.bootstrap(&forged, block.hash(), vec![block.clone()]).is_err());
+    // Ensure the blocks must extend the latest block of the ledger.
+    assert!(new_ledger.bootstrap(&snapshot, block.hash(), vec![ledger.get_block(0).unwrap()]).is_err());
+    // Ensure the snapshot must end at the checkpoint.
+    assert!(new_ledger.bootstrap(&snapshot, ledger.get_hash(0).unwrap(), vec![block.clone()]).is_err());
+    // Ensure a block signed by a signer that is not in the committee is rejected.
+    let forged_block = Block::new_beacon(
+        &PrivateKey::new(rng).unwrap(),
+        block.previous_hash(),
+        *block.header(),
+        block.ratifications().clone(),
+        block.solutions().cloned(),
+        block.transactions().clone(),
+        block.aborted_transaction_ids().clone(),
+        rng,
+    )
+    .unwrap();
+    assert!(new_ledger.bootstrap(&snapshot, block.hash(), vec![forged_block]).is_err());
+    assert_eq!(new_ledger.latest_height(), 0);
+    assert!(new_ledger.get_block(1).is_err());
+
+    // Bootstrap the new ledger from the snapshot, and ensure it matches the ledger.
+    new_ledger.bootstrap(&snapshot, block.hash(), vec![block.clone()]).unwrap();
+    assert_eq!(new_ledger.latest_height(), 1);
+    assert_eq!(new_ledger.latest_hash(), block.hash());
+    assert_eq!(new_ledger.latest_state_root(), ledger.latest_state_root());
+}
+
+#[test]
+fn test_header_chain() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Initialize the header chain from the genesis header.
+    let mut header_chain = HeaderChain::new(ledger.get_header(0).unwrap()).unwrap();
+    assert_eq!(header_chain.latest_hash(), ledger.latest_hash());
+    assert_eq!(header_chain.latest_state_root(), ledger.latest_state_root());
+
+    // Add a block with a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let transaction_id = transaction.id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the header is rejected if it is in the future.
+    assert!(header_chain.check_next_header(block.header(), block.timestamp() - 1).is_err());
+    // Add the header to the header chain.
+    let current_timestamp = OffsetDateTime::now_utc().unix_timestamp();
+    assert_eq!(header_chain.add_next_header(*block.header(), current_timestamp).unwrap(), block.hash());
+    assert_eq!(header_chain.latest_height(), 1);
+    assert_eq!(header_chain.latest_state_root(), ledger.latest_state_root());
+    // Ensure the same header cannot be added twice.
+    assert!(header_chain.check_next_header(block.header(), current_timestamp).is_err());
+
+    // Ensure the transaction is included in the block.
+    let (height, transactions_path) = ledger.get_transaction_inclusion_proof(transaction_id).unwrap();
+    assert_eq!(height, 1);
+    header_chain.verify_transaction_inclusion(height, &transaction_id, &transactions_path).unwrap();
+    // Ensure the inclusion proof does not verify against a different block.
+    assert!(header_chain.verify_transaction_inclusion(0, &transaction_id, &transactions_path).is_err());
+
+    // Ensure the state path of an output record verifies against the header chain.
+    let transaction = block.transactions().get(&transaction_id).unwrap();
+    let commitment = transaction.commitments().next().unwrap();
+    let state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
+    header_chain.verify_state_path(&state_path).unwrap();
+}
+
+#[test]
+fn test_consensus_rules() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+    let genesis = ledger.get_header(0).unwrap();
+    let difficulty_target = AnchorTarget::<CurrentNetwork>::default();
+
+    // Check the median time past.
+    assert_eq!(consensus_rules::median_time_past::<CurrentNetwork>(&[]), None);
+    assert_eq!(consensus_rules::median_time_past(&[genesis]), Some(genesis.timestamp()));
+
+    // Add a block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    let current_timestamp = OffsetDateTime::now_utc().unix_timestamp();
+
+    // Ensure the header is a valid successor of the genesis header.
+    consensus_rules::validate_header(&[genesis], block.header(), &difficulty_target, current_timestamp).unwrap();
+    // Ensure the median time past rule is not active before its activation height.
+    consensus_rules::check_median_time_past(&[*block.header()], &genesis).unwrap();
+    // Ensure the header is rejected without a previous header.
+    assert!(consensus_rules::validate_header(&[], block.header(), &difficulty_target, current_timestamp).is_err());
+    // Ensure the header is rejected as its own successor.
+    assert!(consensus_rules::validate_header(
+        &[genesis, *block.header()],
+        block.header(),
+        &difficulty_target,
+        current_timestamp
+    )
+    .is_err());
+    // Ensure the header is rejected if it is in the future.
+    assert!(consensus_rules::validate_header(&[genesis], block.header(), &difficulty_target, block.timestamp() - 1)
+        .is_err());
+}
+
+#[test]
+fn test_commitment_and_serial_number_indexes() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add a block with a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure each commitment in the block is located in the block.
+    for confirmed in block.transactions().iter() {
+        for transition in confirmed.transaction().transitions() {
+            for (index, output) in transition.outputs().iter().enumerate() {
+                let Some(commitment) = output.commitment() else { continue };
+                let location = ledger.find_commitment_location(commitment).unwrap().unwrap();
+                assert_eq!(location.height(), 1);
+                assert_eq!(location.transaction_id(), &confirmed.id());
+                assert_eq!(location.transition_id(), transition.id());
+                assert_eq!(location.output_index() as usize, index);
+            }
+            // Ensure each serial number is spent by the transaction.
+            for serial_number in transition.serial_numbers() {
+                assert_eq!(ledger.find_spending_transaction_id(serial_number).unwrap(), Some(confirmed.id()));
+            }
+        }
+    }
+
+    // Ensure the iterators cover every commitment and serial number.
+    let locations = ledger.commitment_locations().collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(locations.len(), ledger.commitments().count());
+    let spent = ledger.spent_serial_numbers().collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(spent.len(), ledger.serial_numbers().count());
+
+    // Ensure unknown commitments and serial numbers are not found.
+    assert!(ledger.find_commitment_location(&Field::rand(rng)).unwrap().is_none());
+    assert!(ledger.find_spending_transaction_id(&Field::rand(rng)).unwrap().is_none());
+}
+
+#[test]
+fn test_export() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add a block with a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the block rows cover every block, and the range is bounded by the latest height.
+    let blocks = ledger.export_blocks(0..u32::MAX).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(blocks.len(), 2);
+    assert_eq!(blocks[1].height, 1);
+    assert_eq!(blocks[1].block_hash, block.hash().to_string());
+    assert_eq!(blocks[1].previous_hash, blocks[0].block_hash);
+    assert_eq!(blocks[1].num_transactions, block.transactions().len());
+
+    // Ensure the transaction rows match the transactions in the block.
+    let transactions = ledger.export_transactions(1..2).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(transactions.len(), block.transactions().len());
+    for (row, confirmed) in transactions.iter().zip(block.transactions().iter()) {
+        assert_eq!(row.height, 1);
+        assert_eq!(row.transaction_id, confirmed.id().to_string());
+        assert_eq!(row.variant, confirmed.variant());
+    }
+
+    // Ensure the commitment rows cover every commitment.
+    let commitments = ledger.export_commitments(0..u32::MAX).collect::<Result<Vec<_>>>().unwrap();
+    assert_eq!(commitments.len(), ledger.commitments().count());
+    for commitment in block.commitments() {
+        let row = commitments.iter().find(|row| row.commitment == commitment.to_string()).unwrap();
+        let location = ledger.find_commitment_location(commitment).unwrap().unwrap();
+        assert_eq!(row.height, location.height());
+        assert_eq!(row.transition_id, location.transition_id().to_string());
+        assert_eq!(row.output_index, location.output_index());
+    }
+
+    // Ensure an empty range exports nothing.
+    assert_eq!(ledger.export_blocks(2..u32::MAX).count(), 0);
+}
+
+#[test]
+fn test_events() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Subscribe to the events.
+    let blocks = ledger.on_block_applied();
+    let transactions = ledger.on_transaction_applied();
+    // Ensure a dropped receiver does not prevent the other subscribers from being notified.
+    drop(ledger.on_block_applied());
+
+    // Add a block with a transfer.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the block event was sent.
+    let event = blocks.try_recv().unwrap();
+    assert_eq!(event.height, 1);
+    assert_eq!(event.block_hash, block.hash());
+    assert_eq!(event.num_transactions, block.transactions().len());
+    assert!(blocks.try_recv().is_err());
+
+    // Ensure a transaction event was sent for each transaction in the block.
+    for confirmed in block.transactions().iter() {
+        let event = transactions.try_recv().unwrap();
+        assert_eq!(event.height, 1);
+        assert_eq!(event.block_hash, block.hash());
+        assert_eq!(event.transaction.id(), confirmed.id());
+    }
+    assert!(transactions.try_recv().is_err());
+}
+
+#[test]
+fn test_build_block_template() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Insert a transfer into the mempool.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+    let mut mempool = Mempool::new(10);
+    mempool.insert(transaction.clone(), ledger.latest_height()).unwrap();
+
+    // Build the block template.
+    let template = ledger.build_block_template(&mempool, vec![]).unwrap();
+    assert_eq!(template.previous_hash(), ledger.latest_hash());
+    assert_eq!(template.header().height(), 1);
+    assert_eq!(template.transactions().len(), 1);
+    assert!(template.serial_numbers().eq(transaction.serial_numbers()));
+    assert!(template.commitments().eq(transaction.commitments()));
+
+    // Sign the block template, and ensure the block hash matches the template.
+    let block_hash = template.to_block_hash().unwrap();
+    let block = template.into_beacon_block(&private_key, rng).unwrap();
+    assert_eq!(block.hash(), block_hash);
+
+    // Ensure the block is valid.
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert!(mempool.remove_confirmed(&block).unwrap().len() == 1);
+}
+
+#[test]
+fn test_pruning() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment, retaining the bodies of only the latest block.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+    let ledger = ledger.with_pruning(1).unwrap();
+    assert!(ledger.clone().with_pruning(0).is_err());
+
+    // Add two blocks, each with a transfer.
+    let mut transaction_ids = Vec::new();
+    for _ in 0..2 {
+        let transaction = ledger.create_transfer(&private_key, address, 100, 0, None, rng).unwrap();
+        transaction_ids.push(transaction.id());
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_eq!(ledger.latest_height(), 2);
+    assert_eq!(ledger.available_headers(), 0..=2);
+    assert_eq!(ledger.available_bodies().unwrap(), 2..=2);
+    assert_eq!(ledger.vm().block_store().pruned_height().unwrap(), Some(1));
+
+    // Ensure the transaction in block 1 is pruned, while its commitments are retained.
+    let pruned = ledger.get_transaction(transaction_ids[0]).unwrap();
+    assert!(pruned.execution().unwrap().proof().is_none());
+    assert_eq!(pruned.records().count(), 0);
+    assert!(pruned.commitments().count() > 0);
+    ledger.get_header(1).unwrap();
+
+    // Ensure the transaction in block 2 is retained.
+    let retained = ledger.get_transaction(transaction_ids[1]).unwrap();
+    assert!(retained.execution().unwrap().proof().is_some());
+    assert!(retained.records().count() > 0);
+}
+
+#[test]
+fn test_difficulty_target() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment, with the responsive ASERT retargeting rule.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+    let difficulty_target = AsertTarget::<CurrentNetwork>::new(1, 60).unwrap();
+    let ledger = ledger.with_difficulty_target(difficulty_target);
+    let genesis_header = *ledger.latest_block().header();
+
+    // Create a block, and ensure its targets follow the retargeting rule.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    let expected_coinbase_target = difficulty_target.coinbase_target(&genesis_header, block.timestamp()).unwrap();
+    assert_eq!(block.coinbase_target(), expected_coinbase_target);
+    assert_eq!(block.proof_target(), difficulty_target.proof_target(expected_coinbase_target));
+
+    // Ensure the block is valid under the retargeting rule.
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -636,3 +1191,14 @@ fn test_aborted_transaction_indexing() {
     // Add the deployment block to the ledger.
     ledger.advance_to_next_block(&block).unwrap();
 }
+
+#[test]
+fn test_select_fee_record() {
+    // Ensure the largest record is selected, if it covers the base fee and the priority fee.
+    assert_eq!(CurrentLedger::select_fee_record(&[5, 10, 7], 6, 4).unwrap(), 1);
+    // Ensure a record that covers only the priority fee is rejected.
+    assert!(CurrentLedger::select_fee_record(&[5, 10, 7], 8, 4).is_err());
+    // Ensure the total fee does not overflow.
+    assert!(CurrentLedger::select_fee_record(&[u64::MAX], u64::MAX, 1).is_ok());
+    assert!(CurrentLedger::select_fee_record(&[], 0, 0).is_err());
+}
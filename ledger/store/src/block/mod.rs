@@ -43,8 +43,8 @@ use ledger_narwhal_batch_certificate::BatchCertificate;
 use synthesizer_program::Program;
 
 use anyhow::Result;
-use parking_lot::RwLock;
-use std::{borrow::Cow, io::Cursor, sync::Arc};
+use parking_lot::{Mutex, RwLock};
+use std::{borrow::Cow, collections::VecDeque, io::Cursor, ops::Range, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -201,6 +201,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of (`level`, `index`) to the node hash in the block tree.
+    type TreeNodeMap: for<'a> Map<'a, (u8, u32), Field<N>>;
+    /// The mapping of the pruned height key to the highest pruned `block height`.
+    type PrunedHeightMap: for<'a> Map<'a, u8, u32>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -239,6 +243,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the block tree node map.
+    fn tree_node_map(&self) -> &Self::TreeNodeMap;
+    /// Returns the pruned height map.
+    fn pruned_height_map(&self) -> &Self::PrunedHeightMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -269,6 +277,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.tree_node_map().start_atomic();
+        self.pruned_height_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -289,6 +299,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.tree_node_map().is_atomic_in_progress()
+            || self.pruned_height_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -309,6 +321,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.tree_node_map().atomic_checkpoint();
+        self.pruned_height_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -329,6 +343,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.tree_node_map().clear_latest_checkpoint();
+        self.pruned_height_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -349,6 +365,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.tree_node_map().atomic_rewind();
+        self.pruned_height_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -369,6 +387,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.tree_node_map().abort_atomic();
+        self.pruned_height_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -389,6 +409,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.tree_node_map().finish_atomic()?;
+        self.pruned_height_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -981,13 +1003,32 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 }
 
+/// Returns the number of nodes in the given level of the block tree that cover at least one of the given leaves,
+/// where level 0 contains the hashed leaves.
+const fn num_covered_nodes(number_of_leaves: usize, level: usize) -> usize {
+    match number_of_leaves {
+        0 => 0,
+        n => ((n - 1) >> level) + 1,
+    }
+}
+
+/// The key of the highest pruned block height in the pruned height map.
+const PRUNED_HEIGHT_KEY: u8 = 0;
+
+/// The `(block height, state root)` pairs of the recent state roots, from the oldest to the latest block.
+type RecentStateRoots<N> = VecDeque<(u32, <N as Network>::StateRoot)>;
+
 /// The block store.
 #[derive(Clone)]
 pub struct BlockStore<N: Network, B: BlockStorage<N>> {
     /// The block storage.
     storage: B,
-    /// The block tree.
-    tree: Arc<RwLock<BlockTree<N>>>,
+    /// The block tree, which readers snapshot by cloning the `Arc`.
+    tree: Arc<RwLock<Arc<BlockTree<N>>>>,
+    /// The lock that serializes writers, so readers are not blocked while a block is written.
+    write_lock: Arc<Mutex<()>>,
+    /// The cached recent state roots, if they have been loaded.
+    recent_state_roots: Arc<RwLock<Option<RecentStateRoots<N>>>>,
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -996,42 +1037,207 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         // Initialize the block storage.
         let storage = B::open(dev)?;
 
-        // Compute the block tree.
-        let tree = {
-            // Prepare an iterator over the block heights.
-            let heights = storage.id_map().keys_confirmed();
-            // Prepare the leaves of the block tree.
-            let hashes = match heights.max() {
-                Some(height) => cfg_into_iter!(0..=cow_to_copied!(height))
-                    .map(|height| match storage.get_block_hash(height)? {
-                        Some(hash) => Ok(hash.to_bits_le()),
-                        None => bail!("Missing block hash for block {height}"),
+        // Determine the number of blocks in storage.
+        let number_of_blocks = match storage.id_map().keys_confirmed().max() {
+            Some(height) => usize::try_from(cow_to_copied!(height))? + 1,
+            None => 0,
+        };
+
+        // Load the block tree from the stored tree nodes, or recompute it from the block hashes.
+        let tree = match Self::load_tree(&storage, number_of_blocks) {
+            Ok(tree) => tree,
+            Err(_) => {
+                // Prepare the leaves of the block tree.
+                let hashes = cfg_into_iter!(0..number_of_blocks)
+                    .map(|height| {
+                        let height = u32::try_from(height)?;
+                        match storage.get_block_hash(height)? {
+                            Some(hash) => Ok(hash.to_bits_le()),
+                            None => bail!("Missing block hash for block {height}"),
+                        }
                     })
-                    .collect::<Result<Vec<Vec<bool>>>>()?,
-                None => vec![],
-            };
-            // Construct the block tree.
-            Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
+                    .collect::<Result<Vec<Vec<bool>>>>()?;
+                // Construct the block tree.
+                let tree = N::merkle_tree_bhp(&hashes)?;
+                // Store the tree nodes, so the block tree is loaded directly on the next start.
+                atomic_batch_scope!(storage, { Self::insert_tree_nodes(&storage, &tree, 0..number_of_blocks) })?;
+                tree
+            }
         };
 
         // Return the block store.
-        Ok(Self { storage, tree })
+        Ok(Self {
+            storage,
+            tree: Arc::new(RwLock::new(Arc::new(tree))),
+            write_lock: Default::default(),
+            recent_state_roots: Default::default(),
+        })
+    }
+
+    /// Returns the block tree for the given number of blocks, loaded from the stored tree nodes.
+    /// This method fails if a tree node is missing, or if the tree root does not match the latest state root.
+    fn load_tree(storage: &B, number_of_blocks: usize) -> Result<BlockTree<N>> {
+        // Initialize an empty block tree.
+        let empty_tree = N::merkle_tree_bhp(&[])?;
+        // If there are no blocks, return the empty block tree.
+        if number_of_blocks == 0 {
+            return Ok(empty_tree);
+        }
+
+        // Compute the number of levels in the block tree.
+        let number_of_levels = match number_of_blocks.checked_next_power_of_two() {
+            Some(max_leaves) => max_leaves.trailing_zeros() as usize + 1,
+            None => bail!("Integer overflow when computing the number of levels in the block tree"),
+        };
+        // Retrieve the tree nodes of each level.
+        let levels = (0..number_of_levels)
+            .map(|level| {
+                cfg_into_iter!(0..num_covered_nodes(number_of_blocks, level))
+                    .map(|index| {
+                        let key = (u8::try_from(level)?, u32::try_from(index)?);
+                        match storage.tree_node_map().get_confirmed(&key)? {
+                            Some(node) => Ok(cow_to_copied!(node)),
+                            None => bail!("Missing block tree node {key:?}"),
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the block tree.
+        let tree = empty_tree.prepare_from_levels(number_of_blocks, &levels)?;
+
+        // Ensure the block tree root matches the latest state root.
+        let latest_height = u32::try_from(number_of_blocks - 1)?;
+        match storage.state_root_map().get_confirmed(&latest_height)? {
+            Some(state_root) if cow_to_copied!(state_root) == N::StateRoot::from(*tree.root()) => Ok(tree),
+            _ => bail!("The block tree root does not match the state root for block {latest_height}"),
+        }
+    }
+
+    /// Stores the tree nodes that cover the leaves in the given range, from the given block tree.
+    fn insert_tree_nodes(storage: &B, tree: &BlockTree<N>, leaves: Range<usize>) -> Result<()> {
+        // If the range is empty, there are no tree nodes to store.
+        if leaves.is_empty() {
+            return Ok(());
+        }
+        for level in 0..tree.number_of_levels() {
+            // Store each tree node that covers a leaf in the range.
+            for index in (leaves.start >> level)..=((leaves.end - 1) >> level) {
+                let key = (u8::try_from(level)?, u32::try_from(index)?);
+                match tree.get_node(level, index) {
+                    Some(node) => storage.tree_node_map().insert(key, *node)?,
+                    None => bail!("Missing block tree node {key:?}"),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the tree nodes that no longer cover any leaves, after the number of leaves is reduced.
+    fn remove_tree_nodes(storage: &B, old_tree: &BlockTree<N>, new_tree: &BlockTree<N>) -> Result<()> {
+        for level in 0..old_tree.number_of_levels() {
+            // Determine the first tree node that no longer covers any leaves.
+            let start = match level < new_tree.number_of_levels() {
+                true => num_covered_nodes(new_tree.number_of_leaves(), level),
+                false => 0,
+            };
+            // Remove the tree nodes that no longer cover any leaves.
+            for index in start..num_covered_nodes(old_tree.number_of_leaves(), level) {
+                storage.tree_node_map().remove(&(u8::try_from(level)?, u32::try_from(index)?))?;
+            }
+        }
+        Ok(())
     }
 
     /// Stores the given block into storage.
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
-        // Acquire the write lock on the block tree.
-        let mut tree = self.tree.write();
+        // Acquire the write lock, and snapshot the block tree.
+        let _write_lock = self.write_lock.lock();
+        let tree = self.current_tree();
         // Prepare an updated Merkle tree containing the new block hash.
         let updated_tree = tree.prepare_append(&[block.hash().to_bits_le()])?;
         // Ensure the next block height is correct.
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        // Determine the index of the new leaf.
+        let leaf_index = tree.number_of_leaves();
+        // Lock the block tree until the storage is committed, so readers do not observe the storage and
+        // the block tree out of sync.
+        let mut tree_lock = self.tree.write();
+        atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Insert the updated tree nodes.
+            Self::insert_tree_nodes(&self.storage, &updated_tree, leaf_index..leaf_index + 1)
+        })?;
+        // Update the cached recent state roots, if they have been loaded.
+        if let Some(recent_state_roots) = self.recent_state_roots.write().as_mut() {
+            recent_state_roots.push_back((block.height(), (*updated_tree.root()).into()));
+            while recent_state_roots.len() > N::MAX_STATE_ROOT_AGE as usize + 1 {
+                recent_state_roots.pop_front();
+            }
+        }
         // Update the block tree.
-        *tree = updated_tree;
+        *tree_lock = Arc::new(updated_tree);
+        // Return success.
+        Ok(())
+    }
+
+    /// Stores the given blocks into storage in bulk, as a single atomic batch with one update to the block tree.
+    ///
+    /// As the block tree is only computed once, the state root of each block, but the last, is taken from the
+    /// previous state root in the header of the next block. The caller must ensure the headers are authenticated.
+    pub fn insert_bulk(&self, blocks: &[Block<N>]) -> Result<()> {
+        // Ensure there are blocks to insert.
+        let Some(first_block) = blocks.first() else { bail!("Cannot insert zero blocks") };
+
+        // Acquire the write lock, and snapshot the block tree.
+        let _write_lock = self.write_lock.lock();
+        let tree = self.current_tree();
+        // Ensure the first block extends the latest block.
+        ensure!(
+            first_block.previous_state_root() == N::StateRoot::from(*tree.root()),
+            "Attempted to insert a block that does not extend the latest state root into storage"
+        );
+        // Prepare an updated Merkle tree containing the new block hashes, whose leaves are hashed in parallel.
+        let hashes = cfg_iter!(blocks).map(|block| block.hash().to_bits_le()).collect::<Vec<_>>();
+        let updated_tree = tree.prepare_append(&hashes)?;
+        // Ensure the block heights are correct.
+        let leaf_index = tree.number_of_leaves();
+        for (block, height) in blocks.iter().zip(u32::try_from(leaf_index)?..) {
+            if block.height() != height {
+                bail!("Attempted to insert a block at the incorrect height into storage")
+            }
+        }
+
+        // Determine the state root of each block.
+        let state_roots = blocks
+            .iter()
+            .skip(1)
+            .map(|block| block.previous_state_root())
+            .chain([(*updated_tree.root()).into()])
+            .collect::<Vec<_>>();
+        // Lock the block tree until the storage is committed, so readers do not observe the storage and
+        // the block tree out of sync.
+        let mut tree_lock = self.tree.write();
+        atomic_batch_scope!(self, {
+            // Insert each (state root, block height) pair.
+            for (block, state_root) in blocks.iter().zip(&state_roots) {
+                self.storage.insert(*state_root, block)?;
+            }
+            // Insert the updated tree nodes.
+            Self::insert_tree_nodes(&self.storage, &updated_tree, leaf_index..leaf_index + blocks.len())
+        })?;
+        // Update the cached recent state roots, if they have been loaded.
+        if let Some(recent_state_roots) = self.recent_state_roots.write().as_mut() {
+            recent_state_roots.extend(blocks.iter().map(|block| block.height()).zip(state_roots));
+            while recent_state_roots.len() > N::MAX_STATE_ROOT_AGE as usize + 1 {
+                recent_state_roots.pop_front();
+            }
+        }
+        // Update the block tree.
+        *tree_lock = Arc::new(updated_tree);
         // Return success.
         Ok(())
     }
@@ -1041,8 +1247,9 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         // Ensure 'n' is non-zero.
         ensure!(n > 0, "Cannot remove zero blocks");
 
-        // Acquire the write lock on the block tree.
-        let mut tree = self.tree.write();
+        // Acquire the write lock, and snapshot the block tree.
+        let _write_lock = self.write_lock.lock();
+        let tree = self.current_tree();
 
         // Determine the block heights to remove.
         let heights = match self.storage.id_map().keys_confirmed().max() {
@@ -1072,20 +1279,60 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         // Prepare an updated Merkle tree removing the last 'n' block hashes.
         let updated_tree = tree.prepare_remove_last_n(usize::try_from(n)?)?;
 
+        // Lock the block tree until the storage is committed, so readers do not observe the storage and
+        // the block tree out of sync.
+        let mut tree_lock = self.tree.write();
         atomic_batch_scope!(self, {
             // Remove the blocks, in descending order.
             for block_hash in hashes.iter().rev() {
                 self.storage.remove(block_hash)?;
             }
-            Ok(())
+            // Remove the tree nodes that no longer cover any blocks.
+            Self::remove_tree_nodes(&self.storage, &tree, &updated_tree)?;
+            // Update the tree nodes that cover the new last block.
+            let number_of_leaves = updated_tree.number_of_leaves();
+            Self::insert_tree_nodes(&self.storage, &updated_tree, number_of_leaves.saturating_sub(1)..number_of_leaves)
         })?;
 
+        // Clear the cached recent state roots, as older state roots are recent again.
+        *self.recent_state_roots.write() = None;
         // Update the block tree.
-        *tree = updated_tree;
+        *tree_lock = Arc::new(updated_tree);
         // Return success.
         Ok(())
     }
 
+    /// Prunes the proofs and the output ciphertexts of the transactions in the block at the given height.
+    /// The block header, transaction IDs, serial numbers, and commitments are retained.
+    /// The highest pruned block height is stored with the pruned block, so it persists across restarts.
+    /// Note: Pruned blocks are not expected to be removed, as the record nonces can no longer be recovered.
+    pub fn prune(&self, height: u32) -> Result<()> {
+        // Retrieve the block hash.
+        let Some(block_hash) = self.storage.get_block_hash(height)? else {
+            bail!("Failed to prune block {height}: missing block hash");
+        };
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.storage.transactions_map().get_confirmed(&block_hash)? {
+            Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+            None => bail!("Failed to prune block {height}: missing transactions"),
+        };
+        // Determine the highest pruned block height.
+        let pruned_height = self.pruned_height()?.map_or(height, |pruned_height| pruned_height.max(height));
+        // Prune the transactions.
+        atomic_batch_scope!(self, {
+            for transaction_id in &transaction_ids {
+                self.transaction_store().prune(transaction_id)?;
+            }
+            // Store the highest pruned block height.
+            self.storage.pruned_height_map().insert(PRUNED_HEIGHT_KEY, pruned_height)
+        })
+    }
+
+    /// Returns the highest pruned block height, if any block has been pruned.
+    pub fn pruned_height(&self) -> Result<Option<u32>> {
+        Ok(self.storage.pruned_height_map().get_confirmed(&PRUNED_HEIGHT_KEY)?.map(|height| cow_to_copied!(height)))
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -1158,6 +1405,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Returns a snapshot of the current block tree.
+    /// The snapshot is not affected by blocks that are inserted or removed afterwards.
+    pub fn current_tree(&self) -> Arc<BlockTree<N>> {
+        self.tree.read().clone()
+    }
+
     /// Returns the current state root.
     pub fn current_state_root(&self) -> N::StateRoot {
         (*self.tree.read().root()).into()
@@ -1170,6 +1423,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns a state path for the given `commitment`.
     pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        // Note: The block tree is locked while the storage is read, so the block tree matches the storage.
         self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
     }
 
@@ -1266,6 +1520,81 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.reverse_state_root_map().contains_key_confirmed(state_root)
     }
 
+    /// Returns `true` if the given state root exists, and may be used in a transaction for the next block,
+    /// i.e. from `N::STATE_ROOT_AGE_ACTIVATION_HEIGHT`, it is at most `N::MAX_STATE_ROOT_AGE` blocks old.
+    pub fn contains_recent_state_root(&self, state_root: &N::StateRoot) -> Result<bool> {
+        // Retrieve the latest block height, before the state root, as a block may be inserted in between.
+        let latest_height = self.latest_block_height()?;
+        match self.find_block_height_from_state_root(*state_root)? {
+            Some(height) => Ok(Self::check_state_root_age(latest_height, height).is_ok()),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the number of blocks the given state root lags behind the latest block, if the state root exists.
+    /// Note: The age is only limited from `N::STATE_ROOT_AGE_ACTIVATION_HEIGHT`, so use `contains_recent_state_root`
+    /// (which applies the same limit as `check_state_root`) to check if the state root may be used in a transaction.
+    pub fn get_state_root_age(&self, state_root: &N::StateRoot) -> Result<Option<u32>> {
+        // Retrieve the latest block height, before the state root, as a block may be inserted in between.
+        let latest_height = self.latest_block_height()?;
+        match self.find_block_height_from_state_root(*state_root)? {
+            Some(height) => Ok(Some(latest_height.saturating_sub(height))),
+            None => Ok(None),
+        }
+    }
+
+    /// Ensures the given state root exists, and may be used in a transaction for the next block.
+    /// From `N::STATE_ROOT_AGE_ACTIVATION_HEIGHT`, this method also ensures the state root is recent.
+    pub fn check_state_root(&self, state_root: &N::StateRoot) -> Result<()> {
+        // Retrieve the latest block height, before the state root, as a block may be inserted in between.
+        let latest_height = self.latest_block_height()?;
+        match self.find_block_height_from_state_root(*state_root)? {
+            Some(height) => Self::check_state_root_age(latest_height, height),
+            None => bail!("global state root not found"),
+        }
+    }
+
+    /// Ensures a state root from the given block height may be used in a transaction for the next block,
+    /// after the given latest block height.
+    fn check_state_root_age(latest_height: u32, height: u32) -> Result<()> {
+        // Ensure the state root is recent, if the age limit is active at the next block height.
+        if latest_height.saturating_add(1) >= N::STATE_ROOT_AGE_ACTIVATION_HEIGHT {
+            let age = latest_height.saturating_sub(height);
+            ensure!(age <= N::MAX_STATE_ROOT_AGE, "global state root is {age} blocks old");
+        }
+        Ok(())
+    }
+
+    /// Returns the state roots that may be used in a new transaction, as `(block height, state root)` pairs,
+    /// from the oldest to the latest block.
+    pub fn recent_state_roots(&self) -> Result<Vec<(u32, N::StateRoot)>> {
+        // If the recent state roots have been loaded, return them.
+        if let Some(recent_state_roots) = self.recent_state_roots.read().as_ref() {
+            return Ok(recent_state_roots.iter().copied().collect());
+        }
+
+        // Acquire the write lock, so no block is inserted or removed while the state roots are loaded.
+        let _write_lock = self.write_lock.lock();
+        let latest_height = self.latest_block_height()?;
+        let recent_state_roots = match self.tree.read().number_of_leaves() {
+            0 => VecDeque::new(),
+            _ => (latest_height.saturating_sub(N::MAX_STATE_ROOT_AGE)..=latest_height)
+                .map(|height| match self.get_state_root(height)? {
+                    Some(state_root) => Ok((height, state_root)),
+                    None => bail!("Missing state root for block {height}"),
+                })
+                .collect::<Result<RecentStateRoots<N>>>()?,
+        };
+        // Cache the recent state roots.
+        *self.recent_state_roots.write() = Some(recent_state_roots.clone());
+        Ok(recent_state_roots.into_iter().collect())
+    }
+
+    /// Returns the height of the latest block in the block tree.
+    fn latest_block_height(&self) -> Result<u32> {
+        Ok(u32::try_from(self.tree.read().number_of_leaves())?.saturating_sub(1))
+    }
+
     /// Returns `true` if the given block height exists.
     pub fn contains_block_height(&self, height: u32) -> Result<bool> {
         self.storage.id_map().contains_key_confirmed(&height)
@@ -1352,6 +1681,10 @@ mod tests {
         // Retrieve the block.
         let candidate = block_store.get_block(&block_hash).unwrap();
         assert_eq!(Some(block), candidate);
+        // Ensure the state root is recent.
+        let state_root = block_store.current_state_root();
+        assert_eq!(block_store.recent_state_roots().unwrap(), vec![(0, state_root)]);
+        block_store.check_state_root(&state_root).unwrap();
 
         // Remove the block.
         block_store.remove_last_n(1).unwrap();
@@ -1359,6 +1692,81 @@ mod tests {
         // Ensure the block does not exist.
         let candidate = block_store.get_block(&block_hash).unwrap();
         assert_eq!(None, candidate);
+        // Ensure the state root no longer exists.
+        assert!(block_store.recent_state_roots().unwrap().is_empty());
+        assert!(block_store.check_state_root(&state_root).is_err());
+    }
+
+    #[test]
+    fn test_check_state_root_age() {
+        type Store = BlockStore<CurrentNetwork, BlockMemory<CurrentNetwork>>;
+        let max_age = CurrentNetwork::MAX_STATE_ROOT_AGE;
+        let activation_height = CurrentNetwork::STATE_ROOT_AGE_ACTIVATION_HEIGHT;
+
+        // Ensure a stale state root is accepted before the activation height.
+        let latest_height = activation_height - 2;
+        assert!(Store::check_state_root_age(latest_height, 0).is_ok());
+
+        // Ensure a stale state root is rejected from the activation height.
+        let latest_height = activation_height - 1;
+        assert!(Store::check_state_root_age(latest_height, latest_height - max_age).is_ok());
+        assert!(Store::check_state_root_age(latest_height, latest_height - max_age - 1).is_err());
+        assert!(Store::check_state_root_age(latest_height, 0).is_err());
+        assert!(Store::check_state_root_age(latest_height, latest_height).is_ok());
+    }
+
+    #[test]
+    fn test_load_tree() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        assert_eq!(block_store.storage.tree_node_map().keys_confirmed().count(), 0);
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the block tree is loaded from the stored tree nodes.
+        let tree = BlockStore::<CurrentNetwork, BlockMemory<_>>::load_tree(&block_store.storage, 1).unwrap();
+        assert_eq!(tree.root(), block_store.tree.read().root());
+        assert_eq!(tree.tree(), block_store.tree.read().tree());
+        assert_eq!(<CurrentNetwork as Network>::StateRoot::from(*tree.root()), block_store.current_state_root());
+
+        // Ensure loading fails for an incorrect number of blocks.
+        assert!(BlockStore::<CurrentNetwork, BlockMemory<_>>::load_tree(&block_store.storage, 2).is_err());
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the tree nodes are removed.
+        assert_eq!(block_store.storage.tree_node_map().keys_confirmed().count(), 0);
+    }
+
+    #[test]
+    fn test_current_tree_snapshot() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new block store, and snapshot the empty block tree.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let snapshot = block_store.current_tree();
+        assert_eq!(snapshot.number_of_leaves(), 0);
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the snapshot is unchanged, while the current block tree contains the block.
+        assert_eq!(snapshot.number_of_leaves(), 0);
+        assert_eq!(block_store.current_tree().number_of_leaves(), 1);
+        assert_eq!(
+            <CurrentNetwork as Network>::StateRoot::from(*block_store.current_tree().root()),
+            block_store.current_state_root()
+        );
     }
 
     #[test]
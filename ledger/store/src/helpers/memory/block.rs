@@ -57,6 +57,10 @@ pub struct BlockMemory<N: Network> {
     confirmed_transactions_map: MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The block tree node map.
+    tree_node_map: MemoryMap<(u8, u32), Field<N>>,
+    /// The pruned height map.
+    pruned_height_map: MemoryMap<u8, u32>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -78,6 +82,8 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type TreeNodeMap = MemoryMap<(u8, u32), Field<N>>;
+    type PrunedHeightMap = MemoryMap<u8, u32>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -104,6 +110,8 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            tree_node_map: MemoryMap::default(),
+            pruned_height_map: MemoryMap::default(),
             transaction_store,
         })
     }
@@ -183,6 +191,16 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the block tree node map.
+    fn tree_node_map(&self) -> &Self::TreeNodeMap {
+        &self.tree_node_map
+    }
+
+    /// Returns the pruned height map.
+    fn pruned_height_map(&self) -> &Self::PrunedHeightMap {
+        &self.pruned_height_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
@@ -63,6 +63,10 @@ pub struct BlockDB<N: Network> {
     confirmed_transactions_map: DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The block tree node map.
+    tree_node_map: DataMap<(u8, u32), Field<N>>,
+    /// The pruned height map.
+    pruned_height_map: DataMap<u8, u32>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -84,6 +88,8 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
+    type TreeNodeMap = DataMap<(u8, u32), Field<N>>;
+    type PrunedHeightMap = DataMap<u8, u32>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
@@ -110,6 +116,8 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::ConfirmedTransactions))?,
             rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            tree_node_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::TreeNode))?,
+            pruned_height_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::PrunedHeight))?,
             transaction_store,
         })
     }
@@ -189,6 +197,16 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the block tree node map.
+    fn tree_node_map(&self) -> &Self::TreeNodeMap {
+        &self.tree_node_map
+    }
+
+    /// Returns the pruned height map.
+    fn pruned_height_map(&self) -> &Self::PrunedHeightMap {
+        &self.pruned_height_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
@@ -82,6 +82,8 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    TreeNode = DataID::BlockTreeNodeMap as u16,
+    PrunedHeight = DataID::BlockPrunedHeightMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -285,6 +287,8 @@ enum DataID {
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
     BFTTransmissionsMap,
+    BlockTreeNodeMap,
+    BlockPrunedHeightMap,
 
     // Testing
     #[cfg(test)]
@@ -209,6 +209,11 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the ciphertexts of the outputs for the given `transition ID`.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.output_store().prune(transition_id)
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -310,6 +315,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the ciphertexts of the outputs for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -233,6 +233,33 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the record ciphertexts and the private ciphertexts of the outputs for the given `transition ID`.
+    /// The output IDs, record commitments, and record nonces are retained.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids: Vec<_> = match self.id_map().get_confirmed(transition_id)? {
+            Some(Cow::Borrowed(ids)) => ids.to_vec(),
+            Some(Cow::Owned(ids)) => ids.into_iter().collect(),
+            None => return Ok(()),
+        };
+
+        atomic_batch_scope!(self, {
+            for output_id in output_ids {
+                // If the output is a record, prune the record ciphertext.
+                if let Some(record) = self.record_map().get_confirmed(&output_id)? {
+                    let checksum = record.0;
+                    self.record_map().insert(output_id, (checksum, None))?;
+                }
+                // If the output is private, prune the ciphertext.
+                if self.private_map().get_confirmed(&output_id)?.is_some() {
+                    self.private_map().insert(output_id, None)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(output_id)? {
@@ -366,6 +393,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the record ciphertexts and the private ciphertexts of the outputs for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -347,6 +347,33 @@ pub fn sample_block_transactions(rng: &mut TestRng) -> Transactions<CurrentNetwo
     crate::sample_genesis_block(rng).transactions().clone()
 }
 
+/// Returns a strategy for transactions, drawn from a fixed set of sampled transactions.
+///
+/// Proving a transaction is expensive, so the set is sampled once and reused across cases.
+/// The strategy shrinks toward the first (simplest) transaction, a public fee transaction.
+#[cfg(feature = "prop-tests")]
+pub fn any_transaction() -> proptest::strategy::BoxedStrategy<Transaction<CurrentNetwork>> {
+    use proptest::strategy::Strategy;
+
+    static INSTANCE: OnceCell<Vec<Transaction<CurrentNetwork>>> = OnceCell::new();
+    let transactions = INSTANCE
+        .get_or_init(|| {
+            // Initialize the RNG.
+            let rng = &mut TestRng::default();
+            // Sample the transactions, from simplest to most complex.
+            vec![
+                sample_fee_public_transaction(rng),
+                sample_fee_private_transaction(rng),
+                sample_execution_transaction_with_fee(false, rng),
+                sample_execution_transaction_with_fee(true, rng),
+                sample_deployment_transaction(false, rng),
+                sample_deployment_transaction(true, rng),
+            ]
+        })
+        .clone();
+    proptest::sample::select(transactions).boxed()
+}
+
 /********************************************* Block **********************************************/
 
 /// Samples a random genesis block.
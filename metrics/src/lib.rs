@@ -14,17 +14,61 @@
 
 #![forbid(unsafe_code)]
 
+pub const COUNTER_NAMES: [&str; 5] =
+    [ledger::BLOCKS, ledger::TRANSACTIONS, synthesizer::DEPLOYMENTS, synthesizer::EXECUTIONS, algorithms::MSM_CALLS];
 pub const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
+pub const HISTOGRAM_NAMES: [&str; 6] = [
+    ledger::ADVANCE_TIME,
+    synthesizer::DEPLOY_TIME,
+    synthesizer::EXECUTE_TIME,
+    algorithms::MSM_TIME,
+    algorithms::VARUNA_PROVE_TIME,
+    algorithms::VARUNA_VERIFY_TIME,
+];
+
+pub mod algorithms {
+    pub const MSM_CALLS: &str = "snarkvm_algorithms_msm_calls_total";
+    pub const MSM_TIME: &str = "snarkvm_algorithms_msm_seconds";
+    pub const VARUNA_PROVE_TIME: &str = "snarkvm_algorithms_varuna_prove_seconds";
+    pub const VARUNA_VERIFY_TIME: &str = "snarkvm_algorithms_varuna_verify_seconds";
+}
 
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod ledger {
+    pub const ADVANCE_TIME: &str = "snarkvm_ledger_advance_seconds";
+    pub const BLOCKS: &str = "snarkvm_ledger_blocks_total";
+    pub const TRANSACTIONS: &str = "snarkvm_ledger_transactions_total";
+}
+
+pub mod synthesizer {
+    pub const DEPLOYMENTS: &str = "snarkvm_synthesizer_deployments_total";
+    pub const DEPLOY_TIME: &str = "snarkvm_synthesizer_deploy_seconds";
+    pub const EXECUTIONS: &str = "snarkvm_synthesizer_executions_total";
+    pub const EXECUTE_TIME: &str = "snarkvm_synthesizer_execute_seconds";
+}
+
 /// Registers all metrics.
 pub fn register_metrics() {
+    for name in COUNTER_NAMES {
+        ::metrics::register_counter!(name);
+    }
     for name in GAUGE_NAMES {
         ::metrics::register_gauge!(name);
     }
+    for name in HISTOGRAM_NAMES {
+        ::metrics::register_histogram!(name);
+    }
+}
+
+/// Increments a counter with the given name by the given value.
+///
+/// Counters represent a single monotonic value, which means the value can only be incremented,
+/// not decremented, and always starts out with an initial value of zero.
+pub fn increment_counter(name: &'static str, value: u64) {
+    ::metrics::counter!(name, value);
 }
 
 /// Updates a gauge with the given name to the given value.
@@ -34,3 +78,36 @@ pub fn register_metrics() {
 pub fn gauge<V: Into<f64>>(name: &'static str, value: V) {
     ::metrics::gauge!(name, value.into());
 }
+
+/// Records a value in the histogram with the given name.
+///
+/// Histograms record a distribution of values (e.g. the duration of an operation, in seconds),
+/// which exporters summarize as buckets or quantiles.
+pub fn histogram<V: Into<f64>>(name: &'static str, value: V) {
+    ::metrics::histogram!(name, value.into());
+}
+
+/// Records the time elapsed since `start`, in seconds, in the histogram with the given name.
+pub fn histogram_elapsed(name: &'static str, start: std::time::Instant) {
+    histogram(name, start.elapsed().as_secs_f64());
+}
+
+/// A timer that records the time elapsed since it was started, in seconds,
+/// in the histogram with the given name when it is dropped.
+pub struct HistogramTimer {
+    name: &'static str,
+    start: std::time::Instant,
+}
+
+impl HistogramTimer {
+    /// Starts a timer for the histogram with the given name.
+    pub fn start(name: &'static str) -> Self {
+        Self { name, start: std::time::Instant::now() }
+    }
+}
+
+impl Drop for HistogramTimer {
+    fn drop(&mut self) {
+        histogram_elapsed(self.name, self.start);
+    }
+}
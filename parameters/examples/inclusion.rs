@@ -22,6 +22,7 @@ use snarkvm_console::{
     types::Field,
 };
 use snarkvm_ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
+use snarkvm_parameters::version::PARAMETERS_VERSION;
 use snarkvm_synthesizer::{process::InclusionAssignment, snark::UniversalSRS, VM};
 
 use anyhow::{anyhow, Result};
@@ -137,6 +138,9 @@ pub fn inclusion<N: Network, A: Aleo<Network = N>>() -> Result<()> {
         "prover_size": proving_key_bytes.len(),
         "verifier_checksum": verifying_key_checksum,
         "verifier_size": verifying_key_bytes.len(),
+        "version": PARAMETERS_VERSION,
+        "network": N::ID,
+        "circuit_id": verifying_key.id.to_string(),
     });
 
     println!("{}", serde_json::to_string_pretty(&metadata)?);
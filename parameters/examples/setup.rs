@@ -15,6 +15,7 @@
 use snarkvm_algorithms::crypto_hash::sha256::sha256;
 use snarkvm_circuit::Aleo;
 use snarkvm_console::network::{prelude::ToBytes, Network, Testnet3};
+use snarkvm_parameters::version::PARAMETERS_VERSION;
 use snarkvm_synthesizer::{Process, Program};
 
 use anyhow::Result;
@@ -74,6 +75,8 @@ pub fn usrs() -> Result<()> {
             let metadata = json!({
                 "checksum": checksum,
                 "size": file_size,
+                "version": PARAMETERS_VERSION,
+                "network": Testnet3::ID,
             });
 
             write_metadata(metadata_path.to_str().unwrap(), &metadata)?;
@@ -115,6 +118,9 @@ pub fn credits_program<N: Network, A: Aleo<Network = N>>() -> Result<()> {
             "prover_size": proving_key_bytes.len(),
             "verifier_checksum": verifying_key_checksum,
             "verifier_size": verifying_key_bytes.len(),
+            "version": PARAMETERS_VERSION,
+            "network": N::ID,
+            "circuit_id": verifying_key.id.to_string(),
         });
 
         println!("{}", serde_json::to_string_pretty(&metadata)?);
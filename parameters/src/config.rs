@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{ffi::OsString, path::PathBuf, sync::RwLock};
+
+/// The environment variable that overrides the base URL of the remote parameters.
+pub const REMOTE_URL_ENV: &str = "SNARKVM_PARAMETERS_URL";
+/// The environment variable that overrides the directory in which the remote parameters are cached.
+pub const CACHE_DIR_ENV: &str = "SNARKVM_PARAMETERS_DIR";
+
+/// The extension of the remote and cached parameter files.
+/// With the `compressed` feature (unsupported on wasm), the files are zstd-compressed.
+#[cfg(all(feature = "compressed", not(feature = "wasm")))]
+pub const FILE_EXTENSION: &str = ".zst";
+/// The extension of the remote and cached parameter files.
+/// With the `compressed` feature (unsupported on wasm), the files are zstd-compressed.
+#[cfg(not(all(feature = "compressed", not(feature = "wasm"))))]
+pub const FILE_EXTENSION: &str = "";
+
+lazy_static! {
+    /// The base URL of the remote parameters, if it was set.
+    static ref REMOTE_URL: RwLock<Option<String>> = RwLock::new(None);
+    /// The directory in which the remote parameters are cached, if it was set.
+    static ref CACHE_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+}
+
+/// Sets the base URL from which the remote parameters are fetched, such as a mirror of the default URL.
+/// The fetched parameters are always verified against their embedded checksums.
+pub fn set_remote_url(url: impl Into<String>) {
+    let url = url.into();
+    *REMOTE_URL.write().unwrap_or_else(|error| error.into_inner()) = Some(url.trim_end_matches('/').to_string());
+}
+
+/// Sets the directory in which the remote parameters are cached.
+pub fn set_cache_dir(path: impl Into<PathBuf>) {
+    *CACHE_DIR.write().unwrap_or_else(|error| error.into_inner()) = Some(path.into());
+}
+
+/// Returns the base URL from which the remote parameters are fetched.
+///
+/// The URL is, in order of precedence, the one set with `set_remote_url`,
+/// the `SNARKVM_PARAMETERS_URL` environment variable, or the given default URL.
+pub fn remote_url(default_url: &str) -> String {
+    let url = REMOTE_URL.read().unwrap_or_else(|error| error.into_inner()).clone();
+    resolve_remote_url(url, std::env::var(REMOTE_URL_ENV).ok(), default_url)
+}
+
+/// Returns the directory in which the remote parameters are cached.
+///
+/// The directory is, in order of precedence, the one set with `set_cache_dir`,
+/// the `SNARKVM_PARAMETERS_DIR` environment variable, or the Aleo directory.
+pub fn cache_dir() -> PathBuf {
+    let path = CACHE_DIR.read().unwrap_or_else(|error| error.into_inner()).clone();
+    resolve_cache_dir(path, std::env::var_os(CACHE_DIR_ENV), aleo_std::aleo_dir)
+}
+
+/// Returns the given URL if it was set, or else the environment URL if it is non-empty, or else the default URL.
+fn resolve_remote_url(url: Option<String>, env_url: Option<String>, default_url: &str) -> String {
+    match (url, env_url) {
+        (Some(url), _) => url,
+        (None, Some(url)) if !url.is_empty() => url.trim_end_matches('/').to_string(),
+        _ => default_url.to_string(),
+    }
+}
+
+/// Returns the given directory if it was set, or else the environment directory if it is non-empty,
+/// or else the default directory.
+fn resolve_cache_dir(path: Option<PathBuf>, env_path: Option<OsString>, default_path: fn() -> PathBuf) -> PathBuf {
+    match (path, env_path) {
+        (Some(path), _) => path,
+        (None, Some(path)) if !path.is_empty() => PathBuf::from(path),
+        _ => default_path(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEFAULT_URL: &str = "https://default.example";
+
+    fn default_dir() -> PathBuf {
+        PathBuf::from("/default")
+    }
+
+    #[test]
+    fn test_remote_url_precedence() {
+        let set = || Some("https://set.example".to_string());
+        let env = || Some("https://env.example/".to_string());
+
+        // The URL set in code overrides the environment and the default.
+        assert_eq!(resolve_remote_url(set(), env(), DEFAULT_URL), "https://set.example");
+        assert_eq!(resolve_remote_url(set(), None, DEFAULT_URL), "https://set.example");
+        // The environment overrides the default, without its trailing slash.
+        assert_eq!(resolve_remote_url(None, env(), DEFAULT_URL), "https://env.example");
+        // An empty environment variable is ignored.
+        assert_eq!(resolve_remote_url(None, Some(String::new()), DEFAULT_URL), DEFAULT_URL);
+        assert_eq!(resolve_remote_url(None, None, DEFAULT_URL), DEFAULT_URL);
+    }
+
+    #[test]
+    fn test_cache_dir_precedence() {
+        let set = || Some(PathBuf::from("/set"));
+        let env = || Some(OsString::from("/env"));
+
+        // The directory set in code overrides the environment and the default.
+        assert_eq!(resolve_cache_dir(set(), env(), default_dir), PathBuf::from("/set"));
+        assert_eq!(resolve_cache_dir(set(), None, default_dir), PathBuf::from("/set"));
+        // The environment overrides the default.
+        assert_eq!(resolve_cache_dir(None, env(), default_dir), PathBuf::from("/env"));
+        // An empty environment variable is ignored.
+        assert_eq!(resolve_cache_dir(None, Some(OsString::new()), default_dir), default_dir());
+        assert_eq!(resolve_cache_dir(None, None, default_dir), default_dir());
+    }
+}
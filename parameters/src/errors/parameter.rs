@@ -22,6 +22,9 @@ pub enum ParameterError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("'{}' is incompatible with this version of snarkVM: {}", _0, _1)]
+    Incompatible(String, String),
+
     #[error("{}", _0)]
     Message(String),
 
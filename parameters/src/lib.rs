@@ -27,11 +27,18 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+pub mod config;
+
 pub mod errors;
 pub use errors::*;
 
+#[cfg(not(feature = "wasm"))]
+pub mod loader;
+
 pub mod testnet3;
 
+pub mod version;
+
 pub mod prelude {
     pub use crate::errors::*;
 }
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+
+/// The number of bytes read at a time, when computing the checksum of a parameter file.
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// The reader over the parameter bytes of a parameter file.
+#[cfg(feature = "compressed")]
+pub type ParameterReader = zstd::stream::read::Decoder<'static, BufReader<File>>;
+/// The reader over the parameter bytes of a parameter file.
+#[cfg(not(feature = "compressed"))]
+pub type ParameterReader = BufReader<File>;
+
+/// Opens the parameter file at the given path, and returns a reader over it, once its size and checksum are verified.
+///
+/// The checksum is computed by reading the file in chunks, so the parameter bytes are never held in memory
+/// at once, and the returned reader lets the caller deserialize the parameters directly from the file.
+/// With the `compressed` feature, the file is zstd-compressed, and is decompressed as it is read;
+/// the size and checksum are always over the decompressed parameter bytes.
+/// A corrupt file, including one that fails to decompress, is removed, so it is fetched again on the next load.
+pub fn open_verified(
+    file_path: &Path,
+    expected_size: usize,
+    expected_checksum: &str,
+) -> Result<ParameterReader, ParameterError> {
+    // Compute the size and checksum of the parameter bytes.
+    let (candidate_size, candidate_checksum) = match open(file_path).and_then(size_and_checksum) {
+        Ok(size_and_checksum) => size_and_checksum,
+        Err(error) => {
+            remove_file!(file_path);
+            return Err(error);
+        }
+    };
+
+    // Ensure the size matches.
+    if expected_size != candidate_size {
+        remove_file!(file_path);
+        return Err(ParameterError::SizeMismatch(expected_size, candidate_size));
+    }
+    // Ensure the checksum matches.
+    if expected_checksum != candidate_checksum {
+        remove_file!(file_path);
+        return checksum_error!(expected_checksum.to_string(), candidate_checksum);
+    }
+
+    // Reopen the file, and return a reader over it.
+    open(file_path)
+}
+
+/// Opens the parameter file at the given path, and returns a reader over it, without verifying it.
+/// This is only used for a file that was verified before it was stored.
+pub fn open(file_path: &Path) -> Result<ParameterReader, ParameterError> {
+    let file = File::open(file_path)?;
+    #[cfg(feature = "compressed")]
+    {
+        Ok(zstd::stream::read::Decoder::new(file)?)
+    }
+    #[cfg(not(feature = "compressed"))]
+    {
+        Ok(BufReader::new(file))
+    }
+}
+
+/// Returns the parameter bytes in the given file contents, once their size and checksum are verified.
+/// The parameter bytes are decompressed and hashed once.
+pub fn decompress_verified(
+    contents: Vec<u8>,
+    expected_size: usize,
+    expected_checksum: &str,
+) -> Result<Vec<u8>, ParameterError> {
+    // Decompress the parameter bytes, if the parameter file is compressed.
+    #[cfg(feature = "compressed")]
+    let bytes = zstd::stream::decode_all(&contents[..])?;
+    #[cfg(not(feature = "compressed"))]
+    let bytes = contents;
+
+    // Ensure the size matches.
+    if expected_size != bytes.len() {
+        return Err(ParameterError::SizeMismatch(expected_size, bytes.len()));
+    }
+    // Ensure the checksum matches.
+    let candidate_checksum = checksum!(bytes.as_slice());
+    if expected_checksum != candidate_checksum {
+        return checksum_error!(expected_checksum.to_string(), candidate_checksum);
+    }
+    Ok(bytes)
+}
+
+/// Returns the size and checksum of the bytes in the given reader, reading one chunk at a time.
+fn size_and_checksum(mut reader: impl Read) -> Result<(usize, String), ParameterError> {
+    let mut hasher = Sha256::new();
+    let mut size = 0usize;
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buffer)? {
+            0 => break,
+            num_bytes => {
+                hasher.update(&buffer[..num_bytes]);
+                size = size.saturating_add(num_bytes);
+            }
+        }
+    }
+    Ok((size, hex::encode(hasher.finalize())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the contents of a parameter file for the given parameter bytes.
+    fn to_contents(bytes: &[u8]) -> Vec<u8> {
+        #[cfg(feature = "compressed")]
+        {
+            zstd::stream::encode_all(bytes, 0).unwrap()
+        }
+        #[cfg(not(feature = "compressed"))]
+        {
+            bytes.to_vec()
+        }
+    }
+
+    #[test]
+    fn test_open_verified() {
+        let bytes = (0..3 * CHUNK_SIZE + 7).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let contents = to_contents(&bytes);
+        let file_path = std::env::temp_dir().join(format!("snarkvm-parameters-{}.test", std::process::id()));
+        std::fs::write(&file_path, &contents).unwrap();
+
+        // Ensure the parameter bytes are recovered from the file contents, once they are verified.
+        assert_eq!(decompress_verified(contents.clone(), bytes.len(), &checksum!(&bytes)).unwrap(), bytes);
+        assert!(decompress_verified(contents.clone(), bytes.len() + 1, &checksum!(&bytes)).is_err());
+        assert!(decompress_verified(contents.clone(), bytes.len(), &checksum!(&bytes[1..])).is_err());
+
+        // Ensure a verified file is read in full.
+        let mut reader = open_verified(&file_path, bytes.len(), &checksum!(&bytes)).unwrap();
+        let mut candidate = vec![];
+        reader.read_to_end(&mut candidate).unwrap();
+        assert_eq!(candidate, bytes);
+
+        // Ensure a file with the wrong checksum is rejected and removed.
+        assert!(open_verified(&file_path, bytes.len(), &checksum!(&bytes[1..])).is_err());
+        assert!(!file_path.exists());
+
+        // Ensure a file with the wrong size is rejected and removed.
+        std::fs::write(&file_path, &contents).unwrap();
+        assert!(open_verified(&file_path, bytes.len() + 1, &checksum!(&bytes)).is_err());
+        assert!(!file_path.exists());
+
+        // Ensure a file that fails to decompress is rejected and removed.
+        #[cfg(feature = "compressed")]
+        {
+            std::fs::write(&file_path, &bytes).unwrap();
+            assert!(open_verified(&file_path, bytes.len(), &checksum!(&bytes)).is_err());
+            assert!(!file_path.exists());
+        }
+    }
+}
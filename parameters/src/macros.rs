@@ -161,21 +161,28 @@ macro_rules! impl_load_bytes_logic_local {
         if $expected_checksum != candidate_checksum {
             return checksum_error!($expected_checksum, candidate_checksum);
         }
-
-        return Ok($buffer.to_vec());
     };
 }
 
 macro_rules! impl_load_bytes_logic_remote {
     ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
         // Compose the correct file path for the parameter file.
-        let mut file_path = aleo_std::aleo_dir();
+        let mut file_path = $crate::config::cache_dir();
         file_path.push($local_dir);
-        file_path.push($filename);
+        file_path.push(format!("{}{}", $filename, $crate::config::FILE_EXTENSION));
 
         let buffer = if file_path.exists() {
             // Attempts to load the parameter file locally with an absolute path.
-            std::fs::read(&file_path)?
+            let contents = std::fs::read(&file_path)?;
+            // Decompress the parameter bytes, and ensure their size and checksum match.
+            // Note: A corrupt cached file is removed, so it is fetched again on the next load.
+            match $crate::loader::decompress_verified(contents, $expected_size, &$expected_checksum) {
+                Ok(buffer) => buffer,
+                Err(error) => {
+                    remove_file!(file_path);
+                    return Err(error);
+                }
+            }
         } else {
             // Downloads the missing parameters and stores it in the local directory for use.
              #[cfg(not(feature = "no_std_out"))]
@@ -189,21 +196,19 @@ macro_rules! impl_load_bytes_logic_remote {
             }
 
             // Construct the URL.
-            let url = format!("{}/{}", $remote_url, $filename);
+            let remote_url = $crate::config::remote_url($remote_url);
+            let url = format!("{}/{}{}", remote_url, $filename, $crate::config::FILE_EXTENSION);
 
             // Load remote file
             cfg_if::cfg_if! {
                 if #[cfg(not(feature = "wasm"))] {
-                    let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
+                    let mut contents = vec![];
+                    Self::remote_fetch(&mut contents, &url)?;
 
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
-                    }
+                    // Decompress the parameter bytes, and ensure their size and checksum match.
+                    let buffer = $crate::loader::decompress_verified(contents.clone(), $expected_size, &$expected_checksum)?;
 
-                    match Self::store_bytes(&buffer, &file_path) {
+                    match Self::store_bytes(&contents, &file_path) {
                         Ok(()) => buffer,
                         Err(_) => {
                             eprintln!(
@@ -216,6 +221,10 @@ macro_rules! impl_load_bytes_logic_remote {
                 } else if #[cfg(feature = "wasm")] {
                     let buffer = Self::remote_fetch(&url)?;
 
+                    // Ensure the size matches.
+                    if $expected_size != buffer.len() {
+                        return Err($crate::errors::ParameterError::SizeMismatch($expected_size, buffer.len()));
+                    }
                     // Ensure the checksum matches.
                     let candidate_checksum = checksum!(&buffer);
                     if $expected_checksum != candidate_checksum {
@@ -229,20 +238,28 @@ macro_rules! impl_load_bytes_logic_remote {
             }
         };
 
-        // Ensure the size matches.
-        if $expected_size != buffer.len() {
-            remove_file!(file_path);
-            return Err($crate::errors::ParameterError::SizeMismatch($expected_size, buffer.len()));
-        }
+        return Ok(buffer)
+    }
+}
 
-        // Ensure the checksum matches.
-        let candidate_checksum = checksum!(buffer.as_slice());
-        if $expected_checksum != candidate_checksum {
-            return checksum_error!($expected_checksum, candidate_checksum)
+#[cfg(not(feature = "wasm"))]
+macro_rules! impl_load_reader_logic_remote {
+    ($local_dir: expr, $filename: expr, $expected_checksum: expr, $expected_size: expr) => {
+        // Compose the correct file path for the parameter file.
+        let mut file_path = $crate::config::cache_dir();
+        file_path.push($local_dir);
+        file_path.push(format!("{}{}", $filename, $crate::config::FILE_EXTENSION));
+
+        // If the parameter file does not exist, fetch and store it.
+        // Note: The fetched parameter bytes are verified before they are stored, so the file is not verified again.
+        if !file_path.exists() {
+            Self::load_bytes()?;
+            return $crate::loader::open(&file_path);
         }
 
-        return Ok(buffer)
-    }
+        // Open the parameter file, once its size and checksum are verified.
+        return $crate::loader::open_verified(&file_path, $expected_size, &$expected_checksum);
+    };
 }
 
 #[macro_export]
@@ -266,6 +283,23 @@ macro_rules! impl_local {
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", "usrs"));
 
                 impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
+                Ok(buffer.to_vec())
+            }
+
+            /// Returns a reader over the verified parameter bytes, without copying them.
+            pub fn load_reader() -> Result<&'static [u8], $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                let _filepath = concat!($local_dir, $fname, ".", "usrs");
+                let buffer: &'static [u8] = include_bytes!(concat!($local_dir, $fname, ".", "usrs"));
+
+                impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
+                Ok(buffer)
             }
         }
 
@@ -296,6 +330,23 @@ macro_rules! impl_local {
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", $ftype));
 
                 impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
+                Ok(buffer.to_vec())
+            }
+
+            /// Returns a reader over the verified parameter bytes, without copying them.
+            pub fn load_reader() -> Result<&'static [u8], $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+
+                let _filepath = concat!($local_dir, $fname, ".", $ftype);
+                let buffer: &'static [u8] = include_bytes!(concat!($local_dir, $fname, ".", $ftype));
+
+                impl_load_bytes_logic_local!(_filepath, buffer, expected_size, expected_checksum);
+                Ok(buffer)
             }
         }
 
@@ -342,6 +393,32 @@ macro_rules! impl_remote {
                     expected_size
                 );
             }
+
+            /// Returns a reader over the verified parameter file, which is fetched and cached if it does not exist.
+            /// The file is read incrementally, so the parameter bytes are not held in memory to be deserialized.
+            #[cfg(not(feature = "wasm"))]
+            pub fn load_reader() -> Result<$crate::loader::ParameterReader, $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Construct the versioned filename.
+                let filename = match expected_checksum.get(0..7) {
+                    Some(sum) => format!("{}.{}.{}", $fname, "usrs", sum),
+                    _ => format!("{}.{}", $fname, "usrs"),
+                };
+
+                impl_load_reader_logic_remote!($local_dir, &filename, expected_checksum, expected_size);
+            }
+
+            /// Returns a reader over the verified parameter bytes.
+            #[cfg(feature = "wasm")]
+            pub fn load_reader() -> Result<std::io::Cursor<Vec<u8>>, $crate::errors::ParameterError> {
+                Ok(std::io::Cursor::new(Self::load_bytes()?))
+            }
         }
         paste::item! {
             #[cfg(test)]
@@ -382,6 +459,32 @@ macro_rules! impl_remote {
                     expected_size
                 );
             }
+
+            /// Returns a reader over the verified parameter file, which is fetched and cached if it does not exist.
+            /// The file is read incrementally, so the parameter bytes are not held in memory to be deserialized.
+            #[cfg(not(feature = "wasm"))]
+            pub fn load_reader() -> Result<$crate::loader::ParameterReader, $crate::errors::ParameterError> {
+                let metadata: serde_json::Value =
+                    serde_json::from_str(Self::METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Construct the versioned filename.
+                let filename = match expected_checksum.get(0..7) {
+                    Some(sum) => format!("{}.{}.{}", $fname, $ftype, sum),
+                    _ => format!("{}.{}", $fname, $ftype),
+                };
+
+                impl_load_reader_logic_remote!($local_dir, &filename, expected_checksum, expected_size);
+            }
+
+            /// Returns a reader over the verified parameter bytes.
+            #[cfg(feature = "wasm")]
+            pub fn load_reader() -> Result<std::io::Cursor<Vec<u8>>, $crate::errors::ParameterError> {
+                Ok(std::io::Cursor::new(Self::load_bytes()?))
+            }
         }
 
         paste::item! {
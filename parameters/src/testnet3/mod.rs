@@ -103,30 +103,40 @@ macro_rules! insert_credit_keys {
     ($map:ident, $type:ident<$network:ident>, $variant:ident) => {{
         paste::paste! {
             let string = stringify!([<$variant:lower>]);
-            $crate::insert_key!($map, string, $type<$network>, ("bond_public", $crate::testnet3::[<BondPublic $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("unbond_public", $crate::testnet3::[<UnbondPublic $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("unbond_delegator_as_validator", $crate::testnet3::[<UnbondDelegatorAsValidator $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("claim_unbond_public", $crate::testnet3::[<ClaimUnbondPublic $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("set_validator_state", $crate::testnet3::[<SetValidatorState $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("transfer_private", $crate::testnet3::[<TransferPrivate $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("transfer_public", $crate::testnet3::[<TransferPublic $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("transfer_private_to_public", $crate::testnet3::[<TransferPrivateToPublic $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("transfer_public_to_private", $crate::testnet3::[<TransferPublicToPrivate $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("join", $crate::testnet3::[<Join $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("split", $crate::testnet3::[<Split $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("fee_private", $crate::testnet3::[<FeePrivate $variant>]::load_bytes()));
-            $crate::insert_key!($map, string, $type<$network>, ("fee_public", $crate::testnet3::[<FeePublic $variant>]::load_bytes()));
+            $crate::insert_key!($map, string, $type<$network>, ("bond_public", $crate::testnet3::[<BondPublic $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("unbond_public", $crate::testnet3::[<UnbondPublic $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("unbond_delegator_as_validator", $crate::testnet3::[<UnbondDelegatorAsValidator $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("claim_unbond_public", $crate::testnet3::[<ClaimUnbondPublic $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("set_validator_state", $crate::testnet3::[<SetValidatorState $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("transfer_private", $crate::testnet3::[<TransferPrivate $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("transfer_public", $crate::testnet3::[<TransferPublic $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("transfer_private_to_public", $crate::testnet3::[<TransferPrivateToPublic $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("transfer_public_to_private", $crate::testnet3::[<TransferPublicToPrivate $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("join", $crate::testnet3::[<Join $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("split", $crate::testnet3::[<Split $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("fee_private", $crate::testnet3::[<FeePrivate $variant>]));
+            $crate::insert_key!($map, string, $type<$network>, ("fee_public", $crate::testnet3::[<FeePublic $variant>]));
         }
     }};
 }
 
 #[macro_export]
 macro_rules! insert_key {
-    ($map:ident, $string:tt, $type:ident<$network:ident>, ($name:tt, $circuit_key:expr)) => {{
-        // Load the circuit key bytes.
-        let key_bytes: Vec<u8> = $circuit_key.expect(&format!("Failed to load {} bytes", $string));
+    ($map:ident, $string:tt, $type:ident<$network:ident>, ($name:tt, $parameter:path)) => {{
+        // Ensure the parameter metadata is compatible with this version of snarkVM and the network.
+        $crate::version::check_metadata($name, <$parameter>::METADATA, $crate::testnet3::TESTNET3_NETWORK_ID)
+            .unwrap_or_else(|error| panic!("Failed to load {} - {error}", $string));
+        // Load a reader over the circuit key bytes.
+        // Note: The circuit key is deserialized directly from the reader, without copying its bytes into memory.
+        let mut key_reader = <$parameter>::load_reader().expect(&format!("Failed to load {} bytes", $string));
+        // Ensure the version of the circuit key, which is its first byte, is supported.
+        $crate::version::check_circuit_key_version($name, &mut key_reader)
+            .unwrap_or_else(|error| panic!("Failed to load {} - {error}", $string));
         // Recover the circuit key.
-        let key = $type::<$network>::from_bytes_le(&key_bytes[1..]).expect(&format!("Failed to recover {}", $string));
+        let key = $type::<$network>::read_le(&mut key_reader).expect(&format!("Failed to recover {}", $string));
+        // Ensure the circuit key is for the circuit in the parameter metadata.
+        $crate::version::check_circuit_id($name, <$parameter>::METADATA, &key)
+            .unwrap_or_else(|error| panic!("Failed to load {} - {error}", $string));
         // Insert the circuit key.
         $map.insert($name.to_string(), std::sync::Arc::new(key));
     }};
@@ -138,6 +148,8 @@ impl_local!(InclusionVerifier, "resources/", "inclusion", "verifier");
 
 /// The function name for the inclusion circuit.
 pub const TESTNET3_INCLUSION_FUNCTION_NAME: &str = "inclusion";
+/// The network ID of the parameters.
+pub const TESTNET3_NETWORK_ID: u16 = 3;
 
 lazy_static! {
     pub static ref INCLUSION_PROVING_KEY: Vec<u8> =
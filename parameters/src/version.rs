@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use serde_json::Value;
+use std::io::Read;
+
+/// The version of the parameter files and their metadata.
+pub const PARAMETERS_VERSION: u16 = 1;
+/// The version of the circuit key encoding, which is the first byte of a serialized circuit key.
+pub const CIRCUIT_KEY_VERSION: u8 = 1;
+
+/// A circuit key, which is fingerprinted by the ID of its circuit.
+pub trait CircuitFingerprint {
+    /// Returns the hex-encoded ID of the circuit.
+    fn circuit_id(&self) -> String;
+}
+
+/// Ensures the given parameter metadata is compatible with this version of snarkVM and the given network.
+///
+/// The `version` and `network` fields are optional, as metadata that predates them is compatible by definition.
+pub fn check_metadata(name: &str, metadata: &str, network_id: u16) -> Result<(), ParameterError> {
+    let metadata = parse_metadata(name, metadata)?;
+
+    // Ensure the version matches.
+    if let Some(version) = metadata.get("version") {
+        let version = version.as_u64().ok_or_else(|| incompatible(name, "the version is not a number".into()))?;
+        if version != PARAMETERS_VERSION as u64 {
+            return Err(incompatible(name, format!("expected version {PARAMETERS_VERSION}, found version {version}")));
+        }
+    }
+    // Ensure the network matches.
+    if let Some(network) = metadata.get("network") {
+        let network = network.as_u64().ok_or_else(|| incompatible(name, "the network ID is not a number".into()))?;
+        if network != network_id as u64 {
+            return Err(incompatible(name, format!("expected network {network_id}, found network {network}")));
+        }
+    }
+    Ok(())
+}
+
+/// Reads the version byte of a serialized circuit key, and ensures it is supported.
+pub fn check_circuit_key_version(name: &str, mut reader: impl Read) -> Result<(), ParameterError> {
+    let mut version = [0u8; 1];
+    reader.read_exact(&mut version)?;
+    match version[0] == CIRCUIT_KEY_VERSION {
+        true => Ok(()),
+        false => Err(incompatible(
+            name,
+            format!("expected circuit key version {CIRCUIT_KEY_VERSION}, found version {}", version[0]),
+        )),
+    }
+}
+
+/// Ensures the given circuit key matches the circuit ID in its parameter metadata, if one is recorded.
+pub fn check_circuit_id(name: &str, metadata: &str, key: &impl CircuitFingerprint) -> Result<(), ParameterError> {
+    let metadata = parse_metadata(name, metadata)?;
+    match metadata.get("circuit_id").and_then(|circuit_id| circuit_id.as_str()) {
+        Some(expected) if expected != key.circuit_id() => {
+            Err(incompatible(name, format!("expected circuit ID {expected}, found circuit ID {}", key.circuit_id())))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Parses the given parameter metadata.
+fn parse_metadata(name: &str, metadata: &str) -> Result<Value, ParameterError> {
+    serde_json::from_str(metadata).map_err(|error| incompatible(name, format!("the metadata is malformed ({error})")))
+}
+
+/// Returns an error for the given incompatible parameter.
+fn incompatible(name: &str, reason: String) -> ParameterError {
+    ParameterError::Incompatible(name.to_string(), reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Key(&'static str);
+
+    impl CircuitFingerprint for Key {
+        fn circuit_id(&self) -> String {
+            self.0.to_string()
+        }
+    }
+
+    #[test]
+    fn test_check_metadata() {
+        // Ensure legacy metadata is compatible.
+        assert!(check_metadata("legacy", r#"{ "checksum": "00", "size": 1 }"#, 3).is_ok());
+        // Ensure matching metadata is compatible.
+        assert!(check_metadata("current", r#"{ "version": 1, "network": 3 }"#, 3).is_ok());
+        // Ensure a different version or network is incompatible.
+        assert!(check_metadata("future", r#"{ "version": 2, "network": 3 }"#, 3).is_err());
+        assert!(check_metadata("other", r#"{ "version": 1, "network": 4 }"#, 3).is_err());
+        // Ensure malformed metadata is incompatible.
+        assert!(check_metadata("malformed", "{", 3).is_err());
+    }
+
+    #[test]
+    fn test_check_circuit_key_version() {
+        assert!(check_circuit_key_version("key", &[CIRCUIT_KEY_VERSION, 0][..]).is_ok());
+        assert!(check_circuit_key_version("key", &[CIRCUIT_KEY_VERSION + 1, 0][..]).is_err());
+        assert!(check_circuit_key_version("key", &[][..]).is_err());
+    }
+
+    #[test]
+    fn test_check_circuit_id() {
+        assert!(check_circuit_id("key", r#"{ "circuit_id": "ab" }"#, &Key("ab")).is_ok());
+        assert!(check_circuit_id("key", r#"{ "circuit_id": "ab" }"#, &Key("cd")).is_err());
+        assert!(check_circuit_id("key", r#"{ "version": 1 }"#, &Key("cd")).is_ok());
+    }
+}
@@ -188,6 +188,28 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
+    /// Loads the 'credits.aleo' proving keys and the inclusion proving key into memory.
+    /// These keys are otherwise loaded on first use, which delays the first proof of each function.
+    #[inline]
+    pub fn preload(&self) -> Result<()> {
+        let timer = timer!("Process::preload");
+
+        // Retrieve the 'credits.aleo' stack.
+        let stack = self.get_stack("credits.aleo")?;
+        // Load the 'credits.aleo' proving keys.
+        for function_name in stack.program().functions().keys() {
+            stack.get_proving_key(function_name)?;
+            lap!(timer, "Load proving key for {function_name}");
+        }
+
+        // Load the inclusion proving key.
+        N::inclusion_proving_key();
+        lap!(timer, "Load inclusion proving key");
+
+        finish!(timer);
+        Ok(())
+    }
+
     /// Returns the universal SRS.
     #[inline]
     pub const fn universal_srs(&self) -> &Arc<UniversalSRS<N>> {
@@ -200,6 +222,12 @@ impl<N: Network> Process<N> {
         self.stacks.contains_key(program_id)
     }
 
+    /// Returns an iterator over the IDs of the programs in the process.
+    #[inline]
+    pub fn program_ids(&self) -> impl '_ + ExactSizeIterator<Item = &ProgramID<N>> {
+        self.stacks.keys()
+    }
+
     /// Returns the stack for the given program ID.
     #[inline]
     pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Arc<Stack<N>>> {
@@ -736,6 +736,10 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashKeccak512(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash.keccak256.raw" => ensure!(
+                matches!(instruction, Instruction::HashKeccak256Raw(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             "hash.ped64" => ensure!(
                 matches!(instruction, Instruction::HashPED64(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
@@ -92,6 +92,28 @@ pub fn sample_fee<N: Network, A: Aleo<Network = N>, B: BlockStorage<N>, P: Final
     trace.prove_fee::<A, _>(rng).unwrap()
 }
 
+#[test]
+fn test_process_program_ids() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+function foo:
+    input r0 as field.public;
+    output r0 as field.private;
+",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Ensure the registered program IDs are `credits.aleo` and `example.aleo`, in order.
+    let program_ids = process.program_ids().map(|id| id.to_string()).collect::<Vec<_>>();
+    assert_eq!(program_ids, vec!["credits.aleo", "example.aleo"]);
+    assert!(process.contains_program(program.id()));
+}
+
 #[test]
 fn test_program_evaluate_function() {
     let program = Program::<CurrentNetwork>::from_str(
@@ -2404,6 +2426,22 @@ function compute:
     assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
 }
 
+#[test]
+fn test_process_deploy_htlc_program() {
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Fetch the htlc program.
+    let program = Program::htlc().unwrap();
+
+    // Create a deployment for the htlc.aleo program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    // Ensure the deployment is valid.
+    assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_ok());
+}
+
 #[test]
 fn test_process_zero_input_zero_output_executions() {
     // Initialize the RNG.
@@ -27,6 +27,9 @@ use ledger_query::QueryTrait;
 
 use std::collections::HashMap;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 #[derive(Clone, Debug)]
 struct InputTask<N: Network> {
     /// The commitment.
@@ -15,7 +15,7 @@
 use super::*;
 
 macro_rules! prepare_impl {
-    ($self:ident, $transitions:ident, $query:ident, $current_state_root:ident, $get_state_path_for_commitment:ident $(, $await:ident)?) => {{
+    ($self:ident, $transitions:ident, $query:ident, $prefetched:ident, $current_state_root:ident, $get_state_path_for_commitment:ident $(, $await:ident)?) => {{
         // Ensure the number of leaves is within the Merkle tree size.
         Transaction::<N>::check_execution_size($transitions.len())?;
 
@@ -64,10 +64,14 @@ macro_rules! prepare_impl {
                                     *transition_leaf,
                                 )?
                             }
-                            None => {
-                                $query.$get_state_path_for_commitment(&task.commitment)
-                                $(.$await)?
-                            }?
+                            None => match $prefetched.remove(&task.commitment) {
+                                // Use the prefetched state path, if it exists.
+                                Some(state_path) => state_path,
+                                None => {
+                                    $query.$get_state_path_for_commitment(&task.commitment)
+                                    $(.$await)?
+                                }?,
+                            },
                         };
 
                         // Ensure the global state root is the same across iterations.
@@ -105,9 +109,14 @@ impl<N: Network> Inclusion<N> {
     pub fn prepare(
         &self,
         transitions: &[Transition<N>],
-        query: impl QueryTrait<N>,
+        query: impl QueryTrait<N> + Sync,
     ) -> Result<(Vec<InclusionAssignment<N>>, N::StateRoot)> {
-        prepare_impl!(self, transitions, query, current_state_root, get_state_path_for_commitment)
+        // Fetch the state paths of the global records concurrently, as each fetch may be a round trip to a node.
+        let commitments = self.global_commitments(transitions);
+        let mut prefetched = cfg_iter!(commitments)
+            .map(|commitment| Ok((*commitment, query.get_state_path_for_commitment(commitment)?)))
+            .collect::<Result<HashMap<_, _>>>()?;
+        prepare_impl!(self, transitions, query, prefetched, current_state_root, get_state_path_for_commitment)
     }
 
     /// Returns the inclusion assignments for the given transitions.
@@ -117,6 +126,27 @@ impl<N: Network> Inclusion<N> {
         transitions: &[Transition<N>],
         query: impl QueryTrait<N>,
     ) -> Result<(Vec<InclusionAssignment<N>>, N::StateRoot)> {
-        prepare_impl!(self, transitions, query, current_state_root_async, get_state_path_for_commitment_async, await)
+        let mut prefetched = HashMap::<Field<N>, StatePath<N>>::new();
+        prepare_impl!(
+            self,
+            transitions,
+            query,
+            prefetched,
+            current_state_root_async,
+            get_state_path_for_commitment_async,
+            await
+        )
+    }
+
+    /// Returns the commitments of the records that are consumed by the given transitions,
+    /// and were created in a previous transaction.
+    fn global_commitments(&self, transitions: &[Transition<N>]) -> Vec<Field<N>> {
+        transitions
+            .iter()
+            .filter_map(|transition| self.input_tasks.get(transition.id()))
+            .flatten()
+            .filter(|task| task.local.is_none())
+            .map(|task| task.commitment)
+            .collect()
     }
 }
@@ -30,6 +30,9 @@ use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 #[derive(Clone, Debug, Default)]
 pub struct Trace<N: Network> {
     /// The list of transitions.
@@ -121,7 +124,7 @@ impl<N: Network> Trace<N> {
 
 impl<N: Network> Trace<N> {
     /// Returns the inclusion assignments and global state root for the current transition(s).
-    pub fn prepare(&mut self, query: impl QueryTrait<N>) -> Result<()> {
+    pub fn prepare(&mut self, query: impl QueryTrait<N> + Sync) -> Result<()> {
         // Compute the inclusion assignments.
         let (inclusion_assignments, global_state_root) = self.inclusion_tasks.prepare(&self.transitions, query)?;
         // Store the inclusion assignments and global state root.
@@ -270,17 +273,17 @@ impl<N: Network> Trace<N> {
             bail!("Inclusion expected the global state root in the execution to *not* be zero")
         }
 
-        // Initialize a vector for the batch inclusion assignments.
-        let mut batch_inclusions = Vec::with_capacity(inclusion_assignments.len());
-
         for assignment in inclusion_assignments.iter() {
             // Ensure the global state root is the same across iterations.
             if global_state_root != assignment.state_path.global_state_root() {
                 bail!("Inclusion expected the global state root to be the same across iterations")
             }
-            // Add the assignment to the assignments.
-            batch_inclusions.push(assignment.to_circuit_assignment::<A>()?);
         }
+        // Synthesize the inclusion assignments in parallel, as each circuit is synthesized
+        // in the (thread-local) circuit environment of the thread that synthesizes it.
+        let batch_inclusions = cfg_iter!(inclusion_assignments)
+            .map(|assignment| assignment.to_circuit_assignment::<A>())
+            .collect::<Result<Vec<_>>>()?;
 
         if !batch_inclusions.is_empty() {
             // Fetch the inclusion proving key.
@@ -148,6 +148,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         Self::from_str(include_str!("./resources/credits.aleo"))
     }
 
+    /// Initializes the hash-time-locked swap program, which is deployed like any other program.
+    #[inline]
+    pub fn htlc() -> Result<Self> {
+        Self::from_str(include_str!("./resources/htlc.aleo"))
+    }
+
     /// Returns the ID of the program.
     pub const fn id(&self) -> &ProgramID<N> {
         &self.id
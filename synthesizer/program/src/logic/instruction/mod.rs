@@ -201,6 +201,8 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Performs a Keccak hash on the bytes of a `u8` array, outputting the 32 bytes of the digest.
+    HashKeccak256Raw(HashKeccak256Raw<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -300,6 +302,8 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New instructions are appended, so that the opcode indices of existing instructions are unchanged.
+            HashKeccak256Raw,
         }}
     };
     // A variant **without** curly braces:
@@ -469,7 +473,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            69,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
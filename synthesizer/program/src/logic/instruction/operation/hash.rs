@@ -20,6 +20,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U8,
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -64,6 +65,11 @@ pub type HashManyPSD4<N> = HashInstruction<N, { Hasher::HashManyPSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashManyPSD8<N> = HashInstruction<N, { Hasher::HashManyPSD8 as u8 }>;
 
+/// Keccak256 is a cryptographic hash function that outputs a 256-bit digest.
+/// This variant hashes the bytes of a `u8` array, and outputs the digest as a `[u8; 32u32]` array,
+/// so that it matches the Keccak-256 digests of other chains (e.g. Ethereum).
+pub type HashKeccak256Raw<N> = HashInstruction<N, { Hasher::HashKeccak256Raw as u8 }>;
+
 enum Hasher {
     HashBHP256,
     HashBHP512,
@@ -83,6 +89,7 @@ enum Hasher {
     HashManyPSD2,
     HashManyPSD4,
     HashManyPSD8,
+    HashKeccak256Raw,
 }
 
 /// Returns the expected number of operands given the variant.
@@ -104,11 +111,67 @@ fn check_number_of_operands(variant: u8, opcode: Opcode, num_operands: usize) ->
 }
 
 /// Returns 'true' if the destination type is valid.
-fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) -> bool {
-    !matches!(
-        destination_type,
-        PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String)
-    )
+fn is_valid_destination_type<N: Network>(variant: u8, destination_type: &PlaintextType<N>) -> bool {
+    match variant {
+        // The raw variant outputs the 32 bytes of the digest.
+        18 => match destination_type {
+            PlaintextType::Array(array_type) => {
+                array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8)
+                    && **array_type.length() == 32
+            }
+            _ => false,
+        },
+        _ => !matches!(
+            destination_type,
+            PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String)
+        ),
+    }
+}
+
+/// Returns the Keccak-256 digest of the bytes of the given `u8` array, as a `[u8; 32u32]` array.
+fn hash_keccak256_raw<N: Network>(input: &Value<N>) -> Result<Value<N>> {
+    // Retrieve the bits of the bytes, without the type information of the array.
+    let bits = match input {
+        Value::Plaintext(Plaintext::Array(elements, _)) => elements
+            .iter()
+            .map(|element| match element {
+                Plaintext::Literal(Literal::U8(byte), _) => Ok(byte.to_bits_le()),
+                _ => bail!("Expected a 'u8' array as the input to the raw hash"),
+            })
+            .collect::<Result<Vec<_>>>()?
+            .concat(),
+        _ => bail!("Expected a 'u8' array as the input to the raw hash"),
+    };
+    // Hash the bits, and output the bytes of the digest.
+    let digest = N::hash_keccak256(&bits)?
+        .chunks(8)
+        .map(|byte| Ok(Plaintext::from(Literal::U8(U8::from_bits_le(byte)?))))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Value::Plaintext(Plaintext::Array(digest, Default::default())))
+}
+
+/// Returns the Keccak-256 digest of the bytes of the given `u8` array, as a `[u8; 32u32]` array.
+fn hash_keccak256_raw_circuit<A: circuit::Aleo>(input: &circuit::Value<A>) -> Result<circuit::Value<A>> {
+    use circuit::traits::{FromBits, ToBits};
+
+    // Retrieve the bits of the bytes, without the type information of the array.
+    let bits = match input {
+        circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => elements
+            .iter()
+            .map(|element| match element {
+                circuit::Plaintext::Literal(circuit::Literal::U8(byte), _) => Ok(byte.to_bits_le()),
+                _ => bail!("Expected a 'u8' array as the input to the raw hash"),
+            })
+            .collect::<Result<Vec<_>>>()?
+            .concat(),
+        _ => bail!("Expected a 'u8' array as the input to the raw hash"),
+    };
+    // Hash the bits, and output the bytes of the digest.
+    let digest = A::hash_keccak256(&bits)
+        .chunks(8)
+        .map(|byte| circuit::Plaintext::from(circuit::Literal::U8(circuit::U8::from_bits_le(byte))))
+        .collect();
+    Ok(circuit::Value::Plaintext(circuit::Plaintext::Array(digest, Default::default())))
 }
 
 /// Hashes the operand into the declared type.
@@ -133,7 +196,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Sanity check the number of operands.
         check_number_of_operands(VARIANT, Self::opcode(), operands.len())?;
         // Sanity check the destination type.
-        if !is_valid_destination_type(&destination_type) {
+        if !is_valid_destination_type(VARIANT, &destination_type) {
             bail!("Invalid destination type for 'hash' instruction")
         }
         // Return the instruction.
@@ -162,7 +225,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             15 => Opcode::Hash("hash_many.psd2"),
             16 => Opcode::Hash("hash_many.psd4"),
             17 => Opcode::Hash("hash_many.psd8"),
-            18.. => panic!("Invalid 'hash' instruction opcode"),
+            18 => Opcode::Hash("hash.keccak256.raw"),
+            19.. => panic!("Invalid 'hash' instruction opcode"),
         }
     }
 
@@ -203,10 +267,19 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
+
+        // Hash the bytes of the input, and output the bytes of the digest.
+        if VARIANT == 18 {
+            return registers.store(stack, &self.destination, hash_keccak256_raw(&input)?);
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => Literal::Group(N::hash_to_group_bhp256(&input.to_bits_le())?),
@@ -274,10 +347,19 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
+
+        // Hash the bytes of the input, and output the bytes of the digest.
+        if VARIANT == 18 {
+            return registers.store_circuit(stack, &self.destination, hash_keccak256_raw_circuit(&input)?);
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => circuit::Literal::Group(A::hash_to_group_bhp256(&input.to_bits_le())),
@@ -357,14 +439,26 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
             0..=14 => Ok(vec![RegisterType::Plaintext(self.destination_type.clone())]),
             15..=17 => bail!("'hash_many' is not yet implemented"),
-            18.. => bail!("Invalid 'hash' variant: {VARIANT}"),
+            18 => {
+                // Ensure the input is a `u8` array.
+                match &input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Array(array_type))
+                        if array_type.next_element_type() == &PlaintextType::Literal(LiteralType::U8) => {}
+                    input_type => bail!("Instruction '{}' expects a 'u8' array, found '{input_type}'", Self::opcode()),
+                }
+                Ok(vec![RegisterType::Plaintext(self.destination_type.clone())])
+            }
+            19.. => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
 }
@@ -413,13 +507,11 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         // Parse the destination register type from the string.
         let (string, destination_type) = PlaintextType::parse(string)?;
         // Ensure the destination type is allowed.
-        match destination_type {
-            PlaintextType::Literal(LiteralType::Boolean) | PlaintextType::Literal(LiteralType::String) => {
-                map_res(fail, |_: ParserResult<Self>| {
-                    Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
-                })(string)
-            }
-            _ => Ok((string, Self { operands, destination, destination_type })),
+        match is_valid_destination_type(VARIANT, &destination_type) {
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse 'hash': '{destination_type}' is invalid")))
+            })(string),
+            true => Ok((string, Self { operands, destination, destination_type })),
         }
     }
 }
@@ -530,4 +622,53 @@ mod tests {
             assert_eq!(&hash.destination_type, destination_type, "The destination type is incorrect");
         }
     }
+
+    #[test]
+    fn test_parse_raw() {
+        let (string, hash) =
+            HashKeccak256Raw::<CurrentNetwork>::parse("hash.keccak256.raw r0 into r1 as [u8; 32u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands, vec![Operand::Register(Register::Locator(0))]);
+        assert_eq!(hash.destination_type.to_string(), "[u8; 32u32]");
+
+        // Ensure the raw variant only outputs the 32 bytes of the digest.
+        for destination_type in ["field", "[u8; 31u32]", "[u16; 32u32]"] {
+            let instruction = format!("hash.keccak256.raw r0 into r1 as {destination_type}");
+            assert!(HashKeccak256Raw::<CurrentNetwork>::parse(&instruction).is_err());
+        }
+        // Ensure the typed variant does not parse the raw variant.
+        assert!(HashKeccak256::<CurrentNetwork>::from_str("hash.keccak256.raw r0 into r1 as [u8; 32u32]").is_err());
+    }
+
+    #[test]
+    fn test_hash_keccak256_raw() {
+        use circuit::{Eject, Environment, Inject};
+
+        type CurrentAleo = circuit::AleoV0;
+
+        // Returns the given bytes as a `u8` array.
+        let to_array = |bytes: &[u8]| {
+            let bytes = bytes.iter().map(|byte| Plaintext::from(Literal::U8(U8::new(*byte)))).collect();
+            Value::<CurrentNetwork>::Plaintext(Plaintext::Array(bytes, Default::default()))
+        };
+
+        // Ensure the digest matches the Keccak-256 digest of the bytes.
+        let input = to_array(b"abc");
+        let expected = to_array(&[
+            0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67, 0xc0, 0xd1,
+            0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45,
+        ]);
+        assert_eq!(hash_keccak256_raw(&input).unwrap(), expected);
+
+        // Ensure the circuit matches.
+        let circuit_input = circuit::Value::<CurrentAleo>::new(circuit::Mode::Private, input);
+        let candidate = hash_keccak256_raw_circuit(&circuit_input).unwrap();
+        assert_eq!(candidate.eject_value(), expected);
+        assert!(CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+
+        // Ensure the raw variant rejects other inputs.
+        assert!(hash_keccak256_raw(&Value::<CurrentNetwork>::from_str("1u8").unwrap()).is_err());
+        assert!(hash_keccak256_raw(&Value::<CurrentNetwork>::from_str("[1u16, 2u16]").unwrap()).is_err());
+    }
 }
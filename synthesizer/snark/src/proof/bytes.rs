@@ -14,29 +14,109 @@
 
 use super::*;
 
+use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+
+/// The version of a proof whose Varuna proof is in the compressed encoding.
+const COMPRESSED_VERSION: u8 = 1;
+/// The version of a proof whose Varuna proof is in the uncompressed encoding.
+const UNCOMPRESSED_VERSION: u8 = 2;
+
 impl<N: Network> FromBytes for Proof<N> {
-    /// Reads the proof from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    /// Reads the proof from a buffer, in the compressed encoding.
+    /// The uncompressed encoding is rejected, as it is not part of the consensus encoding of a proof.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_with_mode(reader, Compress::Yes, Validate::Yes)
+    }
+}
+
+/// The wire layout of a proof is:
+///
+/// | Field         | Size            | Description                                                    |
+/// |---------------|-----------------|----------------------------------------------------------------|
+/// | version       | 1 byte          | `1` for the compressed encoding, `2` for the uncompressed one  |
+/// | batch sizes   | 8 + 8 * n bytes | the number of circuits `n`, then the batch size of each one    |
+/// | commitments   | variable        | the commitments to the witness and prover polynomials          |
+/// | evaluations   | variable        | the evaluations of `g_1`, `g_a`, `g_b`, and `g_c`              |
+/// | prover sums   | variable        | the sums in the third and fourth prover messages               |
+/// | opening proof | variable        | the batched polynomial commitment opening proof                |
+///
+/// Every field after the version byte is the `CanonicalSerialize` encoding of `varuna::Proof`,
+/// in the mode selected by the version byte. All integers are little-endian.
+/// Only the compressed encoding is accepted by `read_le`, which is used to decode executions and fees;
+/// the uncompressed encoding is for local storage only, and is read with `read_le_uncompressed`.
+impl<N: Network> ToBytes for Proof<N> {
+    /// Writes the proof to a buffer, in the compressed encoding.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_with_mode(writer, Compress::Yes)
+    }
+}
+
+impl<N: Network> Proof<N> {
+    /// Reads the proof from a buffer, in the compressed encoding,
+    /// without checking that its curve points are in the prime-order subgroup.
+    /// This must only be used for trusted bytes, such as a proof this node has already verified and stored.
+    pub fn read_le_unchecked<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_with_mode(reader, Compress::Yes, Validate::No)
+    }
+
+    /// Reads the proof from a buffer, in the uncompressed encoding.
+    /// This must only be used for bytes written locally by `write_le_uncompressed`, such as a proof cache,
+    /// and never for proofs received from peers, which must be read with `read_le`.
+    pub fn read_le_uncompressed<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_with_mode(reader, Compress::No, Validate::Yes)
+    }
+
+    /// Writes the proof to a buffer, in the uncompressed encoding.
+    /// The uncompressed encoding is larger, but is faster to decode with `read_le_uncompressed`.
+    pub fn write_le_uncompressed<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_with_mode(writer, Compress::No)
+    }
+
+    /// Returns the proof as bytes, in the uncompressed encoding.
+    pub fn to_bytes_le_uncompressed(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(self.serialized_size_uncompressed());
+        self.write_le_uncompressed(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the number of bytes written by `write_le`, which uses the compressed encoding.
+    pub fn serialized_size(&self) -> usize {
+        1 + self.proof.serialized_size(Compress::Yes)
+    }
+
+    /// Returns the number of bytes written by `write_le_uncompressed`.
+    pub fn serialized_size_uncompressed(&self) -> usize {
+        1 + self.proof.serialized_size(Compress::No)
+    }
+
+    /// Reads the proof from a buffer, in the given encoding.
+    fn read_le_with_mode<R: Read>(mut reader: R, compress: Compress, validate: Validate) -> IoResult<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
-        // Ensure the version is valid.
-        if version != 1 {
+        // Ensure the version matches the encoding.
+        let expected_version = match compress {
+            Compress::Yes => COMPRESSED_VERSION,
+            Compress::No => UNCOMPRESSED_VERSION,
+        };
+        if version != expected_version {
             return Err(error("Invalid proof version"));
         }
         // Read the proof.
-        let proof = FromBytes::read_le(&mut reader)?;
+        let proof = varuna::Proof::deserialize_with_mode(&mut reader, compress, validate)
+            .map_err(|_| error("could not deserialize proof"))?;
         // Return the proof.
         Ok(Self { proof })
     }
-}
 
-impl<N: Network> ToBytes for Proof<N> {
-    /// Writes the proof to a buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    /// Writes the proof to a buffer, in the given encoding.
+    fn write_le_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
-        // Write the bytes.
-        self.proof.write_le(&mut writer)
+        match compress {
+            Compress::Yes => COMPRESSED_VERSION.write_le(&mut writer)?,
+            Compress::No => UNCOMPRESSED_VERSION.write_le(&mut writer)?,
+        }
+        // Write the proof.
+        self.proof.serialize_with_mode(&mut writer, compress).map_err(|_| error("could not serialize proof"))
     }
 }
 
@@ -51,7 +131,35 @@ mod tests {
 
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes[0], COMPRESSED_VERSION);
+        assert_eq!(expected_bytes.len(), expected.serialized_size());
         assert_eq!(expected, Proof::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, Proof::read_le_unchecked(&expected_bytes[..])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_uncompressed() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Check the uncompressed byte representation.
+        let expected_bytes = expected.to_bytes_le_uncompressed()?;
+        assert_eq!(expected_bytes[0], UNCOMPRESSED_VERSION);
+        assert_eq!(expected_bytes.len(), expected.serialized_size_uncompressed());
+        assert!(expected.serialized_size_uncompressed() > expected.serialized_size());
+        assert_eq!(expected, Proof::read_le_uncompressed(&expected_bytes[..])?);
+
+        // Check that the uncompressed encoding is rejected by the consensus readers, and vice versa.
+        assert!(Proof::<console::network::Testnet3>::read_le(&expected_bytes[..]).is_err());
+        assert!(Proof::<console::network::Testnet3>::read_le_unchecked(&expected_bytes[..]).is_err());
+        assert!(Proof::<console::network::Testnet3>::read_le_uncompressed(&expected.to_bytes_le()?[..]).is_err());
+
+        // Check that an unknown version is rejected.
+        let mut invalid_bytes = expected_bytes;
+        invalid_bytes[0] = 0;
+        assert!(Proof::<console::network::Testnet3>::read_le_uncompressed(&invalid_bytes[..]).is_err());
 
         Ok(())
     }
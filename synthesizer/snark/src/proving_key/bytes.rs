@@ -39,3 +39,70 @@ impl<N: Network> ToBytes for ProvingKey<N> {
         self.proving_key.write_le(&mut writer)
     }
 }
+
+impl<N: Network> ProvingKey<N> {
+    /// Reads a proving key in the uncompressed layout from a buffer, without validating its group elements.
+    /// The buffer must come from a trusted source, or the proving key must be validated with `check`.
+    pub fn read_uncompressed_unchecked_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 2 {
+            return Err(error("Invalid uncompressed proving key version"));
+        }
+        // Read the proving key.
+        let proving_key = Arc::new(varuna::CircuitProvingKey::read_uncompressed_unchecked(&mut reader)?);
+        // Return the proving key.
+        Ok(Self { proving_key })
+    }
+
+    /// Writes the proving key in the uncompressed layout to a buffer, which is read without point decompression.
+    pub fn write_uncompressed_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        2u8.write_le(&mut writer)?;
+        // Write the bytes.
+        self.proving_key.write_uncompressed(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Sample the proving key.
+        let (expected, _) = crate::test_helpers::sample_keys();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(*expected, *ProvingKey::<CurrentNetwork>::read_le(&expected_bytes[..])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_uncompressed_bytes() -> Result<()> {
+        // Sample the proving key.
+        let (expected, _) = crate::test_helpers::sample_keys();
+
+        // Check the uncompressed byte representation.
+        let mut expected_bytes = vec![];
+        expected.write_uncompressed_le(&mut expected_bytes)?;
+        assert_eq!(2, expected_bytes[0]);
+        let candidate = ProvingKey::<CurrentNetwork>::read_uncompressed_unchecked_le(&expected_bytes[..])?;
+        candidate.check()?;
+        assert_eq!(*expected, *candidate);
+        assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+
+        // Ensure the layouts cannot be confused.
+        assert!(ProvingKey::<CurrentNetwork>::read_le(&expected_bytes[..]).is_err());
+        let compressed_bytes = expected.to_bytes_le()?;
+        assert!(ProvingKey::<CurrentNetwork>::read_uncompressed_unchecked_le(&compressed_bytes[..]).is_err());
+
+        Ok(())
+    }
+}
@@ -14,6 +14,8 @@
 
 use super::*;
 
+use snarkvm_utilities::CanonicalDeserialize;
+
 impl<N: Network> FromBytes for VerifyingKey<N> {
     /// Reads the verifying key from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
@@ -39,3 +41,41 @@ impl<N: Network> ToBytes for VerifyingKey<N> {
         self.verifying_key.write_le(&mut writer)
     }
 }
+
+impl<N: Network> VerifyingKey<N> {
+    /// Reads the verifying key from a buffer, without checking that its curve points are in the prime-order subgroup.
+    /// This must only be used for trusted bytes, such as a verifying key this node has already verified and stored.
+    pub fn read_le_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid verifying key version"));
+        }
+        // Read the verifying key, skipping the point validation.
+        let verifying_key = Arc::new(
+            varuna::CircuitVerifyingKey::deserialize_compressed_unchecked(&mut reader)
+                .map_err(|_| error("could not deserialize verifying key"))?,
+        );
+        // Return the verifying key.
+        Ok(Self { verifying_key })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Sample the verifying key.
+        let (_, expected) = crate::test_helpers::sample_keys();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, VerifyingKey::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, VerifyingKey::read_le_unchecked(&expected_bytes[..])?);
+
+        Ok(())
+    }
+}
@@ -30,6 +30,36 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Transaction<N>> {
+        self.deploy_with_fee_selector(
+            private_key,
+            program,
+            |_| Ok(fee_record),
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a new deploy transaction, where the fee record is chosen once the deployment cost is known.
+    ///
+    /// The `select_fee_record` closure is given the minimum deployment cost (the base fee) in microcredits.
+    /// If it returns a fee record, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    pub fn deploy_with_fee_selector<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        select_fee_record: impl FnOnce(u64) -> Result<Option<Record<N, Plaintext<N>>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let _span = debug_span!("VM::deploy").entered();
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
         // Compute the deployment.
         let deployment = self.deploy_raw(program, rng)?;
         // Ensure the transaction is not empty.
@@ -42,7 +72,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Compute the minimum deployment cost.
         let (minimum_deployment_cost, (_, _)) = deployment_cost(&deployment)?;
         // Authorize the fee.
-        let fee_authorization = match fee_record {
+        let fee_authorization = match select_fee_record(minimum_deployment_cost)? {
             Some(record) => self.authorize_fee_private(
                 private_key,
                 record,
@@ -62,15 +92,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Compute the fee.
         let fee = self.execute_fee_authorization(fee_authorization, query, rng)?;
 
+        // Construct the deploy transaction.
+        let transaction = Transaction::from_deployment(owner, deployment, fee)?;
+
+        #[cfg(feature = "metrics")]
+        {
+            metrics::increment_counter(metrics::synthesizer::DEPLOYMENTS, 1);
+            metrics::histogram_elapsed(metrics::synthesizer::DEPLOY_TIME, start);
+        }
         // Return the deploy transaction.
-        Transaction::from_deployment(owner, deployment, fee)
+        Ok(transaction)
     }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Returns a deployment for the given program.
+    /// Returns a deployment for the given program, without its owner and fee.
+    /// This can be used to compute the deployment cost before choosing how to pay the fee.
     #[inline]
-    pub(super) fn deploy_raw<R: Rng + CryptoRng>(&self, program: &Program<N>, rng: &mut R) -> Result<Deployment<N>> {
+    pub fn deploy_raw<R: Rng + CryptoRng>(&self, program: &Program<N>, rng: &mut R) -> Result<Deployment<N>> {
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the program.
@@ -33,6 +33,38 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Transaction<N>> {
+        self.execute_with_fee_selector(
+            private_key,
+            (program_id, function_name),
+            inputs,
+            |_| Ok(fee_record),
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a new execute transaction, where the fee record is chosen once the execution cost is known.
+    ///
+    /// If a fee is required, the `select_fee_record` closure is given the minimum execution cost (the base fee)
+    /// in microcredits. If it returns a fee record, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    pub fn execute_with_fee_selector<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        select_fee_record: impl FnOnce(u64) -> Result<Option<Record<N, Plaintext<N>>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let _span = debug_span!("VM::execute").entered();
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
         // Compute the authorization.
         let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
         // Determine if a fee is required.
@@ -49,7 +81,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Compute the execution ID.
                 let execution_id = execution.to_execution_id()?;
                 // Authorize the fee.
-                let authorization = match fee_record {
+                let authorization = match select_fee_record(minimum_execution_cost)? {
                     Some(record) => self.authorize_fee_private(
                         private_key,
                         record,
@@ -71,11 +103,26 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
             false => None,
         };
+        // Construct the execute transaction.
+        let transaction = Transaction::from_execution(execution, fee)?;
+
+        #[cfg(feature = "metrics")]
+        {
+            metrics::increment_counter(metrics::synthesizer::EXECUTIONS, 1);
+            metrics::histogram_elapsed(metrics::synthesizer::EXECUTE_TIME, start);
+        }
         // Return the execute transaction.
-        Transaction::from_execution(execution, fee)
+        Ok(transaction)
     }
 
     /// Returns a new execute transaction for the given authorization.
+    ///
+    /// This method does not require the private key, so a transaction can be assembled in two steps:
+    ///   1. On the (offline) signing device, call `VM::authorize` to sign the execution requests, and
+    ///      then `VM::authorize_fee_public` or `VM::authorize_fee_private` with the execution ID from
+    ///      `Authorization::to_execution_id`.
+    ///   2. Transfer the authorizations (which are serializable) to an online device, which fetches the
+    ///      state paths from the `query`, proves the execution and fee, and assembles the transaction.
     pub fn execute_authorization<R: Rng + CryptoRng>(
         &self,
         execute_authorization: Authorization<N>,
@@ -281,6 +328,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_execute_authorization_from_offline_step() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ]
+        .into_iter();
+
+        // Perform the offline step, by authorizing the execution and fee.
+        let execute_authorization =
+            vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs, rng).unwrap();
+        let execution_id = execute_authorization.to_execution_id().unwrap();
+        let fee_authorization = vm.authorize_fee_public(&caller_private_key, 300000, 0, execution_id, rng).unwrap();
+
+        // Serialize the authorizations, to transfer them to the online device.
+        let execute_authorization_bytes = execute_authorization.to_bytes_le().unwrap();
+        let fee_authorization_bytes = fee_authorization.to_bytes_le().unwrap();
+
+        // Perform the online step, by proving the authorizations and assembling the transaction.
+        let execute_authorization = Authorization::read_le(&execute_authorization_bytes[..]).unwrap();
+        let fee_authorization = Authorization::read_le(&fee_authorization_bytes[..]).unwrap();
+        let transaction = vm.execute_authorization(execute_authorization, Some(fee_authorization), None, rng).unwrap();
+
+        // Ensure the transaction is valid.
+        assert_eq!(transaction.fee_payer(), Some(address));
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+
     #[test]
     fn test_transfer_public_transaction_size() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A builder for a genesis block with a custom committee, public balances, and genesis records.
+///
+/// Note: The genesis timestamp and targets are fixed by the network (e.g. `N::GENESIS_TIMESTAMP`),
+/// as they are checked by `Block::is_genesis`.
+#[derive(Clone)]
+pub struct GenesisBuilder<N: Network> {
+    /// The committee members, as a map of `address` to `(stake, is_open)`.
+    members: IndexMap<Address<N>, (u64, bool)>,
+    /// The public balances, as a map of `address` to `microcredits`.
+    public_balances: IndexMap<Address<N>, u64>,
+    /// The genesis records, as a list of `(owner, microcredits)`.
+    records: Vec<(Address<N>, u64)>,
+}
+
+impl<N: Network> Default for GenesisBuilder<N> {
+    /// Initializes a new genesis builder.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> GenesisBuilder<N> {
+    /// Initializes a new genesis builder.
+    pub fn new() -> Self {
+        Self { members: IndexMap::new(), public_balances: IndexMap::new(), records: Vec::new() }
+    }
+
+    /// Adds a committee member with the given stake (in microcredits).
+    pub fn validator(mut self, address: Address<N>, stake: u64, is_open: bool) -> Self {
+        self.members.insert(address, (stake, is_open));
+        self
+    }
+
+    /// Adds the given amount (in microcredits) to the public balance of the given address.
+    pub fn public_balance(mut self, address: Address<N>, amount: u64) -> Self {
+        let balance = self.public_balances.entry(address).or_default();
+        *balance = balance.saturating_add(amount);
+        self
+    }
+
+    /// Adds a genesis record with the given amount (in microcredits) for the given owner.
+    /// The record is funded from the public balance of the genesis caller.
+    pub fn record(mut self, owner: Address<N>, amount: u64) -> Self {
+        self.records.push((owner, amount));
+        self
+    }
+
+    /// Returns a new genesis block, signed and executed by the given private key.
+    ///
+    /// If no genesis records are given, each genesis record transfers the minimum validator stake to the caller.
+    /// Otherwise, exactly `Block::NUM_GENESIS_TRANSACTIONS` genesis records must be given.
+    /// The total stake and public balances must add up to `N::STARTING_SUPPLY`.
+    pub fn build<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &self,
+        vm: &VM<N, C>,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        // Construct the committee.
+        let committee = Committee::<N>::new_genesis(self.members.clone())?;
+
+        // Prepare the genesis records.
+        let caller = Address::try_from(private_key)?;
+        let records = match self.records.is_empty() {
+            true => vec![(caller, ledger_committee::MIN_VALIDATOR_STAKE); Block::<N>::NUM_GENESIS_TRANSACTIONS],
+            false => self.records.clone(),
+        };
+
+        // Ensure the caller has a sufficient public balance to fund the genesis records.
+        let records_supply = records.iter().try_fold(0u64, |acc, (_, amount)| acc.checked_add(*amount));
+        let caller_balance = self.public_balances.get(&caller).copied().unwrap_or_default();
+        match records_supply {
+            Some(records_supply) => ensure!(
+                records_supply <= caller_balance,
+                "The genesis caller has an insufficient public balance to fund the genesis records \
+                (found '{caller_balance}', expected at least '{records_supply}')"
+            ),
+            None => bail!("Invalid supply of the genesis records"),
+        }
+
+        // Return the genesis block.
+        vm.genesis_with_records(private_key, committee, self.public_balances.clone(), &records, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::ViewKey, network::Testnet3, program::Entry};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_genesis_builder() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Sample the validators and the record owner.
+        let private_keys = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+        let owner_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = Address::try_from(&owner_private_key).unwrap();
+        let owner_view_key = ViewKey::try_from(&owner_private_key).unwrap();
+
+        // Prepare the genesis builder.
+        let stake = ledger_committee::MIN_VALIDATOR_STAKE;
+        let remaining_supply = <CurrentNetwork as Network>::STARTING_SUPPLY - stake * 4;
+        let mut builder = GenesisBuilder::new();
+        for private_key in &private_keys {
+            builder = builder.validator(Address::try_from(private_key).unwrap(), stake, true);
+        }
+        let builder = builder.public_balance(Address::try_from(&private_keys[0]).unwrap(), remaining_supply);
+
+        // Ensure the builder requires the correct number of genesis records.
+        assert!(builder.clone().record(owner, 1_000_000).build(&vm, &private_keys[0], rng).is_err());
+        // Ensure the builder requires the total supply to match.
+        let invalid_builder = builder.clone().public_balance(owner, 1);
+        assert!(invalid_builder.build(&vm, &private_keys[0], rng).is_err());
+
+        // Build the genesis block, with the genesis records for the owner.
+        let amounts = [1_000_000, 2_000_000, 3_000_000, 4_000_000];
+        let builder = amounts.iter().fold(builder, |builder, amount| builder.record(owner, *amount));
+        let genesis = builder.build(&vm, &private_keys[0], rng).unwrap();
+        assert!(genesis.is_genesis());
+
+        // Ensure the owner received the genesis records.
+        let mut received = genesis
+            .records()
+            .filter_map(|(_, record)| record.decrypt(&owner_view_key).ok())
+            .map(|record| match record.find(&[Identifier::from_str("microcredits").unwrap()]) {
+                Ok(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => *amount,
+                _ => panic!("Invalid genesis record"),
+            })
+            .collect::<Vec<_>>();
+        received.sort_unstable();
+        assert_eq!(received, amounts);
+
+        // Ensure the genesis block can be added to a VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+    }
+}
@@ -187,6 +187,7 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
         Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
+        Command::Instruction(Instruction::HashKeccak256Raw(_)) => Ok(100_000),
         // TODO: The following 'finalize' commands are currently priced higher than expected.
         //  Expect these numbers to change as their usage is stabilized.
         Command::Await(_) => Ok(2_000),
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::Address,
+    network::prelude::*,
+    program::{Identifier, Literal, LiteralType, Plaintext, Record, Value},
+    types::{Field, Group, U32, U64, U8},
+};
+
+use indexmap::IndexMap;
+
+/// Returns the hash lock for the given 32-byte preimage, as computed by `htlc.aleo/redeem`.
+///
+/// The hash lock is the Keccak-256 digest of the bytes of the preimage, so the counterpart of a swap
+/// on another chain (e.g. `keccak256(preimage)` on Ethereum) can be locked under the same hash.
+pub fn htlc_hash_lock<N: Network>(preimage: &[u8; 32]) -> Result<[u8; 32]> {
+    // Hash the bytes of the preimage, as the `hash.keccak256.raw` instruction does.
+    let digest = N::hash_keccak256(&preimage.to_bits_le())?;
+    let mut hash_lock = [0u8; 32];
+    for (byte, bits) in hash_lock.iter_mut().zip_eq(digest.chunks(8)) {
+        *byte = u8::from_bits_le(bits)?;
+    }
+    Ok(hash_lock)
+}
+
+/// Returns the key of the given swap in the `htlc.aleo/swaps` mapping, as computed from the sender and the swap ID.
+pub fn htlc_swap_key<N: Network>(sender: Address<N>, swap_id: Field<N>) -> Result<Field<N>> {
+    // Construct the swap key as a plaintext struct, as the `cast` instruction does.
+    let members = IndexMap::from([
+        (Identifier::from_str("sender")?, Plaintext::from(Literal::Address(sender))),
+        (Identifier::from_str("id")?, Plaintext::from(Literal::Field(swap_id))),
+    ]);
+    let input = Value::Plaintext(Plaintext::Struct(members, Default::default()));
+    // Hash the swap key, as the `hash.bhp256` instruction does.
+    htlc_cast_to_field(N::hash_to_group_bhp256(&input.to_bits_le())?)
+}
+
+/// Returns the inputs to `htlc.aleo/lock`, which locks `amount` microcredits from the given record
+/// until `receiver` redeems them with the preimage of `hash_lock`, or until the block height reaches `expiration`.
+pub fn htlc_lock_inputs<N: Network>(
+    record: Record<N, Plaintext<N>>,
+    receiver: Address<N>,
+    amount: u64,
+    hash_lock: &[u8; 32],
+    expiration: u32,
+    swap_id: Field<N>,
+) -> Vec<Value<N>> {
+    vec![
+        Value::from(record),
+        Value::from(Literal::Address(receiver)),
+        Value::from(Literal::U64(U64::new(amount))),
+        Value::Plaintext(htlc_bytes(hash_lock)),
+        Value::from(Literal::U32(U32::new(expiration))),
+        Value::from(Literal::Field(swap_id)),
+    ]
+}
+
+/// Returns the inputs to `htlc.aleo/redeem`, which sends the `amount` microcredits of the swap
+/// with the given sender and swap ID to the caller.
+pub fn htlc_redeem_inputs<N: Network>(
+    sender: Address<N>,
+    swap_id: Field<N>,
+    preimage: &[u8; 32],
+    amount: u64,
+) -> Vec<Value<N>> {
+    vec![
+        Value::from(Literal::Address(sender)),
+        Value::from(Literal::Field(swap_id)),
+        Value::Plaintext(htlc_bytes(preimage)),
+        Value::from(Literal::U64(U64::new(amount))),
+    ]
+}
+
+/// Returns the inputs to `htlc.aleo/refund`, which returns the `amount` microcredits of the given expired swap to the caller.
+pub fn htlc_refund_inputs<N: Network>(swap_id: Field<N>, amount: u64) -> Vec<Value<N>> {
+    vec![Value::from(Literal::Field(swap_id)), Value::from(Literal::U64(U64::new(amount)))]
+}
+
+/// Returns the given bytes as a plaintext array of bytes.
+fn htlc_bytes<N: Network>(bytes: &[u8; 32]) -> Plaintext<N> {
+    let bytes = bytes.iter().map(|byte| Plaintext::from(Literal::U8(U8::new(*byte)))).collect();
+    Plaintext::Array(bytes, Default::default())
+}
+
+/// Casts the given hash to a field element, as the `field` destination type of a hash instruction does.
+fn htlc_cast_to_field<N: Network>(hash: Group<N>) -> Result<Field<N>> {
+    match Literal::Group(hash).cast_lossy(LiteralType::Field)? {
+        Literal::Field(hash) => Ok(hash),
+        _ => bail!("Failed to cast the hash to a field element"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{sample_finalize_state, sample_next_block, sample_vm};
+    use console::{
+        account::{PrivateKey, ViewKey},
+        program::ProgramID,
+    };
+    use ledger_block::{Transaction, Transition};
+    use synthesizer_process::Process;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_htlc_hashes_match_program() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program that computes the hash lock and the swap key as `htlc.aleo/redeem` does.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program hash_lock.aleo;
+
+struct swap_key:
+    sender as address;
+    id as field;
+
+function compute:
+    input r0 as address.public;
+    input r1 as field.public;
+    input r2 as [u8; 32u32].public;
+    cast r0 r1 into r3 as swap_key;
+    hash.bhp256 r3 into r4 as field;
+    hash.keccak256.raw r2 into r5 as [u8; 32u32];
+    output r4 as field.public;
+    output r5 as [u8; 32u32].public;",
+        )
+        .unwrap();
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        let private_key = PrivateKey::new(rng).unwrap();
+        for _ in 0..5 {
+            let sender = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+            let swap_id = Field::rand(rng);
+            let preimage: [u8; 32] = rng.gen();
+            // Evaluate the program.
+            let inputs = htlc_redeem_inputs(sender, swap_id, &preimage, 0);
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&private_key, program.id(), "compute", inputs[..3].iter(), rng)
+                .unwrap();
+            let response = process.evaluate::<CurrentAleo>(authorization).unwrap();
+            // Ensure the swap key and the hash lock match.
+            let expected = [
+                Value::from(Literal::Field(htlc_swap_key(sender, swap_id).unwrap())),
+                Value::Plaintext(htlc_bytes(&htlc_hash_lock::<CurrentNetwork>(&preimage).unwrap())),
+            ];
+            assert_eq!(response.outputs(), expected);
+        }
+    }
+
+    #[test]
+    fn test_htlc_hash_lock() {
+        // Ensure the hash lock is the Keccak-256 digest of the preimage, i.e. `keccak256(bytes32(0))` on Ethereum.
+        let hash_lock = htlc_hash_lock::<CurrentNetwork>(&[0; 32]).unwrap();
+        let expected = "290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563";
+        assert_eq!(hash_lock.iter().map(|byte| format!("{byte:02x}")).collect::<String>(), expected);
+    }
+
+    #[test]
+    fn test_htlc_program_inputs() {
+        let program = Program::<CurrentNetwork>::htlc().unwrap();
+        // Ensure the number of inputs matches each function.
+        for (function_name, num_inputs) in [("redeem", 4), ("refund", 2)] {
+            let function = program.get_function(&Identifier::from_str(function_name).unwrap()).unwrap();
+            assert_eq!(function.inputs().len(), num_inputs);
+        }
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut TestRng::default()).unwrap()).unwrap();
+        assert_eq!(htlc_redeem_inputs::<CurrentNetwork>(address, Field::zero(), &[0; 32], 1).len(), 4);
+        assert_eq!(htlc_refund_inputs::<CurrentNetwork>(Field::zero(), 1).len(), 2);
+        let function = program.get_function(&Identifier::from_str("lock").unwrap()).unwrap();
+        assert_eq!(function.inputs().len(), 6);
+    }
+
+    #[test]
+    fn test_htlc_execution() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the sender, who is also the receiver, as only the genesis account has public credits for fees.
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the VM with the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let record = genesis.transitions().cloned().flat_map(Transition::into_records).next().unwrap().1;
+        let record = record.decrypt(&view_key).unwrap();
+        let vm = sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program.
+        let deployment = vm.deploy(&private_key, &Program::htlc().unwrap(), None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[deployment], rng).unwrap()).unwrap();
+
+        // Lock the amount until block 10.
+        let (amount, expiration, swap_id) = (100, 10, Field::rand(rng));
+        let preimage: [u8; 32] = rng.gen();
+        let hash_lock = htlc_hash_lock::<CurrentNetwork>(&preimage).unwrap();
+        let inputs = htlc_lock_inputs(record, address, amount, &hash_lock, expiration, swap_id);
+        let lock = vm.execute(&private_key, ("htlc.aleo", "lock"), inputs.into_iter(), None, 0, None, rng).unwrap();
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[lock], rng).unwrap()).unwrap();
+
+        // Ensure the swap is open under the key of the sender and the swap ID.
+        let key = Plaintext::from(Literal::Field(htlc_swap_key(address, swap_id).unwrap()));
+        let swaps = Identifier::from_str("swaps").unwrap();
+        let program_id = ProgramID::from_str("htlc.aleo").unwrap();
+        assert!(vm.finalize_store().get_value_confirmed(program_id, swaps, &key).unwrap().is_some());
+
+        // Returns `true` if the given transaction is accepted at the given block height.
+        let is_accepted = |transaction: &Transaction<CurrentNetwork>, height: u32| {
+            let (_, transactions, aborted_ids, _) =
+                vm.speculate(sample_finalize_state(height), None, vec![], None, [transaction].into_iter()).unwrap();
+            assert!(aborted_ids.is_empty());
+            let is_accepted = transactions.iter().all(|transaction| transaction.is_accepted());
+            is_accepted
+        };
+
+        // Ensure the swap cannot be redeemed with the wrong preimage.
+        let inputs = htlc_redeem_inputs(address, swap_id, &[0; 32], amount);
+        let redeem = vm.execute(&private_key, ("htlc.aleo", "redeem"), inputs.into_iter(), None, 0, None, rng).unwrap();
+        assert!(!is_accepted(&redeem, expiration - 1));
+
+        // Ensure the swap is redeemed with the preimage before the expiration, but not after it.
+        let inputs = htlc_redeem_inputs(address, swap_id, &preimage, amount);
+        let redeem = vm.execute(&private_key, ("htlc.aleo", "redeem"), inputs.into_iter(), None, 0, None, rng).unwrap();
+        assert!(is_accepted(&redeem, expiration - 1));
+        assert!(!is_accepted(&redeem, expiration));
+
+        // Ensure the swap is refunded after the expiration, but not before it.
+        let inputs = htlc_refund_inputs(swap_id, amount);
+        let refund = vm.execute(&private_key, ("htlc.aleo", "refund"), inputs.into_iter(), None, 0, None, rng).unwrap();
+        assert!(!is_accepted(&refund, expiration - 1));
+        assert!(is_accepted(&refund, expiration));
+
+        // Ensure the swap of another sender with the same swap ID is a different swap.
+        let other = Address::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let inputs = htlc_redeem_inputs(other, swap_id, &preimage, amount);
+        let other_redeem =
+            vm.execute(&private_key, ("htlc.aleo", "redeem"), inputs.into_iter(), None, 0, None, rng).unwrap();
+        assert!(!is_accepted(&other_redeem, expiration - 1));
+
+        // Redeem the swap before the expiration, and ensure it is closed.
+        vm.add_next_block(&sample_next_block(&vm, &private_key, &[redeem], rng).unwrap()).unwrap();
+        assert!(vm.finalize_store().get_value_confirmed(program_id, swaps, &key).unwrap().is_none());
+    }
+}
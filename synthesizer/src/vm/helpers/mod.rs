@@ -18,6 +18,9 @@ pub use committee::*;
 mod cost;
 pub use cost::*;
 
+mod htlc;
+pub use htlc::*;
+
 mod macros;
 
 mod rewards;
@@ -19,6 +19,8 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod genesis;
+pub use genesis::*;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, process};
@@ -220,6 +222,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         public_balances: IndexMap<Address<N>, u64>,
         rng: &mut R,
     ) -> Result<Block<N>> {
+        // Prepare the genesis records, which transfer the minimum validator stake to the caller.
+        let caller = Address::try_from(private_key)?;
+        let records = vec![(caller, ledger_committee::MIN_VALIDATOR_STAKE); Block::<N>::NUM_GENESIS_TRANSACTIONS];
+        // Return the genesis block.
+        self.genesis_with_records(private_key, committee, public_balances, &records, rng)
+    }
+
+    /// Returns a new genesis block, where each genesis transaction transfers the given amount
+    /// from the public balance of the caller to a record for the given owner.
+    pub(crate) fn genesis_with_records<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        committee: Committee<N>,
+        public_balances: IndexMap<Address<N>, u64>,
+        records: &[(Address<N>, u64)],
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        // Ensure the number of genesis records is correct.
+        ensure!(
+            records.len() == Block::<N>::NUM_GENESIS_TRANSACTIONS,
+            "The genesis block must contain exactly {} records",
+            Block::<N>::NUM_GENESIS_TRANSACTIONS
+        );
+
         // Retrieve the total stake.
         let total_stake = committee.total_stake();
         // Compute the account supply.
@@ -229,22 +255,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Ensure the total supply matches.
         ensure!(total_supply == N::STARTING_SUPPLY, "Invalid total supply");
 
-        // Prepare the caller.
-        let caller = Address::try_from(private_key)?;
         // Prepare the locator.
         let locator = ("credits.aleo", "transfer_public_to_private");
-        // Prepare the amount for each call to the function.
-        let amount = ledger_committee::MIN_VALIDATOR_STAKE;
-        // Prepare the function inputs.
-        let inputs = [caller.to_string(), format!("{amount}_u64")];
 
         // Prepare the ratifications.
         let ratifications = vec![Ratify::Genesis(committee, public_balances)];
         // Prepare the solutions.
         let solutions = None; // The genesis block does not require solutions.
         // Prepare the transactions.
-        let transactions = (0..Block::<N>::NUM_GENESIS_TRANSACTIONS)
-            .map(|_| self.execute(private_key, locator, inputs.iter(), None, 0, None, rng))
+        let transactions = records
+            .iter()
+            .map(|(owner, amount)| {
+                // Prepare the function inputs.
+                let inputs = [owner.to_string(), format!("{amount}_u64")];
+                // Execute the function.
+                self.execute(private_key, locator, inputs.iter(), None, 0, None, rng)
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
         // Construct the finalize state.
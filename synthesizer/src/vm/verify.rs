@@ -14,6 +14,11 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// Ensures the given iterator has no duplicate elements, and that the ledger
 /// does not already contain a given item.
 macro_rules! ensure_is_unique {
@@ -31,7 +36,66 @@ macro_rules! ensure_is_unique {
     };
 }
 
+/// Ensures the given iterator has no duplicate elements across a batch of transactions.
+macro_rules! ensure_is_unique_in_batch {
+    ($name:expr, $transactions:expr, $method:ident) => {
+        // Ensure there are no duplicate items across the transactions.
+        if has_duplicates($transactions.iter().flat_map(|(transaction, _)| transaction.$method())) {
+            bail!("Found a duplicate {} in the batch of transactions", $name);
+        }
+    };
+}
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Verifies the given batch of transactions in the VM. On failure, returns an error.
+    ///
+    /// Each transaction is given with its rejected ID (if any), and is checked as in `VM::check_transaction`.
+    /// In addition, this method ensures no two transactions in the batch conflict with each other,
+    /// and verifies the transactions in parallel.
+    #[inline]
+    pub fn check_transactions<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("VM::check_transactions");
+
+        // Ensure the transaction IDs are unique.
+        if has_duplicates(transactions.iter().map(|(transaction, _)| transaction.id())) {
+            bail!("Found a duplicate transaction ID in the batch of transactions");
+        }
+        // Ensure the transition IDs are unique.
+        ensure_is_unique_in_batch!("transition ID", transactions, transition_ids);
+        // Ensure the input IDs are unique.
+        ensure_is_unique_in_batch!("input ID", transactions, input_ids);
+        // Ensure the serial numbers are unique.
+        ensure_is_unique_in_batch!("serial number", transactions, serial_numbers);
+        // Ensure the tags are unique.
+        ensure_is_unique_in_batch!("tag", transactions, tags);
+        // Ensure the output IDs are unique.
+        ensure_is_unique_in_batch!("output ID", transactions, output_ids);
+        // Ensure the commitments are unique.
+        ensure_is_unique_in_batch!("commitment", transactions, commitments);
+        // Ensure the nonces are unique.
+        ensure_is_unique_in_batch!("nonce", transactions, nonces);
+        // Ensure the transition public keys are unique.
+        ensure_is_unique_in_batch!("transition public key", transactions, transition_public_keys);
+        // Ensure the transition commitments are unique.
+        ensure_is_unique_in_batch!("transition commitment", transactions, transition_commitments);
+        lap!(timer, "Check for duplicate elements in the batch");
+
+        // Initialize an RNG for each transaction.
+        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        // Verify each transaction.
+        cfg_iter!(transactions).zip(rngs).try_for_each(|((transaction, rejected_id), mut rng)| {
+            self.check_transaction(transaction, *rejected_id, &mut rng)
+                .map_err(|e| anyhow!("Invalid transaction found in the batch of transactions: {e}"))
+        })?;
+
+        finish!(timer, "Verify the batch of transactions");
+        Ok(())
+    }
+
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction<R: CryptoRng + Rng>(
@@ -236,12 +300,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let verification = self.process.read().verify_execution(execution);
         lap!(timer, "Verify the execution");
 
-        // Ensure the global state root exists in the block store.
+        // Ensure the global state root exists in the block store, and is recent.
         let result = match verification {
-            // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
-                Ok(true) => Ok(()),
-                Ok(false) => bail!("Execution verification failed: global state root not found"),
+            // Ensure the global state root exists in the block store, and is recent.
+            Ok(()) => match self.block_store().check_state_root(&execution.global_state_root()) {
+                Ok(()) => Ok(()),
                 Err(error) => bail!("Execution verification failed: {error}"),
             },
             Err(error) => bail!("Execution verification failed: {error}"),
@@ -288,11 +351,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             ensure!(balance >= fee_amount, "Fee verification failed: insufficient balance");
         }
 
-        // Ensure the global state root exists in the block store.
+        // Ensure the global state root exists in the block store, and is recent.
         let result = match verification {
-            Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
-                Ok(true) => Ok(()),
-                Ok(false) => bail!("Fee verification failed: global state root not found"),
+            Ok(()) => match self.block_store().check_state_root(&fee.global_state_root()) {
+                Ok(()) => Ok(()),
                 Err(error) => bail!("Fee verification failed: {error}"),
             },
             Err(error) => bail!("Fee verification failed: {error}"),
@@ -441,6 +503,26 @@ mod tests {
         vm.check_transaction(&valid_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_transactions() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch the transactions.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+
+        // Ensure the batch of transactions verifies.
+        vm.check_transactions(&[(&deployment_transaction, None), (&execution_transaction, None)], rng).unwrap();
+
+        // Ensure a batch with a duplicate transaction fails to verify.
+        let result = vm.check_transactions(&[(&execution_transaction, None), (&execution_transaction, None)], rng);
+        assert!(result.is_err());
+
+        // Ensure an empty batch verifies.
+        vm.check_transactions(&[], rng).unwrap();
+    }
+
     #[test]
     fn test_verify_deploy_and_execute() {
         // Initialize the RNG.
@@ -567,4 +649,18 @@ function compute:
         // Ensure that the program can't be deployed.
         assert!(vm.deploy_raw(&program, rng).is_err());
     }
+
+    #[test_strategy::proptest(proptest::prelude::ProptestConfig { cases: 6, ..Default::default() })]
+    fn test_any_transaction_verifies(
+        #[strategy(ledger_test_helpers::any_transaction())] transaction: Transaction<CurrentNetwork>,
+    ) {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block that the sampled transactions are built on.
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.add_next_block(&ledger_test_helpers::sample_genesis_block(rng)).unwrap();
+
+        // Ensure the transaction verifies.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
 }
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    error,
+    io::{Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    task,
+};
+use tokio_util::io::SyncIoBridge;
+
+/// Reads `T` from the given asynchronous `reader` as little-endian bytes, and returns it with the reader.
+///
+/// The object is decoded on the blocking thread pool, directly from the stream,
+/// so the async runtime is not blocked and the encoded object is never buffered in full.
+/// This method must be called from within a Tokio runtime.
+pub async fn read_le_async<T, R>(reader: R) -> IoResult<(T, R)>
+where
+    T: FromBytes + Send + 'static,
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    task::spawn_blocking(move || {
+        let mut bridge = SyncIoBridge::new(reader);
+        let object = T::read_le(&mut bridge)?;
+        Ok((object, bridge.into_inner()))
+    })
+    .await
+    .map_err(|_| error("Failed to join the blocking read task"))?
+}
+
+/// Writes the given `object` into the asynchronous `writer` as little-endian bytes, and returns the writer.
+///
+/// The object is encoded on the blocking thread pool, directly into the stream,
+/// so the async runtime is not blocked and the encoded object is never buffered in full.
+/// The writer is flushed, but not shut down. This method must be called from within a Tokio runtime.
+pub async fn write_le_async<T, W>(object: T, writer: W) -> IoResult<W>
+where
+    T: ToBytes + Send + 'static,
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    task::spawn_blocking(move || {
+        let mut bridge = SyncIoBridge::new(writer);
+        object.write_le(&mut bridge)?;
+        bridge.flush()?;
+        Ok(bridge.into_inner())
+    })
+    .await
+    .map_err(|_| error("Failed to join the blocking write task"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestRng;
+
+    use rand::Rng;
+
+    #[tokio::test]
+    async fn test_async_roundtrip() {
+        let mut rng = TestRng::default();
+        let expected: Vec<[u64; 4]> = (0..1000).map(|_| rng.gen()).collect();
+
+        // Stream the objects through an in-memory pipe, smaller than the encoded objects.
+        let (reader, writer) = tokio::io::duplex(64);
+        let objects = expected.clone();
+        let write_task = tokio::spawn(async move {
+            let mut writer = writer;
+            for object in objects {
+                writer = write_le_async(object, writer).await.unwrap();
+            }
+        });
+        let mut reader = reader;
+        for object in &expected {
+            let (candidate, next_reader) = read_le_async::<[u64; 4], _>(reader).await.unwrap();
+            assert_eq!(*object, candidate);
+            reader = next_reader;
+        }
+        write_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_async_read_fails_on_truncated_input() {
+        let bytes = vec![0u8; 7];
+        assert!(read_le_async::<u64, _>(std::io::Cursor::new(bytes)).await.is_err());
+    }
+}
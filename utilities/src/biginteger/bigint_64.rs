@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    biginteger::BigInteger,
+    bititerator::{BitIteratorBE, BitIteratorLE},
+    io::{Read, Result as IoResult, Write},
+    FromBits,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use anyhow::Result;
+use core::fmt::{Debug, Display};
+use num_bigint::BigUint;
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use zeroize::Zeroize;
+
+#[derive(Copy, Clone, PartialEq, Eq, Default, Hash, Zeroize)]
+pub struct BigInteger64(pub [u64; 1]);
+
+impl BigInteger64 {
+    pub const fn new(value: [u64; 1]) -> Self {
+        BigInteger64(value)
+    }
+}
+
+impl crate::biginteger::BigInteger for BigInteger64 {
+    const NUM_LIMBS: usize = 1;
+
+    #[inline]
+    fn add_nocarry(&mut self, other: &Self) -> bool {
+        let (sum, carry) = self.0[0].overflowing_add(other.0[0]);
+        self.0[0] = sum;
+        carry
+    }
+
+    #[inline]
+    fn sub_noborrow(&mut self, other: &Self) -> bool {
+        let (difference, borrow) = self.0[0].overflowing_sub(other.0[0]);
+        self.0[0] = difference;
+        borrow
+    }
+
+    #[inline]
+    fn mul2(&mut self) {
+        self.0[0] <<= 1;
+    }
+
+    #[inline]
+    fn muln(&mut self, n: u32) {
+        self.0[0] = self.0[0].checked_shl(n).unwrap_or(0);
+    }
+
+    #[inline]
+    fn div2(&mut self) {
+        self.0[0] >>= 1;
+    }
+
+    #[inline]
+    fn divn(&mut self, n: u32) {
+        self.0[0] = self.0[0].checked_shr(n).unwrap_or(0);
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.0[0] & 1 == 1
+    }
+
+    #[inline]
+    fn is_even(&self) -> bool {
+        !self.is_odd()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0[0] == 0
+    }
+
+    #[inline]
+    fn num_bits(&self) -> u32 {
+        64 - self.0[0].leading_zeros()
+    }
+
+    #[inline]
+    fn get_bit(&self, i: usize) -> bool {
+        i < 64 && (self.0[0] & (1 << i)) != 0
+    }
+
+    #[inline]
+    fn to_biguint(&self) -> num_bigint::BigUint {
+        BigUint::from_bytes_le(&self.to_bytes_le().unwrap())
+    }
+
+    #[inline]
+    fn find_wnaf(&self) -> Vec<i64> {
+        let mut res = crate::vec::Vec::new();
+        // Use a wider integer, as adding to `e` may carry past 64 bits.
+        let mut e = u128::from(self.0[0]);
+        while e != 0 {
+            let z: i64;
+            if e & 1 == 1 {
+                z = 2 - (e % 4) as i64;
+                if z >= 0 {
+                    e -= z as u128;
+                } else {
+                    e += (-z) as u128;
+                }
+            } else {
+                z = 0;
+            }
+            res.push(z);
+            e >>= 1;
+        }
+        res
+    }
+}
+
+impl ToBits for BigInteger64 {
+    #[doc = " Returns `self` as a boolean array in little-endian order, with trailing zeros."]
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        vec.extend(BitIteratorLE::new(self));
+    }
+
+    #[doc = " Returns `self` as a boolean array in big-endian order, with leading zeros."]
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        vec.extend(BitIteratorBE::new(self));
+    }
+}
+
+impl FromBits for BigInteger64 {
+    #[doc = " Returns a `BigInteger` by parsing a slice of bits in little-endian format"]
+    #[doc = " and transforms it into a slice of little-endian u64 elements."]
+    fn from_bits_le(bits: &[bool]) -> Result<Self> {
+        let mut res = Self::default();
+        for (i, bits64) in bits.chunks(64).enumerate() {
+            let mut acc: u64 = 0;
+            for bit in bits64.iter().rev() {
+                acc <<= 1;
+                acc += *bit as u64;
+            }
+            res.0[i] = acc;
+        }
+        Ok(res)
+    }
+
+    #[doc = " Returns a `BigInteger` by parsing a slice of bits in big-endian format"]
+    #[doc = " and transforms it into a slice of little-endian u64 elements."]
+    fn from_bits_be(bits: &[bool]) -> Result<Self> {
+        let mut res = Self::default();
+        for (i, bits64) in bits.rchunks(64).enumerate() {
+            let mut acc: u64 = 0;
+            for bit in bits64.iter() {
+                acc <<= 1;
+                acc += *bit as u64;
+            }
+            res.0[i] = acc;
+        }
+        Ok(res)
+    }
+}
+
+impl ToBytes for BigInteger64 {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0[0].write_le(writer)
+    }
+}
+
+impl FromBytes for BigInteger64 {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        <[u64; 1]>::read_le(reader).map(Self::new)
+    }
+}
+
+impl Debug for BigInteger64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016X}", self.0[0])
+    }
+}
+
+impl Display for BigInteger64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_biguint())
+    }
+}
+
+impl Ord for BigInteger64 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0[0].cmp(&other.0[0])
+    }
+}
+
+impl PartialOrd for BigInteger64 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Distribution<BigInteger64> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigInteger64 {
+        BigInteger64([rng.gen()])
+    }
+}
+
+impl AsMut<[u64]> for BigInteger64 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u64] {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u64]> for BigInteger64 {
+    #[inline]
+    fn as_ref(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+impl From<u64> for BigInteger64 {
+    #[inline]
+    fn from(val: u64) -> BigInteger64 {
+        BigInteger64([val])
+    }
+}
@@ -17,6 +17,9 @@ use crate::{rand::Uniform, FromBits, FromBytes, ToBits, ToBytes};
 use num_bigint::BigUint;
 use std::fmt::{Debug, Display};
 
+mod bigint_64;
+pub use bigint_64::*;
+
 mod bigint_256;
 pub use bigint_256::*;
 
@@ -115,6 +115,11 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_to_string_test::<B>(&mut rng);
 }
 
+#[test]
+fn test_biginteger64() {
+    test_biginteger(BigInteger64::new([0u64; 1]));
+}
+
 #[test]
 fn test_biginteger256() {
     test_biginteger(BigInteger256::new([0u64; 4]));
@@ -478,6 +478,18 @@ pub fn bytes_from_bits_le(bits: &[bool]) -> Vec<u8> {
     bytes
 }
 
+/// The maximum number of elements to preallocate when reading a length-prefixed sequence.
+pub const MAX_PREALLOCATED_ELEMENTS: usize = 1 << 16;
+
+/// Returns the capacity to preallocate for a sequence of `len` elements read from an untrusted buffer.
+///
+/// The capacity is capped at `MAX_PREALLOCATED_ELEMENTS`, so that a forged length prefix cannot force
+/// a large allocation up front. Longer sequences grow incrementally, as their elements are read.
+#[inline]
+pub fn bounded_capacity(len: u64) -> usize {
+    usize::try_from(len).unwrap_or(usize::MAX).min(MAX_PREALLOCATED_ELEMENTS)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -41,6 +41,11 @@ pub use std::{boxed::Box, vec::Vec};
 #[macro_use]
 extern crate thiserror;
 
+#[cfg(feature = "async")]
+pub mod async_io;
+#[cfg(feature = "async")]
+pub use async_io::*;
+
 pub mod biginteger;
 pub use biginteger::*;
 
@@ -71,6 +76,9 @@ pub use self::rand::*;
 pub mod serialize;
 pub use serialize::*;
 
+pub mod varint;
+pub use varint::*;
+
 #[cfg(not(feature = "std"))]
 pub mod io;
 
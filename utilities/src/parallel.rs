@@ -53,11 +53,78 @@ impl<'a, T> Default for ExecutionPool<'a, T> {
     }
 }
 
+/// Set once the global thread pool is configured with `configure`.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+static CONFIGURED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Configures the global thread pool used by the parallel routines (e.g. MSM, FFT, and Merkle tree hashing)
+/// to use `num_threads` threads, instead of one thread per available core.
+///
+/// If `first_core` is given, thread `i` of the pool is pinned to core `first_core + i`, so that
+/// multiple provers on one machine can be given disjoint sets of cores (e.g. prover `k` calls
+/// `configure(8, Some(8 * k))`). Pinning is skipped on platforms that do not support it,
+/// and without the `affinity` feature.
+///
+/// This must be called once, before any parallel routine runs, as the global thread pool
+/// cannot be reconfigured once it is initialized.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+pub fn configure(num_threads: usize, first_core: Option<usize>) -> anyhow::Result<()> {
+    anyhow::ensure!(num_threads > 0, "The number of threads must be greater than 0");
+
+    let mut builder = rayon::ThreadPoolBuilder::new().num_threads(num_threads);
+    if let Some(first_core) = first_core {
+        builder = pin_threads(builder, num_threads, first_core)?;
+    }
+    builder.build_global().map_err(|error| anyhow::anyhow!("Failed to configure the thread pool: {error}"))?;
+
+    CONFIGURED.store(true, std::sync::atomic::Ordering::SeqCst);
+    Ok(())
+}
+
+/// Pins thread `i` of the given thread pool to core `first_core + i`, if the platform supports it.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+#[allow(unused_variables)]
+fn pin_threads(
+    builder: rayon::ThreadPoolBuilder,
+    num_threads: usize,
+    first_core: usize,
+) -> anyhow::Result<rayon::ThreadPoolBuilder> {
+    // Thread pinning requires `core_affinity`, which is unavailable on wasm targets.
+    #[cfg(all(feature = "affinity", not(target_family = "wasm")))]
+    {
+        // Ensure the requested cores exist.
+        let core_ids = core_affinity::get_core_ids().unwrap_or_default();
+        if !core_ids.is_empty() {
+            anyhow::ensure!(
+                first_core + num_threads <= core_ids.len(),
+                "Cannot pin {num_threads} threads from core {first_core}: only {} cores are available",
+                core_ids.len()
+            );
+            return Ok(builder.start_handler(move |index| {
+                core_affinity::set_for_current(core_ids[first_core + index]);
+            }));
+        }
+    }
+    Ok(builder)
+}
+
+/// Returns `true` if the global thread pool was configured with `configure`.
+#[cfg(not(any(feature = "serial", feature = "wasm")))]
+fn is_configured() -> bool {
+    CONFIGURED.load(std::sync::atomic::Ordering::SeqCst)
+}
+
 #[cfg(not(feature = "serial"))]
 pub fn max_available_threads() -> usize {
     use aleo_std::Cpu;
     let rayon_threads = rayon::current_num_threads();
 
+    // If the thread pool was configured, use all of its threads.
+    #[cfg(not(feature = "wasm"))]
+    if is_configured() {
+        return rayon_threads;
+    }
+
     match aleo_std::get_cpu() {
         Cpu::Intel => num_cpus::get_physical().min(rayon_threads),
         Cpu::AMD | Cpu::Unknown => rayon_threads,
@@ -67,9 +134,17 @@ pub fn max_available_threads() -> usize {
 #[inline(always)]
 #[cfg(not(any(feature = "serial", feature = "wasm")))]
 pub fn execute_with_max_available_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send) -> T {
+    // If the thread pool was configured, run on it, to respect its size and pinning.
+    if is_configured() {
+        return f();
+    }
     execute_with_threads(f, max_available_threads())
 }
 
+/// Runs `f` on the calling thread.
+///
+/// With the `single-thread` (or `serial`) feature, no thread pool is ever built, and every `cfg_*`
+/// macro falls back to a sequential iterator, so chunk sizes never depend on the number of cores.
 #[inline(always)]
 #[cfg(any(feature = "serial", feature = "wasm"))]
 pub fn execute_with_max_available_threads<T>(f: impl FnOnce() -> T + Send) -> T {
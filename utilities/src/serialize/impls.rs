@@ -200,7 +200,7 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
     #[inline]
     fn serialized_size(&self, compress: Compress) -> usize {
-        8 + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
+        self.is_some().serialized_size(compress) + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
     }
 }
 
@@ -424,7 +424,7 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
     ) -> Result<Self, SerializationError> {
         let len = u64::deserialize_with_mode(&mut reader, compress, validate)?;
         let mut values = Vec::new();
-        let _ = values.try_reserve(len as usize);
+        let _ = values.try_reserve(crate::bounded_capacity(len));
         for _ in 0..len {
             values.push(T::deserialize_with_mode(&mut reader, compress, Validate::No)?);
         }
@@ -693,6 +693,16 @@ mod test {
         test_serialize(Vec::<u64>::new());
     }
 
+    #[test]
+    fn test_vec_with_forged_len() {
+        // Serialize a length prefix that is far larger than the number of elements that follow.
+        let mut serialized = vec![];
+        u64::MAX.serialize_compressed(&mut serialized).unwrap();
+        1u64.serialize_compressed(&mut serialized).unwrap();
+        // Ensure deserialization fails on the missing elements, without preallocating the forged length.
+        assert!(Vec::<u64>::deserialize_compressed(&serialized[..]).is_err());
+    }
+
     #[test]
     fn test_vec_without_len() {
         test_serialize_without_len(vec![1u64, 2, 3, 4, 5]);
@@ -713,6 +723,13 @@ mod test {
     fn test_option() {
         test_serialize(Some(3u32));
         test_serialize(None::<u32>);
+
+        // Ensure the serialized size matches the number of bytes written.
+        for data in [Some(3u32), None] {
+            let mut serialized = vec![];
+            data.serialize_compressed(&mut serialized).unwrap();
+            assert_eq!(serialized.len(), data.serialized_size(Compress::Yes));
+        }
     }
 
     #[test]
@@ -62,21 +62,39 @@ pub trait Flags: Default + Clone + Copy + Sized {
     }
 }
 
+/// Selects the encoding used by `CanonicalSerialize` and `CanonicalDeserialize`.
+///
+/// The compressed encoding is smaller, e.g. an elliptic curve point is stored as its `x` coordinate
+/// and a sign flag, while the uncompressed encoding stores every coordinate and is faster to decode,
+/// as it avoids recovering the omitted coordinates (e.g. a square root per point).
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum Compress {
     Yes,
     No,
 }
 
+/// Selects whether `CanonicalDeserialize` runs `Valid::check` on the decoded value.
+///
+/// `Validate::No` must only be used for trusted data, such as the parameters
+/// shipped with (and checksummed by) `snarkvm-parameters`, or data this process wrote itself.
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum Validate {
     Yes,
     No,
 }
 
+/// The validity checks performed on a value after it is decoded with `Validate::Yes`.
+///
+/// Each type documents what its `check` covers. For example, elliptic curve points
+/// are checked to be on the curve and in the prime-order subgroup, while field elements
+/// are range-checked while decoding and have nothing further to check.
+/// Composite types check each of their components.
 pub trait Valid: Sized + Sync {
+    /// Returns an error if `self` is not a valid instance of its type.
     fn check(&self) -> Result<(), SerializationError>;
 
+    /// Checks every item in the given batch, in parallel unless the `serial` feature is enabled.
+    /// Types may override this with a cheaper batched check.
     fn batch_check<'a>(batch: impl Iterator<Item = &'a Self> + Send) -> Result<(), SerializationError>
     where
         Self: 'a,
@@ -113,22 +131,28 @@ pub trait Valid: Sized + Sync {
 /// }
 /// ```
 pub trait CanonicalSerialize {
+    /// Serializes `self` into `writer`, using the given encoding.
     fn serialize_with_mode<W: Write>(&self, writer: W, compress: Compress) -> Result<(), SerializationError>;
 
+    /// Returns the number of bytes written by `serialize_with_mode` for the given encoding.
     fn serialized_size(&self, compress: Compress) -> usize;
 
+    /// Serializes `self` into `writer`, using the compressed encoding.
     fn serialize_compressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
         self.serialize_with_mode(writer, Compress::Yes)
     }
 
+    /// Returns the number of bytes written by `serialize_compressed`.
     fn compressed_size(&self) -> usize {
         self.serialized_size(Compress::Yes)
     }
 
+    /// Serializes `self` into `writer`, using the uncompressed encoding.
     fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
         self.serialize_with_mode(writer, Compress::No)
     }
 
+    /// Returns the number of bytes written by `serialize_uncompressed`.
     fn uncompressed_size(&self) -> usize {
         self.serialized_size(Compress::No)
     }
@@ -151,24 +175,32 @@ pub trait CanonicalSerialize {
 /// }
 /// ```
 pub trait CanonicalDeserialize: Valid {
+    /// Deserializes `Self` from `reader`, using the given encoding,
+    /// and runs `Valid::check` on the result if `validate` is `Validate::Yes`.
     fn deserialize_with_mode<R: Read>(
         reader: R,
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, SerializationError>;
 
+    /// Deserializes and validates `Self` from its compressed encoding.
     fn deserialize_compressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
         Self::deserialize_with_mode(reader, Compress::Yes, Validate::Yes)
     }
 
+    /// Deserializes `Self` from its compressed encoding, without validation.
+    /// This must only be used for trusted data (see `Validate`).
     fn deserialize_compressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
         Self::deserialize_with_mode(reader, Compress::Yes, Validate::No)
     }
 
+    /// Deserializes and validates `Self` from its uncompressed encoding.
     fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
         Self::deserialize_with_mode(reader, Compress::No, Validate::Yes)
     }
 
+    /// Deserializes `Self` from its uncompressed encoding, without validation.
+    /// This is the fastest way to load trusted data, such as the proving keys in `snarkvm-parameters`.
     fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
         Self::deserialize_with_mode(reader, Compress::No, Validate::No)
     }
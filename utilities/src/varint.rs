@@ -0,0 +1,170 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+    Vec,
+};
+
+/// The maximum number of bytes in the LEB128 encoding of a `u64`.
+pub const MAX_VARINT_SIZE: usize = 10;
+
+/// Writes the given `value` into `writer` as an unsigned LEB128 variable-length integer.
+///
+/// Each byte holds 7 bits of the value, starting from the least-significant bits,
+/// and the most-significant bit of each byte is set if more bytes follow.
+pub fn write_varint<W: Write>(value: u64, mut writer: W) -> IoResult<()> {
+    let mut value = value;
+    loop {
+        // Take the lowest 7 bits.
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        // Set the continuation bit, if more bytes follow.
+        match value {
+            0 => return byte.write_le(&mut writer),
+            _ => (byte | 0x80).write_le(&mut writer)?,
+        }
+    }
+}
+
+/// Reads an unsigned LEB128 variable-length integer from `reader`.
+///
+/// This method rejects encodings that are not the shortest encoding of their value,
+/// so that each value has exactly one valid encoding.
+pub fn read_varint<R: Read>(mut reader: R) -> IoResult<u64> {
+    let mut value = 0u64;
+    for index in 0..MAX_VARINT_SIZE {
+        let byte = u8::read_le(&mut reader)?;
+        let bits = (byte & 0x7f) as u64;
+        // Ensure the bits fit in a `u64`.
+        let shift = 7 * index as u32;
+        if (bits << shift) >> shift != bits {
+            return Err(error("Variable-length integer exceeds u64"));
+        }
+        value |= bits << shift;
+        // If there are no more bytes, ensure the encoding is canonical.
+        if byte & 0x80 == 0 {
+            if byte == 0 && index > 0 {
+                return Err(error("Variable-length integer is not canonically encoded"));
+            }
+            return Ok(value);
+        }
+    }
+    Err(error("Variable-length integer exceeds u64"))
+}
+
+/// Returns the number of bytes in the LEB128 encoding of the given `value`.
+pub const fn varint_size(value: u64) -> usize {
+    let bits = (u64::BITS - value.leading_zeros()) as usize;
+    match bits {
+        0 => 1,
+        _ => (bits + 6) / 7,
+    }
+}
+
+/// Writes the given `items` into `writer`, prefixed by their number as a variable-length integer.
+pub fn write_length_prefixed<T: ToBytes, W: Write>(items: &[T], mut writer: W) -> IoResult<()> {
+    write_varint(items.len() as u64, &mut writer)?;
+    for item in items {
+        item.write_le(&mut writer)?;
+    }
+    Ok(())
+}
+
+/// Reads a list of items from `reader`, prefixed by their number as a variable-length integer.
+///
+/// This method fails if the number of items exceeds `max_items`, before reading any item.
+pub fn read_length_prefixed<T: FromBytes, R: Read>(mut reader: R, max_items: usize) -> IoResult<Vec<T>> {
+    // Read the number of items.
+    let num_items = read_varint(&mut reader)?;
+    if num_items > max_items as u64 {
+        return Err(error("Length prefix exceeds the maximum number of items"));
+    }
+    // Read the items.
+    (0..num_items).map(|_| T::read_le(&mut reader)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestRng;
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 10_000;
+
+    fn encode(value: u64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_varint(value, &mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_varint_encoding() {
+        assert_eq!(encode(0), [0x00]);
+        assert_eq!(encode(1), [0x01]);
+        assert_eq!(encode(127), [0x7f]);
+        assert_eq!(encode(128), [0x80, 0x01]);
+        assert_eq!(encode(300), [0xac, 0x02]);
+        assert_eq!(encode(16_383), [0xff, 0x7f]);
+        assert_eq!(encode(16_384), [0x80, 0x80, 0x01]);
+        assert_eq!(encode(u64::MAX), [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+    }
+
+    #[test]
+    fn test_varint_roundtrip() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample values of every size.
+            let value = rng.gen::<u64>() >> rng.gen_range(0..64);
+            let bytes = encode(value);
+            assert_eq!(bytes.len(), varint_size(value));
+            assert_eq!(read_varint(&bytes[..]).unwrap(), value);
+        }
+        for value in [0, 127, 128, u32::MAX as u64, u64::MAX] {
+            let bytes = encode(value);
+            assert_eq!(bytes.len(), varint_size(value));
+            assert_eq!(read_varint(&bytes[..]).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_varint_rejects_invalid_encodings() {
+        // Non-canonical encodings.
+        assert!(read_varint(&[0x80, 0x00][..]).is_err());
+        assert!(read_varint(&[0x81, 0x80, 0x00][..]).is_err());
+        // Values that exceed `u64`.
+        assert!(read_varint(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02][..]).is_err());
+        assert!(read_varint(&[0xff; MAX_VARINT_SIZE + 1][..]).is_err());
+        // Truncated encodings.
+        assert!(read_varint(&[][..]).is_err());
+        assert!(read_varint(&[0x80][..]).is_err());
+    }
+
+    #[test]
+    fn test_length_prefixed() {
+        let items = vec![1u32, 2, 3, 300];
+        let mut bytes = Vec::new();
+        write_length_prefixed(&items, &mut bytes).unwrap();
+        // The prefix takes a single byte, instead of the 8 bytes of a `u64` prefix.
+        assert_eq!(bytes.len(), 1 + 4 * 4);
+        assert_eq!(read_length_prefixed::<u32, _>(&bytes[..], 4).unwrap(), items);
+        // Ensure the maximum number of items is enforced.
+        assert!(read_length_prefixed::<u32, _>(&bytes[..], 3).is_err());
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
+use crate::cli::commands::{Build, Clean, Execute, New, Run, Setup, Update};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -48,6 +48,8 @@ pub enum Command {
     New(New),
     #[clap(name = "run")]
     Run(Run),
+    #[clap(name = "setup")]
+    Setup(Setup),
     #[clap(name = "update")]
     Update(Update),
 }
@@ -61,6 +63,7 @@ impl Command {
             Self::Execute(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
+            Self::Setup(command) => command.parse(),
             Self::Update(command) => command.parse(),
         }
     }
@@ -27,6 +27,9 @@ pub use new::*;
 pub mod run;
 pub use run::*;
 
+pub mod setup;
+pub use setup::*;
+
 pub mod update;
 pub use update::*;
 
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{prelude::Network, setup::ParameterGenerator};
+
+use anyhow::bail;
+use rand::{rngs::OsRng, CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use std::path::PathBuf;
+
+/// Regenerates the parameters of a private network, one stage at a time.
+#[derive(Debug, Parser)]
+pub struct Setup {
+    /// The stage to run [options: srs, keys, genesis]
+    stage: String,
+    /// The directory in which the parameter files are written
+    output_dir: PathBuf,
+    /// The network ID
+    #[clap(default_value = "3", short, long)]
+    network: u16,
+    /// The log2 of the largest number of contiguous powers of beta G to generate
+    #[clap(long)]
+    max_degree_log2: Option<usize>,
+    /// The seed of the RNG, for a reproducible setup of a test network (the secrets are derived from the seed)
+    #[clap(long)]
+    seed: Option<u64>,
+}
+
+impl Setup {
+    /// Runs the given stage of the parameter generation, for the given network.
+    pub fn parse(self) -> Result<String> {
+        match self.network {
+            CurrentNetwork::ID => self.run::<CurrentNetwork, Aleo>(),
+            id => bail!("Unsupported network ID: {id}"),
+        }
+    }
+
+    /// Runs the given stage of the parameter generation, with secrets sampled from the seeded RNG, if a seed is given,
+    /// and from the OS otherwise.
+    fn run<N: Network, A: crate::circuit::Aleo<Network = N>>(self) -> Result<String> {
+        match self.seed {
+            Some(seed) => self.run_with_rng::<N, A, _>(&mut ChaChaRng::seed_from_u64(seed)),
+            None => self.run_with_rng::<N, A, _>(&mut OsRng),
+        }
+    }
+
+    /// Runs the given stage of the parameter generation, with secrets sampled from the given RNG.
+    fn run_with_rng<N: Network, A: crate::circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        self,
+        rng: &mut R,
+    ) -> Result<String> {
+        let mut generator = ParameterGenerator::<N, A>::new(&self.output_dir);
+        if let Some(max_degree_log2) = self.max_degree_log2 {
+            generator = generator.with_max_degree_log2(max_degree_log2)?;
+        }
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", self.output_dir.display());
+
+        let remote_files = match self.stage.as_str() {
+            "srs" => generator.universal_srs(rng)?,
+            "keys" => generator.circuit_keys(rng)?,
+            "genesis" => {
+                let (private_key, block) = generator.genesis_block(rng)?;
+                return Ok(format!(
+                    "✅ Generated the genesis block '{}' {}\n\nGenesis private key: {private_key}",
+                    block.hash().to_string().bold(),
+                    path_string.dimmed()
+                ));
+            }
+            stage => bail!("Invalid stage '{stage}' [options: srs, keys, genesis]"),
+        };
+
+        let mut output = format!("✅ Generated the '{}' parameters {}", self.stage.bold(), path_string.dimmed());
+        if !remote_files.is_empty() {
+            output += "\n\nUpload the following remote files to the parameter cache:\n";
+            for filename in remote_files {
+                output += &format!("\n  {filename}");
+            }
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_setup() {
+        let arg_vec = vec!["snarkvm", "setup", "genesis", "params", "--seed", "42"];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Setup(setup) = cli.command {
+            assert_eq!(setup.stage, "genesis");
+            assert_eq!(setup.output_dir, PathBuf::from("params"));
+            assert_eq!(setup.seed, Some(42));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
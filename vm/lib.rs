@@ -24,6 +24,8 @@ extern crate thiserror;
 pub mod cli;
 pub mod file;
 pub mod package;
+#[cfg(feature = "setup")]
+pub mod setup;
 
 #[cfg(feature = "algorithms")]
 pub use snarkvm_algorithms as algorithms;
@@ -45,6 +47,8 @@ pub use snarkvm_parameters as parameters;
 pub use snarkvm_synthesizer as synthesizer;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
+#[cfg(feature = "utilities")]
+pub use snarkvm_utilities::parallel;
 #[cfg(feature = "wasm")]
 pub use snarkvm_wasm as wasm;
 
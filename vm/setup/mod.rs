@@ -0,0 +1,375 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    algorithms::{crypto_hash::sha256::sha256, msm::FixedBase},
+    circuit::Aleo,
+    console::{
+        account::PrivateKey,
+        network::Network,
+        prelude::*,
+        program::{Plaintext, Record},
+        types::Field,
+    },
+    curves::PairingEngine,
+    ledger::{
+        block::Block,
+        store::{helpers::memory::ConsensusMemory, ConsensusStore},
+    },
+    parameters::version::PARAMETERS_VERSION,
+    synthesizer::{
+        process::InclusionAssignment,
+        snark::{ProvingKey, UniversalSRS, VerifyingKey},
+        Process,
+        Program,
+        VM,
+    },
+    utilities::CanonicalSerialize,
+};
+
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    marker::PhantomData,
+    path::PathBuf,
+};
+
+/// The log2 of the number of powers of beta G in the universal SRS.
+const MAX_DEGREE_LOG2: usize = 28;
+/// The log2 of the number of powers of beta G in the smallest file of the universal SRS.
+const MIN_DEGREE_LOG2: usize = 15;
+/// The largest log2 of the number of powers of beta G stored in a local file of the universal SRS.
+const MAX_LOCAL_DEGREE_LOG2: usize = 16;
+/// The number of powers of beta G in the universal SRS.
+const MAX_NUM_POWERS: usize = 1 << MAX_DEGREE_LOG2;
+/// The hiding bound supported by the powers of beta times gamma G.
+const HIDING_BOUND: usize = 1;
+
+/// Regenerates the universal SRS, the circuit keys, and the genesis block of a private network,
+/// and writes them in the layout `snarkvm-parameters` expects.
+///
+/// Each stage loads the parameters embedded in `snarkvm-parameters`, so the outputs of a stage must be moved into
+/// the resources of the network (and the remote files into the parameter cache) before running the next stage.
+/// The secrets of each stage (e.g. the SRS trapdoor) are sampled from the given RNG and discarded,
+/// so the RNG must be cryptographically secure, e.g. `OsRng`. A seeded RNG (e.g. `ChaChaRng`) makes the
+/// setup reproducible, which is only suitable for test networks, as anyone with the seed recovers the secrets.
+pub struct ParameterGenerator<N: Network, A: Aleo<Network = N>> {
+    /// The directory in which the parameter files are written.
+    output_dir: PathBuf,
+    /// The log2 of the largest number of contiguous powers of beta G to generate.
+    max_degree_log2: usize,
+    _phantom: PhantomData<(N, A)>,
+}
+
+impl<N: Network, A: Aleo<Network = N>> ParameterGenerator<N, A> {
+    /// Initializes a new parameter generator, which writes to the given directory.
+    /// By default, only the powers of beta G stored in local files are generated.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self { output_dir: output_dir.into(), max_degree_log2: MAX_LOCAL_DEGREE_LOG2, _phantom: PhantomData }
+    }
+
+    /// Sets the log2 of the largest number of contiguous powers of beta G to generate.
+    pub fn with_max_degree_log2(mut self, max_degree_log2: usize) -> Result<Self> {
+        ensure!(
+            (MAX_LOCAL_DEGREE_LOG2..=MAX_DEGREE_LOG2).contains(&max_degree_log2),
+            "The maximum degree must be between 2^{MAX_LOCAL_DEGREE_LOG2} and 2^{MAX_DEGREE_LOG2}"
+        );
+        self.max_degree_log2 = max_degree_log2;
+        Ok(self)
+    }
+
+    /// Generates the universal SRS, and returns the names of the remote files.
+    pub fn universal_srs<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Vec<String>> {
+        type G1<N> = <<N as Environment>::PairingCurve as PairingEngine>::G1Projective;
+        type G2<N> = <<N as Environment>::PairingCurve as PairingEngine>::G2Projective;
+
+        // Sample the trapdoor.
+        let beta = N::Field::rand(rng);
+        let gamma = N::Field::rand(rng);
+        let beta_inverse = beta.inverse().ok_or_else(|| anyhow!("Sampled a zero trapdoor"))?;
+
+        // Generate the powers of beta G, starting from the 0-th power.
+        let mut remote_files = vec![];
+        let powers = fixed_base_mul::<G1<N>>(&powers_of::<N>(beta, 0, 1 << MIN_DEGREE_LOG2));
+        remote_files.extend(self.write_usrs(&format!("powers-of-beta-{MIN_DEGREE_LOG2}"), &powers, true)?);
+        for log2 in (MIN_DEGREE_LOG2 + 1)..=self.max_degree_log2 {
+            let powers = fixed_base_mul::<G1<N>>(&powers_of::<N>(beta, 1 << (log2 - 1), 1 << log2));
+            let is_local = log2 <= MAX_LOCAL_DEGREE_LOG2;
+            remote_files.extend(self.write_usrs(&format!("powers-of-beta-{log2}"), &powers, is_local)?);
+        }
+
+        // Generate the shifted powers of beta G, ending at the maximum power.
+        let start = MAX_NUM_POWERS - (1 << MIN_DEGREE_LOG2);
+        let powers = fixed_base_mul::<G1<N>>(&powers_of::<N>(beta, start, MAX_NUM_POWERS));
+        remote_files.extend(self.write_usrs(&format!("shifted-powers-of-beta-{MIN_DEGREE_LOG2}"), &powers, true)?);
+        for log2 in (MIN_DEGREE_LOG2 + 1)..=self.max_degree_log2.min(MAX_DEGREE_LOG2 - 1) {
+            let (start, end) = (MAX_NUM_POWERS - (1 << log2), MAX_NUM_POWERS - (1 << (log2 - 1)));
+            let powers = fixed_base_mul::<G1<N>>(&powers_of::<N>(beta, start, end));
+            remote_files.extend(self.write_usrs(&format!("shifted-powers-of-beta-{log2}"), &powers, false)?);
+        }
+
+        // Determine the degree bounds, which are the radix-2 domain sizes minus 2.
+        let max_degree = MAX_NUM_POWERS - 1;
+        let degree_bounds = (2..=MAX_DEGREE_LOG2).map(|log2| (1 << log2) - 2).collect::<Vec<usize>>();
+
+        // Generate the powers of beta times gamma G, for hiding the unshifted and shifted commitments.
+        let mut gamma_indices = (0..=HIDING_BOUND + 1).collect::<Vec<_>>();
+        for degree_bound in &degree_bounds {
+            gamma_indices.extend((0..=HIDING_BOUND + 1).map(|i| max_degree - degree_bound + i));
+        }
+        let gamma_scalars = gamma_indices.iter().map(|i| beta.pow([*i as u64]) * gamma).collect::<Vec<_>>();
+        let powers_of_beta_gamma_g =
+            gamma_indices.into_iter().zip(fixed_base_mul::<G1<N>>(&gamma_scalars)).collect::<BTreeMap<_, _>>();
+        self.write_usrs("powers-of-beta-gamma", &powers_of_beta_gamma_g, true)?;
+
+        // Generate the negative powers of beta H, for checking the degree bounds.
+        let neg_scalars = degree_bounds.iter().map(|d| beta_inverse.pow([(max_degree - d) as u64])).collect::<Vec<_>>();
+        let neg_powers_of_beta_h =
+            degree_bounds.into_iter().zip(fixed_base_mul::<G2<N>>(&neg_scalars)).collect::<BTreeMap<_, _>>();
+        self.write_usrs("neg-powers-of-beta", &neg_powers_of_beta_h, true)?;
+
+        // Generate beta H.
+        let beta_h = fixed_base_mul::<G2<N>>(&[beta])[0];
+        self.write_usrs("beta-h", &beta_h, true)?;
+
+        Ok(remote_files)
+    }
+
+    /// Synthesizes the circuit keys for the credits program and the inclusion circuit, from the embedded universal SRS,
+    /// and returns the names of the remote files.
+    pub fn circuit_keys<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Vec<String>> {
+        let mut remote_files = vec![];
+
+        // Synthesize the 'credits.aleo' circuit keys.
+        let process = Process::setup::<A, _>(rng)?;
+        let program = Program::<N>::credits()?;
+        for function_name in program.functions().keys() {
+            let proving_key = process.get_proving_key(program.id(), function_name)?;
+            let verifying_key = process.get_verifying_key(program.id(), function_name)?;
+            remote_files.push(self.write_keys(&function_name.to_string(), &proving_key, &verifying_key)?);
+        }
+
+        // Sample an assignment for the inclusion circuit.
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+        let private_key = PrivateKey::<N>::new(rng)?;
+        let block = vm.genesis_beacon(&private_key, rng)?;
+        vm.add_next_block(&block)?;
+        let commitment = block.commitments().next().ok_or_else(|| anyhow!("No commitments found"))?;
+        let state_path = vm.block_store().get_state_path_for_commitment(commitment)?;
+        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
+        let gamma = h * private_key.sk_sig();
+        let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, *commitment)?;
+        let assignment =
+            InclusionAssignment::new(state_path.clone(), *commitment, gamma, serial_number, Default::default(), true)
+                .to_circuit_assignment::<A>()?;
+
+        // Synthesize the inclusion circuit keys, and ensure they are valid.
+        let function_name = N::INCLUSION_FUNCTION_NAME;
+        let (proving_key, verifying_key) = UniversalSRS::<N>::load()?.to_circuit_key(function_name, &assignment)?;
+        let proof = proving_key.prove(function_name, &assignment, rng)?;
+        let inputs = [N::Field::one(), **state_path.global_state_root(), *Field::<N>::zero(), *serial_number];
+        ensure!(verifying_key.verify(function_name, &inputs, &proof), "The inclusion circuit keys are invalid");
+        remote_files.push(self.write_keys(function_name, &proving_key, &verifying_key)?);
+
+        Ok(remote_files)
+    }
+
+    /// Generates the genesis block, from the embedded universal SRS and circuit keys,
+    /// and returns it with the genesis private key.
+    pub fn genesis_block<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<(PrivateKey<N>, Block<N>)> {
+        // Sample the genesis private key.
+        let private_key = PrivateKey::<N>::new(rng)?;
+        // Generate the genesis block.
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+        let block = vm.genesis_beacon(&private_key, rng)?;
+
+        // Write the genesis block, with its metadata.
+        let bytes = block.to_bytes_le()?;
+        let metadata = json!({
+            "checksum": checksum(&bytes),
+            "size": bytes.len(),
+            "version": PARAMETERS_VERSION,
+            "network": N::ID,
+            "block_hash": block.hash().to_string(),
+        });
+        self.write_metadata("genesis.metadata", &metadata)?;
+        self.write(PathBuf::from("block.genesis"), &bytes)?;
+        Ok((private_key, block))
+    }
+
+    /// Writes the given SRS component to a parameter file, with its metadata,
+    /// and returns the name of the file, if it is remote.
+    fn write_usrs<T: CanonicalSerialize>(&self, name: &str, value: &T, is_local: bool) -> Result<Option<String>> {
+        let mut bytes = Vec::with_capacity(value.uncompressed_size());
+        value.serialize_uncompressed(&mut bytes)?;
+        let checksum = checksum(&bytes);
+
+        let metadata = json!({
+            "checksum": checksum,
+            "size": bytes.len(),
+            "version": PARAMETERS_VERSION,
+            "network": N::ID,
+        });
+        self.write_metadata(&format!("{name}.metadata"), &metadata)?;
+        match is_local {
+            true => {
+                self.write(PathBuf::from(format!("{name}.usrs")), &bytes)?;
+                Ok(None)
+            }
+            false => {
+                let filename = versioned_filename(&format!("{name}.usrs"), &checksum);
+                self.write(PathBuf::from(&filename), &bytes)?;
+                Ok(Some(filename))
+            }
+        }
+    }
+
+    /// Writes the given circuit keys to parameter files, with their metadata,
+    /// and returns the name of the proving key file, which is remote.
+    fn write_keys(&self, name: &str, proving_key: &ProvingKey<N>, verifying_key: &VerifyingKey<N>) -> Result<String> {
+        let (proving_key_bytes, verifying_key_bytes) = (&proving_key.to_bytes_le()?, &verifying_key.to_bytes_le()?);
+        let proving_key_checksum = checksum(proving_key_bytes);
+        let metadata = json!({
+            "prover_checksum": proving_key_checksum,
+            "prover_size": proving_key_bytes.len(),
+            "verifier_checksum": checksum(verifying_key_bytes),
+            "verifier_size": verifying_key_bytes.len(),
+            "version": PARAMETERS_VERSION,
+            "network": N::ID,
+            "circuit_id": verifying_key.id.to_string(),
+        });
+
+        let filename = versioned_filename(&format!("{name}.prover"), &proving_key_checksum);
+        self.write_metadata(&format!("{name}.metadata"), &metadata)?;
+        self.write(PathBuf::from(&filename), proving_key_bytes)?;
+        self.write(PathBuf::from(format!("{name}.verifier")), verifying_key_bytes)?;
+        Ok(filename)
+    }
+
+    /// Writes the given metadata as JSON to the given filename.
+    fn write_metadata(&self, filename: &str, metadata: &Value) -> Result<()> {
+        self.write(PathBuf::from(filename), &serde_json::to_vec_pretty(metadata)?)
+    }
+
+    /// Writes the given bytes to the given filename in the output directory.
+    fn write(&self, filename: PathBuf, bytes: &[u8]) -> Result<()> {
+        fs::create_dir_all(&self.output_dir)?;
+        let mut file = BufWriter::new(File::create(self.output_dir.join(filename))?);
+        file.write_all(bytes)?;
+        Ok(())
+    }
+}
+
+/// Returns the SHA-256 checksum of the given bytes, as a hex string.
+fn checksum(bytes: &[u8]) -> String {
+    sha256(bytes).iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Returns the filename, suffixed with the first 7 characters of the checksum.
+fn versioned_filename(filename: &str, checksum: &str) -> String {
+    match checksum.get(0..7) {
+        Some(sum) => format!("{filename}.{sum}"),
+        _ => filename.to_string(),
+    }
+}
+
+/// Returns the powers `[base^start, base^(start + 1), ..., base^(end - 1)]`.
+fn powers_of<N: Network>(base: N::Field, start: usize, end: usize) -> Vec<N::Field> {
+    let mut power = base.pow([start as u64]);
+    (start..end)
+        .map(|_| {
+            let current = power;
+            power *= base;
+            current
+        })
+        .collect()
+}
+
+/// Returns the given scalars multiplied by the generator of `G`.
+fn fixed_base_mul<G: ProjectiveCurve>(scalars: &[G::ScalarField]) -> Vec<G::Affine> {
+    let scalar_size = G::ScalarField::size_in_bits();
+    let window = FixedBase::get_mul_window_size(scalars.len());
+    let table = FixedBase::get_window_table(scalar_size, window, G::prime_subgroup_generator());
+    G::batch_normalization_into_affine(FixedBase::msm(scalar_size, window, &table, scalars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        circuit::AleoV0,
+        console::network::Testnet3,
+        curves::AffineCurve,
+        utilities::{CanonicalDeserialize, TestRng},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type G1Affine = <<CurrentNetwork as Environment>::PairingCurve as PairingEngine>::G1Affine;
+    type G2Affine = <<CurrentNetwork as Environment>::PairingCurve as PairingEngine>::G2Affine;
+
+    /// Reads the given SRS component, and checks it against its metadata.
+    fn read_usrs<T: CanonicalDeserialize>(directory: &std::path::Path, name: &str) -> Result<T> {
+        let metadata: Value = serde_json::from_slice(&fs::read(directory.join(format!("{name}.metadata")))?)?;
+        let bytes = fs::read(directory.join(format!("{name}.usrs")))?;
+        assert_eq!(metadata["checksum"], checksum(&bytes));
+        assert_eq!(metadata["size"], bytes.len());
+        assert_eq!(metadata["version"], PARAMETERS_VERSION);
+        assert_eq!(metadata["network"], CurrentNetwork::ID);
+        Ok(T::deserialize_uncompressed(&*bytes)?)
+    }
+
+    #[test]
+    fn test_universal_srs_round_trip() -> Result<()> {
+        let directory = tempfile::tempdir()?;
+        let generator = ParameterGenerator::<CurrentNetwork, AleoV0>::new(directory.path());
+        let remote_files = generator.universal_srs(&mut TestRng::default())?;
+
+        // Ensure only the shifted powers beyond the smallest file are remote.
+        assert_eq!(remote_files.len(), 1);
+        assert!(remote_files[0].starts_with("shifted-powers-of-beta-16.usrs."));
+        assert!(directory.path().join(&remote_files[0]).exists());
+
+        // Read back the local files.
+        let powers = read_usrs::<Vec<G1Affine>>(directory.path(), "powers-of-beta-15")?;
+        let next_powers = read_usrs::<Vec<G1Affine>>(directory.path(), "powers-of-beta-16")?;
+        let beta_h = read_usrs::<G2Affine>(directory.path(), "beta-h")?;
+        let neg_powers = read_usrs::<BTreeMap<usize, G2Affine>>(directory.path(), "neg-powers-of-beta")?;
+        read_usrs::<BTreeMap<usize, G1Affine>>(directory.path(), "powers-of-beta-gamma")?;
+        read_usrs::<Vec<G1Affine>>(directory.path(), "shifted-powers-of-beta-15")?;
+        assert_eq!(powers.len(), 1 << MIN_DEGREE_LOG2);
+        assert_eq!(next_powers.len(), 1 << MIN_DEGREE_LOG2);
+        assert_eq!(neg_powers.len(), MAX_DEGREE_LOG2 - 1);
+
+        // Ensure the powers of beta G and beta H share the same trapdoor.
+        let (g, h) = (G1Affine::prime_subgroup_generator(), G2Affine::prime_subgroup_generator());
+        type Pairing = <CurrentNetwork as Environment>::PairingCurve;
+        assert_eq!(powers[0], g);
+        assert_eq!(Pairing::pairing(powers[1], h), Pairing::pairing(g, beta_h));
+        assert_eq!(Pairing::pairing(powers[2], h), Pairing::pairing(powers[1], beta_h));
+        assert_eq!(Pairing::pairing(next_powers[0], h), Pairing::pairing(powers[(1 << MIN_DEGREE_LOG2) - 1], beta_h));
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_degree_log2() {
+        let generator = ParameterGenerator::<CurrentNetwork, AleoV0>::new("unused");
+        assert!(generator.with_max_degree_log2(MAX_LOCAL_DEGREE_LOG2 - 1).is_err());
+        let generator = ParameterGenerator::<CurrentNetwork, AleoV0>::new("unused");
+        assert!(generator.with_max_degree_log2(MAX_DEGREE_LOG2 + 1).is_err());
+        let generator = ParameterGenerator::<CurrentNetwork, AleoV0>::new("unused");
+        assert_eq!(generator.with_max_degree_log2(20).unwrap().max_degree_log2, 20);
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "algorithms")]
+pub use snarkvm_algorithms as algorithms;
 #[cfg(feature = "console")]
 pub use snarkvm_console as console;
 #[cfg(feature = "curves")]
@@ -14,9 +14,10 @@
 
 use snarkvm_console::{
     account::{Address, PrivateKey, ViewKey},
-    network::Testnet3,
+    network::{Network, Testnet3},
+    types::Field,
 };
-use snarkvm_utilities::TestRng;
+use snarkvm_utilities::{TestRng, Uniform};
 
 use core::str::FromStr;
 use wasm_bindgen_test::*;
@@ -58,3 +59,24 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+#[wasm_bindgen_test]
+fn test_merkle_path_verify() {
+    const DEPTH: u8 = 16;
+
+    let mut rng = TestRng::default();
+
+    // Construct a Merkle tree over random leaves.
+    let leaves = (0..32).map(|_| vec![Field::<Testnet3>::rand(&mut rng)]).collect::<Vec<_>>();
+    let tree = Testnet3::merkle_tree_psd::<DEPTH>(&leaves).unwrap();
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        // Verify the Merkle path for the leaf.
+        let path = tree.prove(index, leaf).unwrap();
+        assert!(Testnet3::verify_merkle_path_psd(&path, tree.root(), leaf));
+
+        // Ensure the Merkle path does not verify for a different leaf.
+        let other_leaf = vec![Field::<Testnet3>::rand(&mut rng)];
+        assert!(!Testnet3::verify_merkle_path_psd(&path, tree.root(), &other_leaf));
+    }
+}